@@ -1,6 +1,9 @@
 use crate::MarinadeError;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::stake::state::StakeState;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
 use anchor_spl::token::{Mint, TokenAccount};
 
 pub fn check_owner_program<'info, A: ToAccountInfo<'info>>(
@@ -25,6 +28,22 @@ pub fn check_owner_program<'info, A: ToAccountInfo<'info>>(
     }
 }
 
+/// the per-validator PDAs of the optional features are optional accounts,
+/// required once their feature is configured
+pub fn check_feature_account<T>(
+    account: &Option<T>,
+    configured: bool,
+    field_name: &str,
+) -> Result<()> {
+    if configured && account.is_none() {
+        msg!("{} is required", field_name);
+        return Err(Error::from(MarinadeError::MissingFeatureAccount)
+            .with_account_name(field_name)
+            .with_source(source!()));
+    }
+    Ok(())
+}
+
 pub fn check_mint_authority(mint: &Mint, mint_authority: &Pubkey, field_name: &str) -> Result<()> {
     if mint.mint_authority.contains(mint_authority) {
         Ok(())
@@ -157,3 +176,92 @@ pub fn check_token_source_account<'info>(
     }
     Ok(())
 }
+
+/// true when an instruction of this program with `discriminator` and `first_account`
+/// as its first account comes later in the transaction
+pub fn is_followed_by(
+    instructions: &AccountInfo,
+    discriminator: &[u8],
+    first_account: &Pubkey,
+) -> Result<bool> {
+    let mut index = load_current_index_checked(instructions)? as usize + 1;
+    while let Ok(instruction) = load_instruction_at_checked(index, instructions) {
+        if instruction.program_id == crate::ID
+            && instruction.data.starts_with(discriminator)
+            && instruction
+                .accounts
+                .first()
+                .is_some_and(|account| account.pubkey == *first_account)
+        {
+            return Ok(true);
+        }
+        index += 1;
+    }
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::{spl_token, TokenAccount};
+
+    use super::check_token_source_account;
+    use crate::MarinadeError;
+
+    fn check_source(
+        amount: u64,
+        delegated_amount: u64,
+        authority: fn(owner: Pubkey, delegate: Pubkey) -> Pubkey,
+        token_amount: u64,
+    ) -> Result<()> {
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint: Pubkey::new_unique(),
+            owner,
+            amount,
+            delegate: Some(delegate).filter(|_| delegated_amount > 0).into(),
+            state: spl_token::state::AccountState::Initialized,
+            delegated_amount,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &spl_token::ID,
+            false,
+            0,
+        );
+        let source = Account::<TokenAccount>::try_from(&info)?;
+        check_token_source_account(&source, &authority(owner, delegate), token_amount)
+    }
+
+    #[test]
+    fn test_u64_max_tokens() {
+        let by_owner = |owner, _| owner;
+        let by_delegate = |_, delegate| delegate;
+        for (amount, delegated_amount, authority) in [
+            (1_000, 0, by_owner as fn(Pubkey, Pubkey) -> Pubkey),
+            (u64::MAX - 1, 0, by_owner),
+            (1_000_000, 500, by_delegate),
+        ] {
+            assert_eq!(
+                check_source(amount, delegated_amount, authority, u64::MAX),
+                Err(MarinadeError::NotEnoughUserFunds.into())
+            );
+        }
+        assert!(check_source(u64::MAX, 0, by_owner, u64::MAX).is_ok());
+        assert_eq!(
+            check_source(u64::MAX, 0, |_, _| Pubkey::new_unique(), u64::MAX),
+            Err(MarinadeError::WrongTokenOwnerOrDelegate.into())
+        );
+    }
+}
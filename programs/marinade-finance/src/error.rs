@@ -270,4 +270,747 @@ pub enum MarinadeError {
 
     #[msg("Capacity of the list must be not less than it's current size")]
     ShrinkingListWithDeletingContents, // 6086 0x17c6
+
+    #[msg("Vesting slots must be start <= cliff <= end")]
+    InvalidVestingSchedule, // 6087 0x17c7
+
+    #[msg("Nothing vested yet")]
+    NothingVestedYet, // 6088 0x17c8
+
+    #[msg("Flash removal from the liquidity pool is in progress")]
+    FlashRemoveInProgress, // 6089 0x17c9
+
+    #[msg("No flash removal in progress")]
+    FlashRemoveNotInProgress, // 6090 0x17ca
+
+    #[msg("Liquidity pool legs were not restored after flash removal")]
+    FlashRemoveNotRestored, // 6091 0x17cb
+
+    #[msg("Flash removal must be followed by end_flash_remove_liquidity in the same transaction")]
+    FlashRemoveEndMissing, // 6092 0x17cc
+
+    #[msg("LP mint supply is not above the virtual lp supply")]
+    LpSupplyNotAboveVirtual, // 6093 0x17cd
+
+    #[msg("Keeper tip is above the allowed share of the SOL output")]
+    KeeperTipTooHigh, // 6094 0x17ce
+
+    #[msg("mSOL price circuit breaker tripped, removals are blocked until reset")]
+    CircuitBreakerTripped, // 6095 0x17cf
+
+    #[msg("Remove liquidity output below the requested minimum")]
+    RemoveLiquidityBelowMinOut, // 6096 0x17d0
+
+    #[msg("Invalid unstake fee curve")]
+    InvalidUnstakeFeeCurve, // 6097 0x17d1
+
+    #[msg("Deposit would put the LP token account over lp_account_cap")]
+    LpAccountCapExceeded, // 6098 0x17d2
+
+    #[msg("LP cap bypass of another state")]
+    InvalidLpCapBypass, // 6099 0x17d3
+
+    #[msg("Invalid lock duration")]
+    InvalidLockDuration, // 6100 0x17d4
+
+    #[msg("Lock not expired")]
+    LockNotExpired, // 6101 0x17d5
+
+    #[msg("Locked LP boost is not configured")]
+    LockedLpBoostDisabled, // 6102 0x17d6
+
+    #[msg("Locked LP boost vault can not change while positions are locked")]
+    LockedLpBoostVaultInUse, // 6103 0x17d7
+
+    #[msg("Locked LP position of another state")]
+    InvalidLockedLpPosition, // 6104 0x17d8
+
+    #[msg("Flash loan in progress")]
+    FlashLoanInProgress, // 6105 0x17d9
+
+    #[msg("No flash loan in progress")]
+    FlashLoanNotInProgress, // 6106 0x17da
+
+    #[msg("Flash loan without a later flash_repay_sol")]
+    FlashLoanRepayMissing, // 6107 0x17db
+
+    #[msg("Flash loan not repaid")]
+    FlashLoanNotRepaid, // 6108 0x17dc
+
+    #[msg("Liq pool mSOL share within the limit or no reserve to rebalance with")]
+    LiqPoolRebalanceNotNeeded, // 6109 0x17dd
+
+    #[msg("Liq pool legs are held in the emergency escrow")]
+    LiqPoolDrained, // 6110 0x17de
+
+    #[msg("Liq pool legs are not in the emergency escrow")]
+    LiqPoolNotDrained, // 6111 0x17df
+
+    #[msg("Arb does not lower the liquid unstake fee")]
+    ArbDoesNotImprovePrice, // 6112 0x17e0
+
+    #[msg("Arb bounty must not exceed the liq pool min fee")]
+    InvalidArbBounty, // 6113 0x17e1, no longer returned: arb_liq_pool pays no bounty
+
+    #[msg("Arb mSOL output below min_msol_out")]
+    ArbBelowMinOut, // 6114 0x17e2
+
+    #[msg("Claim amount must be between 1 and the ticket balance")]
+    InvalidClaimAmount, // 6115 0x17e3
+
+    #[msg("Tokenized ticket accounts missing")]
+    TicketTokenAccountsMissing, // 6116 0x17e4
+
+    #[msg("Ticket is due, claim it instead")]
+    TicketAlreadyDue, // 6117 0x17e5
+
+    #[msg("Instant withdraw epoch cap reached")]
+    InstantWithdrawIsCapped, // 6118 0x17e6
+
+    #[msg("Not enough idle reserve lamports for instant withdraw")]
+    NotEnoughIdleReserve, // 6119 0x17e7
+
+    #[msg("Liquid unstake payout below min_lamports_out")]
+    SlippageExceeded, // 6120 0x17e8
+
+    #[msg("Claim tip is too high")]
+    ClaimTipIsTooHigh, // 6121 0x17e9
+
+    #[msg("Ticket registry is full")]
+    TicketRegistryFull, // 6122 0x17ea
+
+    #[msg("Ticket batch amounts do not match the ticket accounts")]
+    InvalidTicketBatch, // 6123 0x17eb
+
+    #[msg("Ticket still holds lamports")]
+    TicketNotEmpty, // 6124 0x17ec
+
+    #[msg("Ticket ETA accounts missing")]
+    TicketEtaAccountsMissing, // 6125 0x17ed
+
+    #[msg("Wrong directed stake record")]
+    WrongDirectedStakeRecord, // 6126 0x17ee
+
+    #[msg("Referral fee is too high")]
+    ReferralFeeIsTooHigh, // 6127 0x17ef
+
+    #[msg("No referral rewards to claim")]
+    NoReferralRewards, // 6128 0x17f0
+
+    #[msg("Referral belongs to another state")]
+    InvalidReferral, // 6129 0x17f1
+
+    #[msg("Deposit split must leave a part of the stake")]
+    InvalidDepositSplit, // 6130 0x17f2
+
+    #[msg("Invalid staking cap bypass")]
+    InvalidStakingCapBypass, // 6131 0x17f3
+
+    #[msg("Only a stake deactivated in the current epoch can be reactivated")]
+    StakeDeactivationNotRescindable, // 6132 0x17f4
+
+    #[msg("Invalid stake account batch")]
+    InvalidStakeAccountBatch, // 6133 0x17f5
+
+    #[msg("Permissionless validator add is disabled")]
+    PermissionlessAddDisabled, // 6134 0x17f6
+
+    #[msg("Invalid vote account")]
+    InvalidVoteAccount, // 6135 0x17f7
+
+    #[msg("Validator commission is too high")]
+    ValidatorCommissionTooHigh, // 6136 0x17f8
+
+    #[msg("Validator is delinquent")]
+    ValidatorDelinquent, // 6137 0x17f9
+
+    #[msg("Invalid score oracle scorer")]
+    InvalidScorer, // 6138 0x17fa
+
+    #[msg("Invalid oracle scores")]
+    InvalidOracleScores, // 6139 0x17fb
+
+    #[msg("Wrong validator stake cap")]
+    WrongValidatorStakeCap, // 6140 0x17fc
+
+    #[msg("Invalid validator stake caps")]
+    InvalidValidatorStakeCaps, // 6141 0x17fd
+
+    #[msg("Delinquent validator unstake is disabled")]
+    DelinquentUnstakeDisabled, // 6142 0x17fe
+
+    #[msg("Validator is not delinquent")]
+    ValidatorNotDelinquent, // 6143 0x17ff
+
+    #[msg("Validator vote account is required to check the commission ceiling")]
+    MissingValidatorVote, // 6144 0x1800
+
+    #[msg("Validator is blacklisted")]
+    ValidatorBlacklisted, // 6145 0x1801
+
+    #[msg("Invalid blacklisted validator")]
+    InvalidBlacklistedValidator, // 6146 0x1802
+
+    #[msg("Wrong gauge")]
+    WrongGauge, // 6147 0x1803
+
+    #[msg("Gauge already tallied this epoch")]
+    GaugeAlreadyTallied, // 6148 0x1804
+
+    #[msg("Gauge lock not expired")]
+    GaugeLockNotExpired, // 6149 0x1805
+
+    #[msg("Invalid gauge lock")]
+    InvalidGaugeLock, // 6150 0x1806
+
+    #[msg("Wrong validator history")]
+    WrongValidatorHistory, // 6151 0x1807
+
+    #[msg("Validator list hash mismatch")]
+    InvalidValidatorListHash, // 6152 0x1808
+
+    #[msg("Wrong validator bond")]
+    WrongValidatorBond, // 6153 0x1809
+
+    #[msg("Invalid validator bond authority")]
+    InvalidBondAuthority, // 6154 0x180a
+
+    #[msg("Validator bond is too low for its stake")]
+    BondIsTooLow, // 6155 0x180b
+
+    #[msg("Removing a validator with a score")]
+    RemovingScoredValidator, // 6156 0x180c
+
+    #[msg("Validator stake is protected from rebalancing")]
+    ValidatorIsProtected, // 6157 0x180d
+
+    #[msg("Invalid protected validator")]
+    InvalidProtectedValidator, // 6158 0x180e
+
+    #[msg("Invalid validator identity")]
+    InvalidValidatorIdentity, // 6159 0x180f
+
+    #[msg("Stake credits observed mismatch")]
+    StakeCreditsObservedMismatch, // 6160 0x1810
+
+    #[msg("Update window is too high")]
+    UpdateWindowIsTooHigh, // 6161 0x1811
+
+    #[msg("Min stake is too high")]
+    MinStakeIsTooHigh, // 6162 0x1812
+
+    #[msg("Crank tip is too high")]
+    CrankTipIsTooHigh, // 6163 0x1813
+
+    #[msg("System program is required to pay the crank tip")]
+    MissingSystemProgram, // 6164 0x1814
+
+    #[msg("Invalid pending admin authority")]
+    InvalidPendingAdminAuthority, // 6165 0x1815
+
+    #[msg("Config changes must go through a config proposal")]
+    ConfigIsTimelocked, // 6166 0x1816
+
+    #[msg("Config proposal timelock not elapsed")]
+    ConfigTimelockNotElapsed, // 6167 0x1817
+
+    #[msg("Config timelock is too high")]
+    ConfigTimelockIsTooHigh, // 6168 0x1818
+
+    #[msg("Instruction group is paused")]
+    GroupIsPaused, // 6169 0x1819
+
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags, // 6170 0x181a
+
+    #[msg("Invalid pause guardians")]
+    InvalidPauseGuardians, // 6171 0x181b
+
+    #[msg("Guardian quorum not reached")]
+    GuardianQuorumNotReached, // 6172 0x181c
+
+    #[msg("Groups paused by guardians resume by admin config proposal only")]
+    PausedByGuardians, // 6173 0x181d
+
+    #[msg("Fee change exceeds the per epoch limit")]
+    FeeChangeIsTooHigh, // 6174 0x181e
+
+    #[msg("Invalid max fee change per epoch")]
+    InvalidMaxFeeChangePerEpoch, // 6175 0x181f
+
+    #[msg("Invalid treasury split")]
+    InvalidTreasurySplit, // 6176 0x1820
+
+    #[msg("Treasury split is required")]
+    MissingTreasurySplit, // 6177 0x1821
+
+    #[msg("Wrong treasury split destination")]
+    WrongTreasurySplitDestination, // 6178 0x1822
+
+    #[msg("Invalid treasury authority")]
+    InvalidTreasuryAuthority, // 6179 0x1823
+
+    #[msg("Pending admin is not the native treasury of the governance")]
+    InvalidGovernanceNativeTreasury, // 6180 0x1824
+
+    #[msg("Admin log is required")]
+    MissingAdminLog, // 6181 0x1825
+
+    #[msg("Wrong admin log")]
+    WrongAdminLog, // 6182 0x1826
+
+    #[msg("Invalid treasury vesting epochs")]
+    InvalidTreasuryVestingEpochs, // 6183 0x1827
+
+    #[msg("Treasury vesting is required")]
+    MissingTreasuryVesting, // 6184 0x1828
+
+    #[msg("Locked LP boost vault is required while the locked LP boost is configured")]
+    MissingLockedLpBoostVault, // 6185 0x1829
+
+    #[msg("Treasury mSOL account is required while the removal dust goes to the treasury")]
+    MissingTreasuryMsolAccount, // 6186 0x182a
+
+    #[msg("State account already has the current size")]
+    StateAlreadyReallocated, // 6187 0x182b
+
+    #[msg("Ticket account is not closed yet")]
+    TicketNotClosed, // 6188 0x182c
+
+    #[msg("Wrong rent payer")]
+    WrongRentPayer, // 6189 0x182d
+
+    #[msg("Account required by a configured feature is missing")]
+    MissingFeatureAccount, // 6190 0x182e
+
+    #[msg("Ticket does not record the mSOL burned for it")]
+    TicketNotCancelable, // 6191 0x182f
+
+    #[msg("Ticket account has no room for the ticket extension")]
+    TicketAccountTooSmall, // 6192 0x1830
+}
+
+impl MarinadeError {
+    /// Stable machine-readable reason for each error, so clients can localize messages
+    /// without depending on the #[msg] texts. Never change an existing value
+    pub fn reason_code(&self) -> &'static str {
+        match self {
+            Self::WrongReserveOwner => "wrong_reserve_owner",
+            Self::NonEmptyReserveData => "non_empty_reserve_data",
+            Self::InvalidInitialReserveLamports => "invalid_initial_reserve_lamports",
+            Self::ZeroValidatorChunkSize => "zero_validator_chunk_size",
+            Self::TooBigValidatorChunkSize => "too_big_validator_chunk_size",
+            Self::ZeroCreditChunkSize => "zero_credit_chunk_size",
+            Self::TooBigCreditChunkSize => "too_big_credit_chunk_size",
+            Self::TooLowCreditFee => "too_low_credit_fee",
+            Self::InvalidMintAuthority => "invalid_mint_authority",
+            Self::MintHasInitialSupply => "mint_has_initial_supply",
+            Self::InvalidOwnerFeeState => "invalid_owner_fee_state",
+            Self::InvalidProgramId => "invalid_program_id",
+            Self::UnexpectedAccount => "unexpected_account",
+            Self::CalculationFailure => "calculation_failure",
+            Self::StakeAccountWithLockup => "stake_account_with_lockup",
+            Self::MinStakeIsTooLow => "min_stake_is_too_low",
+            Self::LpMaxFeeIsTooHigh => "lp_max_fee_is_too_high",
+            Self::BasisPointsOverflow => "basis_points_overflow",
+            Self::LpFeesAreWrongWayRound => "lp_fees_are_wrong_way_round",
+            Self::LiquidityTargetTooLow => "liquidity_target_too_low",
+            Self::TicketNotDue => "ticket_not_due",
+            Self::TicketNotReady => "ticket_not_ready",
+            Self::WrongBeneficiary => "wrong_beneficiary",
+            Self::StakeAccountNotUpdatedYet => "stake_account_not_updated_yet",
+            Self::StakeNotDelegated => "stake_not_delegated",
+            Self::StakeAccountIsEmergencyUnstaking => "stake_account_is_emergency_unstaking",
+            Self::InsufficientLiquidity => "insufficient_liquidity",
+            Self::NotUsed6027 => "not_used_6027",
+            Self::InvalidAdminAuthority => "invalid_admin_authority",
+            Self::InvalidValidatorManager => "invalid_validator_manager",
+            Self::InvalidStakeListDiscriminator => "invalid_stake_list_discriminator",
+            Self::InvalidValidatorListDiscriminator => "invalid_validator_list_discriminator",
+            Self::TreasuryCutIsTooHigh => "treasury_cut_is_too_high",
+            Self::RewardsFeeIsTooHigh => "rewards_fee_is_too_high",
+            Self::StakingIsCapped => "staking_is_capped",
+            Self::LiquidityIsCapped => "liquidity_is_capped",
+            Self::UpdateWindowIsTooLow => "update_window_is_too_low",
+            Self::MinWithdrawIsTooHigh => "min_withdraw_is_too_high",
+            Self::WithdrawAmountIsTooLow => "withdraw_amount_is_too_low",
+            Self::DepositAmountIsTooLow => "deposit_amount_is_too_low",
+            Self::NotEnoughUserFunds => "not_enough_user_funds",
+            Self::WrongTokenOwnerOrDelegate => "wrong_token_owner_or_delegate",
+            Self::TooEarlyForStakeDelta => "too_early_for_stake_delta",
+            Self::RequiredDelegatedStake => "required_delegated_stake",
+            Self::RequiredActiveStake => "required_active_stake",
+            Self::RequiredDeactivatingStake => "required_deactivating_stake",
+            Self::DepositingNotActivatedStake => "depositing_not_activated_stake",
+            Self::TooLowDelegationInDepositingStake => "too_low_delegation_in_depositing_stake",
+            Self::WrongStakeBalance => "wrong_stake_balance",
+            Self::WrongValidatorAccountOrIndex => "wrong_validator_account_or_index",
+            Self::WrongStakeAccountOrIndex => "wrong_stake_account_or_index",
+            Self::UnstakingOnPositiveDelta => "unstaking_on_positive_delta",
+            Self::StakingOnNegativeDelta => "staking_on_negative_delta",
+            Self::MovingStakeIsCapped => "moving_stake_is_capped",
+            Self::StakeMustBeUninitialized => "stake_must_be_uninitialized",
+            Self::DestinationStakeMustBeDelegated => "destination_stake_must_be_delegated",
+            Self::DestinationStakeMustNotBeDeactivating => {
+                "destination_stake_must_not_be_deactivating"
+            }
+            Self::DestinationStakeMustBeUpdated => "destination_stake_must_be_updated",
+            Self::InvalidDestinationStakeDelegation => "invalid_destination_stake_delegation",
+            Self::SourceStakeMustBeDelegated => "source_stake_must_be_delegated",
+            Self::SourceStakeMustNotBeDeactivating => "source_stake_must_not_be_deactivating",
+            Self::SourceStakeMustBeUpdated => "source_stake_must_be_updated",
+            Self::InvalidSourceStakeDelegation => "invalid_source_stake_delegation",
+            Self::InvalidDelayedUnstakeTicket => "invalid_delayed_unstake_ticket",
+            Self::ReusingDelayedUnstakeTicket => "reusing_delayed_unstake_ticket",
+            Self::EmergencyUnstakingFromNonZeroScoredValidator => {
+                "emergency_unstaking_from_non_zero_scored_validator"
+            }
+            Self::WrongValidatorDuplicationFlag => "wrong_validator_duplication_flag",
+            Self::RedepositingMarinadeStake => "redepositing_marinade_stake",
+            Self::RemovingValidatorWithBalance => "removing_validator_with_balance",
+            Self::RedelegateOverTarget => "redelegate_over_target",
+            Self::SourceAndDestValidatorsAreTheSame => "source_and_dest_validators_are_the_same",
+            Self::UnregisteredMsolMinted => "unregistered_msol_minted",
+            Self::UnregisteredLPMinted => "unregistered_lp_minted",
+            Self::ListIndexOutOfBounds => "list_index_out_of_bounds",
+            Self::ListOverflow => "list_overflow",
+            Self::AlreadyPaused => "already_paused",
+            Self::NotPaused => "not_paused",
+            Self::ProgramIsPaused => "program_is_paused",
+            Self::InvalidPauseAuthority => "invalid_pause_authority",
+            Self::SelectedStakeAccountHasNotEnoughFunds => {
+                "selected_stake_account_has_not_enough_funds"
+            }
+            Self::BasisPointCentsOverflow => "basis_point_cents_overflow",
+            Self::WithdrawStakeAccountIsNotEnabled => "withdraw_stake_account_is_not_enabled",
+            Self::WithdrawStakeAccountFeeIsTooHigh => "withdraw_stake_account_fee_is_too_high",
+            Self::DelayedUnstakeFeeIsTooHigh => "delayed_unstake_fee_is_too_high",
+            Self::WithdrawStakeLamportsIsTooLow => "withdraw_stake_lamports_is_too_low",
+            Self::StakeAccountRemainderTooLow => "stake_account_remainder_too_low",
+            Self::ShrinkingListWithDeletingContents => "shrinking_list_with_deleting_contents",
+            Self::InvalidVestingSchedule => "invalid_vesting_schedule",
+            Self::NothingVestedYet => "nothing_vested_yet",
+            Self::FlashRemoveInProgress => "flash_remove_in_progress",
+            Self::FlashRemoveNotInProgress => "flash_remove_not_in_progress",
+            Self::FlashRemoveNotRestored => "flash_remove_not_restored",
+            Self::FlashRemoveEndMissing => "flash_remove_end_missing",
+            Self::LpSupplyNotAboveVirtual => "lp_supply_not_above_virtual",
+            Self::KeeperTipTooHigh => "keeper_tip_too_high",
+            Self::CircuitBreakerTripped => "circuit_breaker_tripped",
+            Self::RemoveLiquidityBelowMinOut => "remove_liquidity_below_min_out",
+            Self::InvalidUnstakeFeeCurve => "invalid_unstake_fee_curve",
+            Self::LpAccountCapExceeded => "lp_account_cap_exceeded",
+            Self::InvalidLpCapBypass => "invalid_lp_cap_bypass",
+            Self::InvalidLockDuration => "invalid_lock_duration",
+            Self::LockNotExpired => "lock_not_expired",
+            Self::LockedLpBoostDisabled => "locked_lp_boost_disabled",
+            Self::LockedLpBoostVaultInUse => "locked_lp_boost_vault_in_use",
+            Self::InvalidLockedLpPosition => "invalid_locked_lp_position",
+            Self::FlashLoanInProgress => "flash_loan_in_progress",
+            Self::FlashLoanNotInProgress => "flash_loan_not_in_progress",
+            Self::FlashLoanRepayMissing => "flash_loan_repay_missing",
+            Self::FlashLoanNotRepaid => "flash_loan_not_repaid",
+            Self::LiqPoolRebalanceNotNeeded => "liq_pool_rebalance_not_needed",
+            Self::LiqPoolDrained => "liq_pool_drained",
+            Self::LiqPoolNotDrained => "liq_pool_not_drained",
+            Self::ArbDoesNotImprovePrice => "arb_does_not_improve_price",
+            Self::InvalidArbBounty => "invalid_arb_bounty",
+            Self::ArbBelowMinOut => "arb_below_min_out",
+            Self::InvalidClaimAmount => "invalid_claim_amount",
+            Self::TicketTokenAccountsMissing => "ticket_token_accounts_missing",
+            Self::TicketAlreadyDue => "ticket_already_due",
+            Self::InstantWithdrawIsCapped => "instant_withdraw_is_capped",
+            Self::NotEnoughIdleReserve => "not_enough_idle_reserve",
+            Self::SlippageExceeded => "slippage_exceeded",
+            Self::ClaimTipIsTooHigh => "claim_tip_is_too_high",
+            Self::TicketRegistryFull => "ticket_registry_full",
+            Self::InvalidTicketBatch => "invalid_ticket_batch",
+            Self::TicketNotEmpty => "ticket_not_empty",
+            Self::TicketEtaAccountsMissing => "ticket_eta_accounts_missing",
+            Self::WrongDirectedStakeRecord => "wrong_directed_stake_record",
+            Self::ReferralFeeIsTooHigh => "referral_fee_is_too_high",
+            Self::NoReferralRewards => "no_referral_rewards",
+            Self::InvalidReferral => "invalid_referral",
+            Self::InvalidDepositSplit => "invalid_deposit_split",
+            Self::InvalidStakingCapBypass => "invalid_staking_cap_bypass",
+            Self::StakeDeactivationNotRescindable => "stake_deactivation_not_rescindable",
+            Self::InvalidStakeAccountBatch => "invalid_stake_account_batch",
+            Self::PermissionlessAddDisabled => "permissionless_add_disabled",
+            Self::InvalidVoteAccount => "invalid_vote_account",
+            Self::ValidatorCommissionTooHigh => "validator_commission_too_high",
+            Self::ValidatorDelinquent => "validator_delinquent",
+            Self::InvalidScorer => "invalid_scorer",
+            Self::InvalidOracleScores => "invalid_oracle_scores",
+            Self::WrongValidatorStakeCap => "wrong_validator_stake_cap",
+            Self::InvalidValidatorStakeCaps => "invalid_validator_stake_caps",
+            Self::DelinquentUnstakeDisabled => "delinquent_unstake_disabled",
+            Self::ValidatorNotDelinquent => "validator_not_delinquent",
+            Self::MissingValidatorVote => "missing_validator_vote",
+            Self::ValidatorBlacklisted => "validator_blacklisted",
+            Self::InvalidBlacklistedValidator => "invalid_blacklisted_validator",
+            Self::WrongGauge => "wrong_gauge",
+            Self::GaugeAlreadyTallied => "gauge_already_tallied",
+            Self::GaugeLockNotExpired => "gauge_lock_not_expired",
+            Self::InvalidGaugeLock => "invalid_gauge_lock",
+            Self::WrongValidatorHistory => "wrong_validator_history",
+            Self::InvalidValidatorListHash => "invalid_validator_list_hash",
+            Self::WrongValidatorBond => "wrong_validator_bond",
+            Self::InvalidBondAuthority => "invalid_bond_authority",
+            Self::BondIsTooLow => "bond_is_too_low",
+            Self::RemovingScoredValidator => "removing_scored_validator",
+            Self::ValidatorIsProtected => "validator_is_protected",
+            Self::InvalidProtectedValidator => "invalid_protected_validator",
+            Self::InvalidValidatorIdentity => "invalid_validator_identity",
+            Self::StakeCreditsObservedMismatch => "stake_credits_observed_mismatch",
+            Self::UpdateWindowIsTooHigh => "update_window_is_too_high",
+            Self::MinStakeIsTooHigh => "min_stake_is_too_high",
+            Self::CrankTipIsTooHigh => "crank_tip_is_too_high",
+            Self::MissingSystemProgram => "missing_system_program",
+            Self::InvalidPendingAdminAuthority => "invalid_pending_admin_authority",
+            Self::ConfigIsTimelocked => "config_is_timelocked",
+            Self::ConfigTimelockNotElapsed => "config_timelock_not_elapsed",
+            Self::ConfigTimelockIsTooHigh => "config_timelock_is_too_high",
+            Self::GroupIsPaused => "group_is_paused",
+            Self::InvalidPauseFlags => "invalid_pause_flags",
+            Self::InvalidPauseGuardians => "invalid_pause_guardians",
+            Self::GuardianQuorumNotReached => "guardian_quorum_not_reached",
+            Self::PausedByGuardians => "paused_by_guardians",
+            Self::FeeChangeIsTooHigh => "fee_change_is_too_high",
+            Self::InvalidMaxFeeChangePerEpoch => "invalid_max_fee_change_per_epoch",
+            Self::InvalidTreasurySplit => "invalid_treasury_split",
+            Self::MissingTreasurySplit => "missing_treasury_split",
+            Self::WrongTreasurySplitDestination => "wrong_treasury_split_destination",
+            Self::InvalidTreasuryAuthority => "invalid_treasury_authority",
+            Self::InvalidGovernanceNativeTreasury => "invalid_governance_native_treasury",
+            Self::MissingAdminLog => "missing_admin_log",
+            Self::WrongAdminLog => "wrong_admin_log",
+            Self::InvalidTreasuryVestingEpochs => "invalid_treasury_vesting_epochs",
+            Self::MissingTreasuryVesting => "missing_treasury_vesting",
+            Self::MissingLockedLpBoostVault => "missing_locked_lp_boost_vault",
+            Self::MissingTreasuryMsolAccount => "missing_treasury_msol_account",
+            Self::StateAlreadyReallocated => "state_already_reallocated",
+            Self::TicketNotClosed => "ticket_not_closed",
+            Self::WrongRentPayer => "wrong_rent_payer",
+            Self::MissingFeatureAccount => "missing_feature_account",
+            Self::TicketNotCancelable => "ticket_not_cancelable",
+            Self::TicketAccountTooSmall => "ticket_account_too_small",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::MarinadeError;
+
+    #[test]
+    fn test_reason_codes() {
+        let all = [
+            MarinadeError::WrongReserveOwner,
+            MarinadeError::NonEmptyReserveData,
+            MarinadeError::InvalidInitialReserveLamports,
+            MarinadeError::ZeroValidatorChunkSize,
+            MarinadeError::TooBigValidatorChunkSize,
+            MarinadeError::ZeroCreditChunkSize,
+            MarinadeError::TooBigCreditChunkSize,
+            MarinadeError::TooLowCreditFee,
+            MarinadeError::InvalidMintAuthority,
+            MarinadeError::MintHasInitialSupply,
+            MarinadeError::InvalidOwnerFeeState,
+            MarinadeError::InvalidProgramId,
+            MarinadeError::UnexpectedAccount,
+            MarinadeError::CalculationFailure,
+            MarinadeError::StakeAccountWithLockup,
+            MarinadeError::MinStakeIsTooLow,
+            MarinadeError::LpMaxFeeIsTooHigh,
+            MarinadeError::BasisPointsOverflow,
+            MarinadeError::LpFeesAreWrongWayRound,
+            MarinadeError::LiquidityTargetTooLow,
+            MarinadeError::TicketNotDue,
+            MarinadeError::TicketNotReady,
+            MarinadeError::WrongBeneficiary,
+            MarinadeError::StakeAccountNotUpdatedYet,
+            MarinadeError::StakeNotDelegated,
+            MarinadeError::StakeAccountIsEmergencyUnstaking,
+            MarinadeError::InsufficientLiquidity,
+            MarinadeError::NotUsed6027,
+            MarinadeError::InvalidAdminAuthority,
+            MarinadeError::InvalidValidatorManager,
+            MarinadeError::InvalidStakeListDiscriminator,
+            MarinadeError::InvalidValidatorListDiscriminator,
+            MarinadeError::TreasuryCutIsTooHigh,
+            MarinadeError::RewardsFeeIsTooHigh,
+            MarinadeError::StakingIsCapped,
+            MarinadeError::LiquidityIsCapped,
+            MarinadeError::UpdateWindowIsTooLow,
+            MarinadeError::MinWithdrawIsTooHigh,
+            MarinadeError::WithdrawAmountIsTooLow,
+            MarinadeError::DepositAmountIsTooLow,
+            MarinadeError::NotEnoughUserFunds,
+            MarinadeError::WrongTokenOwnerOrDelegate,
+            MarinadeError::TooEarlyForStakeDelta,
+            MarinadeError::RequiredDelegatedStake,
+            MarinadeError::RequiredActiveStake,
+            MarinadeError::RequiredDeactivatingStake,
+            MarinadeError::DepositingNotActivatedStake,
+            MarinadeError::TooLowDelegationInDepositingStake,
+            MarinadeError::WrongStakeBalance,
+            MarinadeError::WrongValidatorAccountOrIndex,
+            MarinadeError::WrongStakeAccountOrIndex,
+            MarinadeError::UnstakingOnPositiveDelta,
+            MarinadeError::StakingOnNegativeDelta,
+            MarinadeError::MovingStakeIsCapped,
+            MarinadeError::StakeMustBeUninitialized,
+            MarinadeError::DestinationStakeMustBeDelegated,
+            MarinadeError::DestinationStakeMustNotBeDeactivating,
+            MarinadeError::DestinationStakeMustBeUpdated,
+            MarinadeError::InvalidDestinationStakeDelegation,
+            MarinadeError::SourceStakeMustBeDelegated,
+            MarinadeError::SourceStakeMustNotBeDeactivating,
+            MarinadeError::SourceStakeMustBeUpdated,
+            MarinadeError::InvalidSourceStakeDelegation,
+            MarinadeError::InvalidDelayedUnstakeTicket,
+            MarinadeError::ReusingDelayedUnstakeTicket,
+            MarinadeError::EmergencyUnstakingFromNonZeroScoredValidator,
+            MarinadeError::WrongValidatorDuplicationFlag,
+            MarinadeError::RedepositingMarinadeStake,
+            MarinadeError::RemovingValidatorWithBalance,
+            MarinadeError::RedelegateOverTarget,
+            MarinadeError::SourceAndDestValidatorsAreTheSame,
+            MarinadeError::UnregisteredMsolMinted,
+            MarinadeError::UnregisteredLPMinted,
+            MarinadeError::ListIndexOutOfBounds,
+            MarinadeError::ListOverflow,
+            MarinadeError::AlreadyPaused,
+            MarinadeError::NotPaused,
+            MarinadeError::ProgramIsPaused,
+            MarinadeError::InvalidPauseAuthority,
+            MarinadeError::SelectedStakeAccountHasNotEnoughFunds,
+            MarinadeError::BasisPointCentsOverflow,
+            MarinadeError::WithdrawStakeAccountIsNotEnabled,
+            MarinadeError::WithdrawStakeAccountFeeIsTooHigh,
+            MarinadeError::DelayedUnstakeFeeIsTooHigh,
+            MarinadeError::WithdrawStakeLamportsIsTooLow,
+            MarinadeError::StakeAccountRemainderTooLow,
+            MarinadeError::ShrinkingListWithDeletingContents,
+            MarinadeError::InvalidVestingSchedule,
+            MarinadeError::NothingVestedYet,
+            MarinadeError::FlashRemoveInProgress,
+            MarinadeError::FlashRemoveNotInProgress,
+            MarinadeError::FlashRemoveNotRestored,
+            MarinadeError::FlashRemoveEndMissing,
+            MarinadeError::LpSupplyNotAboveVirtual,
+            MarinadeError::KeeperTipTooHigh,
+            MarinadeError::CircuitBreakerTripped,
+            MarinadeError::RemoveLiquidityBelowMinOut,
+            MarinadeError::InvalidUnstakeFeeCurve,
+            MarinadeError::LpAccountCapExceeded,
+            MarinadeError::InvalidLpCapBypass,
+            MarinadeError::InvalidLockDuration,
+            MarinadeError::LockNotExpired,
+            MarinadeError::LockedLpBoostDisabled,
+            MarinadeError::LockedLpBoostVaultInUse,
+            MarinadeError::InvalidLockedLpPosition,
+            MarinadeError::FlashLoanInProgress,
+            MarinadeError::FlashLoanNotInProgress,
+            MarinadeError::FlashLoanRepayMissing,
+            MarinadeError::FlashLoanNotRepaid,
+            MarinadeError::LiqPoolRebalanceNotNeeded,
+            MarinadeError::LiqPoolDrained,
+            MarinadeError::LiqPoolNotDrained,
+            MarinadeError::ArbDoesNotImprovePrice,
+            MarinadeError::InvalidArbBounty,
+            MarinadeError::ArbBelowMinOut,
+            MarinadeError::InvalidClaimAmount,
+            MarinadeError::TicketTokenAccountsMissing,
+            MarinadeError::TicketAlreadyDue,
+            MarinadeError::InstantWithdrawIsCapped,
+            MarinadeError::NotEnoughIdleReserve,
+            MarinadeError::SlippageExceeded,
+            MarinadeError::ClaimTipIsTooHigh,
+            MarinadeError::TicketRegistryFull,
+            MarinadeError::InvalidTicketBatch,
+            MarinadeError::TicketNotEmpty,
+            MarinadeError::TicketEtaAccountsMissing,
+            MarinadeError::WrongDirectedStakeRecord,
+            MarinadeError::ReferralFeeIsTooHigh,
+            MarinadeError::NoReferralRewards,
+            MarinadeError::InvalidReferral,
+            MarinadeError::InvalidDepositSplit,
+            MarinadeError::InvalidStakingCapBypass,
+            MarinadeError::StakeDeactivationNotRescindable,
+            MarinadeError::InvalidStakeAccountBatch,
+            MarinadeError::PermissionlessAddDisabled,
+            MarinadeError::InvalidVoteAccount,
+            MarinadeError::ValidatorCommissionTooHigh,
+            MarinadeError::ValidatorDelinquent,
+            MarinadeError::InvalidScorer,
+            MarinadeError::InvalidOracleScores,
+            MarinadeError::WrongValidatorStakeCap,
+            MarinadeError::InvalidValidatorStakeCaps,
+            MarinadeError::DelinquentUnstakeDisabled,
+            MarinadeError::ValidatorNotDelinquent,
+            MarinadeError::MissingValidatorVote,
+            MarinadeError::ValidatorBlacklisted,
+            MarinadeError::InvalidBlacklistedValidator,
+            MarinadeError::WrongGauge,
+            MarinadeError::GaugeAlreadyTallied,
+            MarinadeError::GaugeLockNotExpired,
+            MarinadeError::InvalidGaugeLock,
+            MarinadeError::WrongValidatorHistory,
+            MarinadeError::InvalidValidatorListHash,
+            MarinadeError::WrongValidatorBond,
+            MarinadeError::InvalidBondAuthority,
+            MarinadeError::BondIsTooLow,
+            MarinadeError::RemovingScoredValidator,
+            MarinadeError::ValidatorIsProtected,
+            MarinadeError::InvalidProtectedValidator,
+            MarinadeError::InvalidValidatorIdentity,
+            MarinadeError::StakeCreditsObservedMismatch,
+            MarinadeError::UpdateWindowIsTooHigh,
+            MarinadeError::MinStakeIsTooHigh,
+            MarinadeError::CrankTipIsTooHigh,
+            MarinadeError::MissingSystemProgram,
+            MarinadeError::InvalidPendingAdminAuthority,
+            MarinadeError::ConfigIsTimelocked,
+            MarinadeError::ConfigTimelockNotElapsed,
+            MarinadeError::ConfigTimelockIsTooHigh,
+            MarinadeError::GroupIsPaused,
+            MarinadeError::InvalidPauseFlags,
+            MarinadeError::InvalidPauseGuardians,
+            MarinadeError::GuardianQuorumNotReached,
+            MarinadeError::PausedByGuardians,
+            MarinadeError::FeeChangeIsTooHigh,
+            MarinadeError::InvalidMaxFeeChangePerEpoch,
+            MarinadeError::InvalidTreasurySplit,
+            MarinadeError::MissingTreasurySplit,
+            MarinadeError::WrongTreasurySplitDestination,
+            MarinadeError::InvalidTreasuryAuthority,
+            MarinadeError::InvalidGovernanceNativeTreasury,
+            MarinadeError::MissingAdminLog,
+            MarinadeError::WrongAdminLog,
+            MarinadeError::InvalidTreasuryVestingEpochs,
+            MarinadeError::MissingTreasuryVesting,
+            MarinadeError::MissingLockedLpBoostVault,
+            MarinadeError::MissingTreasuryMsolAccount,
+            MarinadeError::StateAlreadyReallocated,
+            MarinadeError::TicketNotClosed,
+            MarinadeError::WrongRentPayer,
+            MarinadeError::MissingFeatureAccount,
+            MarinadeError::TicketNotCancelable,
+            MarinadeError::TicketAccountTooSmall,
+        ];
+        let mut reasons = BTreeSet::new();
+        for (i, error) in all.iter().enumerate() {
+            // list must follow the declaration order
+            assert_eq!(u32::from(*error), 6000 + i as u32);
+            let reason = error.reason_code();
+            assert!(!reason.is_empty());
+            assert!(reason
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'));
+            assert!(reasons.insert(reason), "duplicated reason {}", reason);
+        }
+    }
 }
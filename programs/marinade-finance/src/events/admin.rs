@@ -1,19 +1,28 @@
 use anchor_lang::prelude::*;
 
-use crate::instructions::InitializeData;
+use crate::{
+    instructions::InitializeData,
+    state::{
+        config_proposal::ConfigChange, treasury_split::TreasurySplitEntry,
+        unstake_fee_curve::FeeCurvePoint, Fee,
+    },
+};
 
 use super::{
-    BoolValueChange, FeeCentsValueChange, FeeValueChange, PubkeyValueChange, U64ValueChange,
+    BoolValueChange, FeeCentsValueChange, FeeValueChange, PubkeyValueChange, U32ValueChange,
+    U64ValueChange,
 };
 
 #[event]
 pub struct ChangeAuthorityEvent {
     pub state: Pubkey,
     pub admin_change: Option<PubkeyValueChange>,
+    pub pending_admin_change: Option<PubkeyValueChange>,
     pub validator_manager_change: Option<PubkeyValueChange>,
     pub operational_sol_account_change: Option<PubkeyValueChange>,
     pub treasury_msol_account_change: Option<PubkeyValueChange>,
     pub pause_authority_change: Option<PubkeyValueChange>,
+    pub treasury_authority_change: Option<PubkeyValueChange>,
 }
 
 #[event]
@@ -23,6 +32,10 @@ pub struct ConfigLpEvent {
     pub max_fee_change: Option<FeeValueChange>,
     pub liquidity_target_change: Option<U64ValueChange>,
     pub treasury_cut_change: Option<FeeValueChange>,
+    pub removal_dust_to_treasury_change: Option<BoolValueChange>,
+    pub removal_event_price_impact_change: Option<BoolValueChange>,
+    pub lp_account_cap_change: Option<U64ValueChange>,
+    pub max_msol_share_change: Option<FeeValueChange>,
 }
 
 #[event]
@@ -39,9 +52,32 @@ pub struct ConfigMarinadeEvent {
     pub delayed_unstake_fee_change: Option<FeeCentsValueChange>,
     pub withdraw_stake_account_fee_change: Option<FeeCentsValueChange>,
     pub max_stake_moved_per_epoch_change: Option<FeeValueChange>,
+    pub circuit_breaker_max_price_move_change: Option<FeeValueChange>,
+    pub circuit_breaker_window_slots_change: Option<U64ValueChange>,
+    pub instant_withdraw_cap_per_epoch_change: Option<U64ValueChange>,
+    pub claim_tip_change: Option<FeeValueChange>,
+    pub directed_stake_max_share_change: Option<FeeValueChange>,
+    pub min_deposit_stake_account_change: Option<U64ValueChange>,
+    pub permissionless_validator_score_change: Option<U32ValueChange>,
+    pub validator_max_commission_change: Option<FeeValueChange>,
+    pub validator_max_vote_lag_slots_change: Option<U64ValueChange>,
+    pub validator_max_stake_share_change: Option<FeeValueChange>,
+    pub delinquent_unstake_slots_change: Option<U64ValueChange>,
+    pub commission_ceiling_change: Option<FeeValueChange>,
+    pub gauge_stake_share_change: Option<FeeValueChange>,
+    pub gauge_lock_epochs_change: Option<U64ValueChange>,
+    pub bond_stake_multiplier_change: Option<U32ValueChange>,
+    pub rebalance_threshold_change: Option<FeeValueChange>,
+    pub crank_tip_change: Option<FeeValueChange>,
+    pub max_crank_tip_change: Option<U64ValueChange>,
+    pub config_timelock_seconds_change: Option<U64ValueChange>,
 }
 
-// TODO: ConfigValidatorSystemEvent?
+#[event]
+pub struct ConfigValidatorSystemEvent {
+    pub state: Pubkey,
+    pub extra_runs_change: U32ValueChange,
+}
 
 #[event]
 pub struct InitializeEvent {
@@ -79,3 +115,200 @@ pub struct ReallocStakeListEvent {
     pub count: u32,
     pub new_capacity: u32,
 }
+
+#[event]
+pub struct ReallocStateEvent {
+    pub state: Pubkey,
+    pub old_len: u32,
+    pub new_len: u32,
+}
+
+#[event]
+pub struct ForceSyncLpSupplyUpEvent {
+    pub state: Pubkey,
+    pub lp_supply_change: U64ValueChange,
+}
+
+#[event]
+pub struct ConfigLockedLpEvent {
+    pub state: Pubkey,
+    pub boost_vault_change: Option<PubkeyValueChange>,
+    pub boost_fee_share_change: FeeValueChange,
+}
+
+#[event]
+pub struct ConfigUnstakeFeeCurveEvent {
+    pub state: Pubkey,
+    pub old_points: Vec<FeeCurvePoint>, // empty = liq_pool linear fee
+    pub new_points: Vec<FeeCurvePoint>,
+}
+
+#[event]
+pub struct AddLpCapBypassEvent {
+    pub state: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[event]
+pub struct RemoveLpCapBypassEvent {
+    pub state: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[event]
+pub struct EmergencyDrainLiqPoolEvent {
+    pub state: Pubkey,
+    pub escrow: Pubkey,
+    pub msol_escrow: Pubkey,
+    pub drained_lamports: u64,
+    pub drained_msol: u64,
+    pub lp_supply: u64,
+}
+
+#[event]
+pub struct RestoreLiqPoolEvent {
+    pub state: Pubkey,
+    pub drained_lamports: u64,
+    pub drained_msol: u64,
+    pub restored_lamports: u64,
+    pub restored_msol: u64,
+}
+
+#[event]
+pub struct SetLpMintMetadataEvent {
+    pub state: Pubkey,
+    pub lp_mint: Pubkey,
+    pub metadata: Pubkey,
+    pub created: bool, // false = updated
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct ResetCircuitBreakerEvent {
+    pub state: Pubkey,
+    pub reference_price: u64,
+    pub reference_slot: u64,
+}
+
+#[event]
+pub struct InitReferralEvent {
+    pub state: Pubkey,
+    pub partner: Pubkey,
+    pub referral: Pubkey,
+    pub fee: Fee,
+}
+
+#[event]
+pub struct AddStakingCapBypassEvent {
+    pub state: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[event]
+pub struct RemoveStakingCapBypassEvent {
+    pub state: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[event]
+pub struct InitScoreOracleEvent {
+    pub state: Pubkey,
+    pub score_oracle: Pubkey,
+    pub scorer: Pubkey,
+    pub max_age_epochs: u64,
+}
+
+#[event]
+pub struct SetValidatorStakeCapEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+    pub old_max_stake: u64,
+    pub new_max_stake: u64,
+}
+
+#[event]
+pub struct BlacklistValidatorEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+}
+
+#[event]
+pub struct UnblacklistValidatorEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+}
+
+#[event]
+pub struct SlashValidatorBondEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+    pub lamports: u64,
+    pub bonded_lamports: u64,
+}
+
+#[event]
+pub struct ProposeConfigEvent {
+    pub state: Pubkey,
+    pub config_proposal: Pubkey,
+    pub id: u64,
+    pub change: ConfigChange,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct ExecuteConfigProposalEvent {
+    pub state: Pubkey,
+    pub config_proposal: Pubkey,
+    pub id: u64,
+}
+
+#[event]
+pub struct CancelConfigProposalEvent {
+    pub state: Pubkey,
+    pub config_proposal: Pubkey,
+    pub id: u64,
+}
+
+#[event]
+pub struct SetPauseFlagsEvent {
+    pub state: Pubkey,
+    pub old_pause_flags: u8,
+    pub new_pause_flags: u8,
+}
+
+#[event]
+pub struct SetPauseGuardiansEvent {
+    pub state: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct GuardianPauseEvent {
+    pub state: Pubkey,
+    pub old_pause_flags: u8,
+    pub new_pause_flags: u8,
+    pub guardians: Vec<Pubkey>, // signers of the quorum
+}
+
+#[event]
+pub struct SetTreasurySplitEvent {
+    pub state: Pubkey,
+    pub entries: Vec<TreasurySplitEntry>,
+}
+
+#[event]
+pub struct InitAdminLogEvent {
+    pub state: Pubkey,
+    pub admin_log: Pubkey,
+}
+
+#[event]
+pub struct InitTreasuryVestingEvent {
+    pub state: Pubkey,
+    pub treasury_vesting: Pubkey,
+    pub msol_escrow: Pubkey,
+    pub vesting_epochs: u64,
+}
@@ -125,3 +125,124 @@ pub struct UpdateDeactivatedEvent {
     pub total_virtual_staked_lamports: u64,
     pub msol_supply: u64,
 }
+
+#[event]
+pub struct CircuitBreakerTrippedEvent {
+    pub state: Pubkey,
+    pub reference_price: u64,
+    pub reference_slot: u64,
+    pub msol_price: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RebalanceLiqPoolEvent {
+    pub state: Pubkey,
+    pub sol_leg_balance: u64,
+    pub msol_leg_balance: u64,
+    pub reserve_balance: u64,
+    pub max_msol_share: Fee,
+    pub msol_burned: u64,
+    pub lamports: u64, // moved from the reserve to the sol leg
+}
+
+#[event]
+pub struct CrankClaimEvent {
+    pub state: Pubkey,
+    pub ticket: Pubkey,
+    pub beneficiary: Pubkey,
+    pub cranker: Pubkey,
+    pub lamports: u64, // claimed, including the tip
+    pub tip: u64,
+}
+
+#[event]
+pub struct ApplyOracleScoresEvent {
+    pub state: Pubkey,
+    pub epoch: u64,
+    pub start_index: u32,
+    pub end_index: u32, // exclusive
+    pub applied: u32,   // the others kept their last known score
+    pub total_validator_score: u32,
+}
+
+#[event]
+pub struct UnstakeDelinquentEvent {
+    pub state: Pubkey,
+    pub epoch: u64,
+    pub stake_index: u32,
+    pub stake_account: Pubkey,
+    pub validator_index: u32,
+    pub validator_vote: Pubkey,
+    pub last_vote_slot: Option<u64>,
+    pub old_score: u32,
+    pub unstaked_amount: u64,
+    pub total_active_balance: u64,
+    pub emergency_cooling_down: u64,
+}
+
+#[event]
+pub struct CommissionViolationEvent {
+    pub state: Pubkey,
+    pub epoch: u64,
+    pub validator_index: u32,
+    pub validator_vote: Pubkey,
+    pub commission: Fee,
+    pub commission_ceiling: Fee,
+    pub old_score: u32,
+}
+
+#[event]
+pub struct TallyGaugeEvent {
+    pub state: Pubkey,
+    pub epoch: u64,
+    pub gauge: Pubkey,
+    pub validator: Pubkey,
+    pub old_weight: u64,
+    pub new_weight: u64,
+    pub total_gauge_weight: u64,
+}
+
+#[event]
+pub struct InitValidatorHistoryEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+    pub validator_history: Pubkey,
+}
+
+#[event]
+pub struct CrankTipEvent {
+    pub state: Pubkey,
+    pub tip_to: Pubkey,
+    pub cranked_lamports: u64,
+    pub tip: u64,
+}
+
+#[event]
+pub struct InitEpochReportEvent {
+    pub state: Pubkey,
+    pub epoch: u64,
+    pub epoch_report: Pubkey,
+    pub stake_accounts_to_update: u32,
+}
+
+#[event]
+pub struct StakeFlaggedEvent {
+    pub state: Pubkey,
+    pub epoch: u64,
+    pub stake_index: u32,
+    pub stake_account: Pubkey,
+    pub validator_vote: Pubkey,
+    pub last_update_delegated_lamports: u64,
+    pub observed_lamports: u64, // lower than last_update_delegated_lamports
+    pub flag_recorded: bool,    // StakeRecord::flagged set, the stake list has room for it
+}
+
+#[event]
+pub struct ClaimVestedFeesEvent {
+    pub state: Pubkey,
+    pub epoch: u64,
+    pub msol_amount: u64,
+    pub vested: u64,
+    pub claimed: u64,
+}
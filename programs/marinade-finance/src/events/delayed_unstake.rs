@@ -11,6 +11,8 @@ pub struct ClaimEvent {
     pub reserve_balance: u64,
     pub user_balance: u64,
     pub amount: u64,
+    pub ticket_balance_left: u64, // 0 = ticket closed
+    pub transfer_sol_to: Pubkey,  // beneficiary unless tokenized or signed by the beneficiary
 }
 
 #[event]
@@ -29,3 +31,92 @@ pub struct OrderUnstakeEvent {
     pub total_virtual_staked_lamports: u64,
     pub msol_supply: u64,
 }
+
+#[event]
+pub struct SplitTicketEvent {
+    pub state: Pubkey,
+    pub ticket: Pubkey,
+    pub new_ticket: Pubkey,
+    pub beneficiary: Pubkey,
+    pub new_ticket_beneficiary: Pubkey,
+    pub ticket_epoch: u64,
+    pub ticket_balance_left: u64,
+    pub new_ticket_amount: u64,
+    pub circulating_ticket_count: u64,
+}
+
+#[event]
+pub struct TransferTicketEvent {
+    pub state: Pubkey,
+    pub ticket: Pubkey,
+    pub old_beneficiary: Pubkey,
+    pub new_beneficiary: Pubkey,
+    pub lamports_amount: u64,
+    pub ticket_epoch: u64,
+}
+
+#[event]
+pub struct TicketTokenizedEvent {
+    pub state: Pubkey,
+    pub ticket: Pubkey,
+    pub ticket_mint: Pubkey,
+    pub ticket_token_account: Pubkey,
+    pub lamports_amount: u64,
+}
+
+#[event]
+pub struct CancelUnstakeTicketEvent {
+    pub state: Pubkey,
+    pub ticket: Pubkey,
+    pub beneficiary: Pubkey,
+    pub ticket_epoch: u64,
+    pub lamports_amount: u64,
+    pub minted_msol: u64,
+    pub circulating_ticket_balance: u64,
+    pub circulating_ticket_count: u64,
+    // MSOL price used
+    pub total_virtual_staked_lamports: u64,
+    pub msol_supply: u64,
+}
+
+#[event]
+pub struct InitTicketRegistryEvent {
+    pub state: Pubkey,
+    pub beneficiary: Pubkey,
+    pub ticket_registry: Pubkey,
+}
+
+#[event]
+pub struct CloseRegistryEvent {
+    pub state: Pubkey,
+    pub beneficiary: Pubkey,
+    pub ticket_registry: Pubkey,
+    pub tickets_listed: u32,
+}
+
+#[event]
+pub struct PruneTicketRegistryEvent {
+    pub state: Pubkey,
+    pub beneficiary: Pubkey,
+    pub ticket_registry: Pubkey,
+    pub pruned: u32,
+    pub tickets_listed: u32,
+}
+
+#[event]
+pub struct CloseTicketEtaEvent {
+    pub ticket_eta: Pubkey,
+    pub ticket: Pubkey,
+    pub rent_payer: Pubkey,
+    pub rent: u64,
+}
+
+#[event]
+pub struct GcTicketEvent {
+    pub state: Pubkey,
+    pub ticket: Pubkey,
+    pub beneficiary: Pubkey,
+    pub rent_payer: Pubkey,
+    pub rent: u64,
+    pub circulating_ticket_count: u64,
+}
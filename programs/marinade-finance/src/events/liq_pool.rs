@@ -17,6 +17,67 @@ pub struct AddLiquidityEvent {
     pub msol_supply: u64,
 }
 
+#[event]
+pub struct BestEffortUnstakeEvent {
+    pub state: Pubkey,
+    pub msol_owner: Pubkey,
+    pub msol_amount: u64,
+    pub reserve_msol: u64,
+    pub reserve_lamports: u64,
+    pub pool_msol: u64,
+    pub pool_lamports: u64,
+    pub ticket: Option<Pubkey>,
+    pub ticket_lamports: u64,
+    pub msol_left: u64, // not unstaked, left in the user account
+}
+
+#[event]
+pub struct ArbLiqPoolEvent {
+    pub state: Pubkey,
+    pub arbitrageur: Pubkey,
+    pub sol_leg_balance: u64,
+    pub msol_leg_balance: u64,
+    pub lamports: u64, // paid into the sol leg
+    pub msol_out: u64,
+    pub fee_before: Fee,
+    pub fee_after: Fee,
+    pub msol_price: u64,
+}
+
+#[event]
+pub struct AddLiquidityMsolEvent {
+    pub state: Pubkey,
+    pub msol_owner: Pubkey,
+    pub user_msol_balance: u64,
+    pub user_lp_balance: u64,
+    pub msol_leg_balance: u64,
+    pub lp_supply: u64,
+    pub msol_added_amount: u64,
+    pub msol_added_value: u64, // lamports, at the mSOL price below
+    pub lp_minted: u64,
+    // MSOL price used
+    pub total_virtual_staked_lamports: u64,
+    pub msol_supply: u64,
+}
+
+#[event]
+pub struct ZapAddLiquidityEvent {
+    pub state: Pubkey,
+    pub sol_owner: Pubkey,
+    pub user_sol_balance: u64,
+    pub user_lp_balance: u64,
+    pub sol_leg_balance: u64,
+    pub msol_leg_balance: u64,
+    pub lp_supply: u64,
+    pub sol_added_amount: u64,  // to the SOL leg
+    pub sol_staked_amount: u64, // to the reserve, for the mSOL minted into the mSOL leg
+    pub msol_minted: u64,
+    pub lp_minted: u64,
+    // MSOL price used
+    pub total_virtual_staked_lamports: u64,
+    pub msol_supply: u64,
+}
+
 #[event]
 pub struct LiquidUnstakeEvent {
     pub state: Pubkey,
@@ -29,6 +90,7 @@ pub struct LiquidUnstakeEvent {
     pub msol_amount: u64,
     pub msol_fee: u64,
     pub treasury_msol_cut: u64,
+    pub locked_lp_boost: u64, // mSOL of the fee sent to the locked LP boost vault
     pub sol_amount: u64,
     // params used
     pub lp_liquidity_target: u64,
@@ -37,6 +99,67 @@ pub struct LiquidUnstakeEvent {
     pub treasury_cut: Fee,
 }
 
+#[event]
+pub struct FlashLoanSolEvent {
+    pub state: Pubkey,
+    pub sol_leg_balance: u64,
+    pub lamports: u64,
+    pub sol_leg_target: u64,
+}
+
+#[event]
+pub struct FlashRepaySolEvent {
+    pub state: Pubkey,
+    pub repaid: u64,
+    pub sol_leg_balance: u64,
+    pub sol_leg_target: u64,
+}
+
+#[event]
+pub struct LpFeeAccruedEvent {
+    pub state: Pubkey,
+    pub epoch: u64,
+    pub msol_fee: u64, // part of the fee kept by the LPs
+    pub fee_lamports: u64,
+    pub lp_supply: u64,
+    pub epoch_fees: u64,
+    pub previous_epoch_fees: u64,
+    pub lifetime_fees: u64,
+    pub fees_per_lp: u128, // see LpFeeAccrual::fees_per_lp
+}
+
+#[event]
+pub struct LockLiquidityEvent {
+    pub state: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub lp_amount: u64,
+    pub unlock_slot: u64,
+    pub weight: u64,
+    pub total_weight: u64,
+}
+
+#[event]
+pub struct ExtendLockEvent {
+    pub state: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub boost_msol: u64, // paid out before re-weighting
+    pub unlock_slot: u64,
+    pub weight: u64,
+    pub total_weight: u64,
+}
+
+#[event]
+pub struct UnlockLiquidityEvent {
+    pub state: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub lp_amount: u64,
+    pub boost_msol: u64,
+    pub total_weight: u64,
+}
+
 #[event]
 pub struct RemoveLiquidityEvent {
     pub state: Pubkey,
@@ -49,4 +172,100 @@ pub struct RemoveLiquidityEvent {
     pub lp_burned: u64,
     pub sol_out_amount: u64,
     pub msol_out_amount: u64,
+    pub treasury_msol_dust: u64,
+    // only when State::removal_event_price_impact is on
+    pub price_impact: Option<RemovalPriceImpact>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct RemovalPriceImpact {
+    pub sol_leg_ratio_before_bps: u16, // SOL share of the pool value, see LiqPool::leg_ratio_bps
+    pub sol_leg_ratio_after_bps: u16,
+    pub effective_price: u64, // lamports delivered per LP token, State::PRICE_DENOMINATOR based like msol_price
+}
+
+#[event]
+pub struct RemoveLiquidityVestedEvent {
+    pub state: Pubkey,
+    pub vesting: Pubkey,
+    pub beneficiary: Pubkey,
+    pub lp_burned: u64,
+    pub sol_out_amount: u64,
+    pub msol_out_amount: u64,
+    pub start_slot: u64,
+    pub cliff_slot: u64,
+    pub end_slot: u64,
+}
+
+#[event]
+pub struct ClaimVestedEvent {
+    pub state: Pubkey,
+    pub vesting: Pubkey,
+    pub beneficiary: Pubkey,
+    pub slot: u64,
+    pub lamports: u64,
+    pub msol_amount: u64,
+    pub claimed_lamports: u64,
+    pub claimed_msol: u64,
+}
+
+#[event]
+pub struct RemoveLiquidityReceiptEvent {
+    pub state: Pubkey,
+    pub receipt_mint: Pubkey,
+    pub owner: Pubkey,
+    pub slot: u64,
+    pub lp_burned: u64,
+    pub sol_out_amount: u64,
+    pub msol_out_amount: u64,
+}
+
+#[event]
+pub struct BeginFlashRemoveLiquidityEvent {
+    pub state: Pubkey,
+    pub sol_leg_balance: u64,
+    pub msol_leg_balance: u64,
+    pub lp_tokens: u64,
+    pub sol_out_amount: u64,
+    pub msol_out_amount: u64,
+    pub sol_leg_target: u64,
+    pub msol_leg_target: u64,
+}
+
+#[event]
+pub struct EndFlashRemoveLiquidityEvent {
+    pub state: Pubkey,
+    pub sol_leg_balance: u64,
+    pub msol_leg_balance: u64,
+    pub sol_leg_target: u64,
+    pub msol_leg_target: u64,
+}
+
+#[event]
+pub struct KeeperTipEvent {
+    pub state: Pubkey,
+    pub keeper: Pubkey,
+    pub sol_out_amount: u64,
+    pub tip: u64,
+}
+
+#[event]
+pub struct RemoveLiquiditySolOnlyEvent {
+    pub state: Pubkey,
+    pub lp_burned: u64,
+    pub sol_out_amount: u64, // SOL part of the removal
+    pub msol_swapped: u64,   // mSOL part of the removal, liquid-unstaked
+    pub msol_fee: u64,
+    pub treasury_msol_cut: u64,
+    pub swap_lamports: u64, // paid for msol_swapped on top of sol_out_amount
+}
+
+#[event]
+pub struct RemoveLiquidityRebalanceEvent {
+    pub state: Pubkey,
+    pub lp_burned: u64,
+    pub sol_converted: u64,
+    pub msol_minted: u64,
+    pub sol_leg_ratio_bps: u16, // before the conversion
+    pub lp_credited: u64,
 }
@@ -10,8 +10,31 @@ pub struct AddValidatorEvent {
     pub score: u32,
 }
 
-// TODO: EmergencyUnstake
-// TODO: PartialUnstake
+#[event]
+pub struct EmergencyUnstakeEvent {
+    pub state: Pubkey,
+    pub epoch: u64,
+    pub stake_index: u32,
+    pub stake_account: Pubkey,
+    pub validator_index: u32,
+    pub validator_vote: Pubkey,
+    pub unstake_amount: u64,
+}
+
+#[event]
+pub struct PartialUnstakeEvent {
+    pub state: Pubkey,
+    pub epoch: u64,
+    pub stake_index: u32,
+    pub stake_account: Pubkey,
+    pub validator_index: u32,
+    pub validator_vote: Pubkey,
+    pub desired_unstake_amount: u64,
+    pub validator_stake_target: u64,
+    pub unstake_amount: u64,
+    // None when the whole stake account is deactivated
+    pub split_stake_account: Option<Pubkey>,
+}
 
 #[event]
 pub struct RemoveValidatorEvent {
@@ -28,3 +51,39 @@ pub struct SetValidatorScoreEvent {
     pub index: u32,
     pub score_change: U32ValueChange,
 }
+
+#[event]
+pub struct ResetDirectedStakeEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+    pub directed_lamports: u64,
+    pub total_directed_stake: u64,
+}
+
+#[event]
+pub struct UpdateOracleScoresEvent {
+    pub state: Pubkey,
+    pub epoch: u64,
+    pub start_index: u32,
+    pub count: u32,
+}
+
+#[event]
+pub struct SetValidatorScoresBulkEvent {
+    pub state: Pubkey,
+    pub validator_list_hash: [u8; 32],
+    pub updated: u32,
+    pub total_validator_score: u32,
+}
+
+#[event]
+pub struct ProtectValidatorEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+}
+
+#[event]
+pub struct UnprotectValidatorEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::validator_profile::ValidatorProfileData;
+
 #[event]
 pub struct DepositStakeAccountEvent {
     pub state: Pubkey,
@@ -57,3 +59,149 @@ pub struct WithdrawStakeAccountEvent {
     pub total_virtual_staked_lamports: u64,
     pub msol_supply: u64,
 }
+
+#[event]
+pub struct InstantWithdrawEvent {
+    pub state: Pubkey,
+    pub epoch: u64,
+    pub msol_owner: Pubkey,
+    pub user_msol_balance: u64,
+    pub reserve_balance: u64,
+    pub msol_burned: u64,
+    pub lamports: u64,
+    pub instant_withdrawn: u64, // this epoch, including lamports
+    // MSOL price used
+    pub total_virtual_staked_lamports: u64,
+    pub msol_supply: u64,
+}
+
+#[event]
+pub struct InitDirectedStakeEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+    pub directed_stake: Pubkey,
+}
+
+#[event]
+pub struct DepositDirectedEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+    pub lamports: u64,
+    pub directed_lamports: u64, // to the validator, after the deposit
+    pub total_directed_stake: u64,
+}
+
+#[event]
+pub struct ReferredDepositEvent {
+    pub state: Pubkey,
+    pub partner: Pubkey,
+    pub msol_received: u64, // by the depositor
+    pub credited_msol: u64, // to the partner, rewards of the earlier referred deposits
+    pub accrued_msol: u64,
+}
+
+#[event]
+pub struct ClaimReferralRewardsEvent {
+    pub state: Pubkey,
+    pub partner: Pubkey,
+    pub msol_amount: u64,
+    pub accrued_msol: u64, // left unpaid
+    pub claimed_msol: u64,
+}
+
+#[event]
+pub struct DepositStakeAccountSplitEvent {
+    pub state: Pubkey,
+    pub stake: Pubkey,           // deposited
+    pub remainder_stake: Pubkey, // left to the user
+    pub deposited: u64,
+    pub remainder: u64,
+}
+
+#[event]
+pub struct ReactivateStakeAccountEvent {
+    pub state: Pubkey,
+    pub stake: Pubkey,
+    pub validator: Pubkey,
+    pub delegated: u64,
+}
+
+#[event]
+pub struct DepositStakeAccountsEvent {
+    pub state: Pubkey,
+    pub stake: Pubkey,       // deposited
+    pub merged: Vec<Pubkey>, // into stake before the deposit, closed
+}
+
+#[event]
+pub struct GiftDepositEvent {
+    pub state: Pubkey,
+    pub payer: Pubkey,
+    pub beneficiary: Pubkey, // owner of the mSOL account credited
+    pub lamports: u64,
+    pub msol_received: u64,
+    pub memo: Option<[u8; 64]>,
+}
+
+#[event]
+pub struct InitGaugeEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+    pub gauge: Pubkey,
+}
+
+#[event]
+pub struct LockGaugeEvent {
+    pub state: Pubkey,
+    pub gauge: Pubkey,
+    pub validator: Pubkey,
+    pub gauge_lock: Pubkey,
+    pub owner: Pubkey,
+    pub msol_amount: u64,
+    pub unlock_epoch: u64,
+    pub locked_msol: u64,
+}
+
+#[event]
+pub struct UnlockGaugeEvent {
+    pub state: Pubkey,
+    pub gauge: Pubkey,
+    pub validator: Pubkey,
+    pub gauge_lock: Pubkey,
+    pub owner: Pubkey,
+    pub msol_amount: u64,
+    pub locked_msol: u64,
+}
+
+#[event]
+pub struct InitValidatorBondEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+    pub validator_bond: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct DepositValidatorBondEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+    pub depositor: Pubkey,
+    pub lamports: u64,
+    pub bonded_lamports: u64,
+}
+
+#[event]
+pub struct WithdrawValidatorBondEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+    pub lamports: u64,
+    pub bonded_lamports: u64,
+}
+
+#[event]
+pub struct ValidatorProfileEvent {
+    pub state: Pubkey,
+    pub validator: Pubkey,
+    pub identity: Pubkey,
+    pub data: ValidatorProfileData,
+}
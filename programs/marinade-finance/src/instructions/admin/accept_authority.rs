@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    events::{admin::ChangeAuthorityEvent, PubkeyValueChange},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        constraint = state.pending_admin_authority == new_admin_authority.key()
+            @ MarinadeError::InvalidPendingAdminAuthority
+    )]
+    pub state: Account<'info, State>,
+    // nominated by change_authority
+    pub new_admin_authority: Signer<'info>,
+}
+
+impl<'info> AcceptAuthority<'info> {
+    // fn accept_authority()
+    pub fn process(&mut self) -> Result<()> {
+        let old = self.state.admin_authority;
+        self.state.admin_authority = self.new_admin_authority.key();
+        self.state.pending_admin_authority = Pubkey::default();
+
+        emit!(ChangeAuthorityEvent {
+            state: self.state.key(),
+            admin_change: Some(PubkeyValueChange {
+                old,
+                new: self.new_admin_authority.key(),
+            }),
+            pending_admin_change: Some(PubkeyValueChange {
+                old: self.new_admin_authority.key(),
+                new: Pubkey::default(),
+            }),
+            validator_manager_change: None,
+            operational_sol_account_change: None,
+            treasury_msol_account_change: None,
+            pause_authority_change: None,
+            treasury_authority_change: None,
+        });
+        Ok(())
+    }
+}
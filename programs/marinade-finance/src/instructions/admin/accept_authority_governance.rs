@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    events::{admin::ChangeAuthorityEvent, PubkeyValueChange},
+    State,
+};
+
+// accept_authority signed by a governance proposal transaction works as well,
+// this one lets the admin nomination of a governance native treasury complete without a vote
+#[derive(Accounts)]
+pub struct AcceptAuthorityGovernance<'info> {
+    #[account(mut)]
+    pub state: Account<'info, State>,
+
+    /// CHECK: any deployment of the spl-governance program
+    #[account(executable)]
+    pub governance_program: UncheckedAccount<'info>,
+    /// CHECK: owner checked, seed of the native treasury
+    #[account(owner = governance_program.key())]
+    pub governance: UncheckedAccount<'info>,
+}
+
+impl<'info> AcceptAuthorityGovernance<'info> {
+    pub const NATIVE_TREASURY_SEED: &'static [u8] = b"native-treasury";
+
+    // fn accept_authority_governance()
+    pub fn process(&mut self) -> Result<()> {
+        let (native_treasury, _) = Pubkey::find_program_address(
+            &[
+                Self::NATIVE_TREASURY_SEED,
+                &self.governance.key().to_bytes(),
+            ],
+            self.governance_program.key,
+        );
+        // only the governance can sign for this PDA, no need for its signature
+        require_keys_eq!(
+            self.state.pending_admin_authority,
+            native_treasury,
+            MarinadeError::InvalidGovernanceNativeTreasury
+        );
+        let old = self.state.admin_authority;
+        self.state.admin_authority = native_treasury;
+        self.state.pending_admin_authority = Pubkey::default();
+
+        emit!(ChangeAuthorityEvent {
+            state: self.state.key(),
+            admin_change: Some(PubkeyValueChange {
+                old,
+                new: native_treasury,
+            }),
+            pending_admin_change: Some(PubkeyValueChange {
+                old: native_treasury,
+                new: Pubkey::default(),
+            }),
+            validator_manager_change: None,
+            operational_sol_account_change: None,
+            treasury_msol_account_change: None,
+            pause_authority_change: None,
+            treasury_authority_change: None,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::admin::AddLpCapBypassEvent, state::lp_cap_bypass::LpCapBypass, MarinadeError, State,
+};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddLpCapBypass<'info> {
+    #[account(has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<LpCapBypass>(),
+        seeds = [
+            &state.key().to_bytes(),
+            LpCapBypass::SEED,
+            &wallet.to_bytes()
+        ],
+        bump
+    )]
+    pub lp_cap_bypass: Account<'info, LpCapBypass>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddLpCapBypass<'info> {
+    // fn add_lp_cap_bypass() // exempt `wallet` from the liq-pool deposit caps
+    pub fn process(&mut self, wallet: Pubkey) -> Result<()> {
+        self.lp_cap_bypass.set_inner(LpCapBypass {
+            state_address: self.state.key(),
+            wallet,
+        });
+
+        emit!(AddLpCapBypassEvent {
+            state: self.state.key(),
+            wallet,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::admin::AddStakingCapBypassEvent, state::staking_cap_bypass::StakingCapBypass,
+    MarinadeError, State,
+};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddStakingCapBypass<'info> {
+    #[account(has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<StakingCapBypass>(),
+        seeds = [
+            &state.key().to_bytes(),
+            StakingCapBypass::SEED,
+            &wallet.to_bytes()
+        ],
+        bump
+    )]
+    pub staking_cap_bypass: Account<'info, StakingCapBypass>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddStakingCapBypass<'info> {
+    // fn add_staking_cap_bypass() // exempt `wallet` from staking_sol_cap
+    pub fn process(&mut self, wallet: Pubkey) -> Result<()> {
+        self.staking_cap_bypass.set_inner(StakingCapBypass {
+            state_address: self.state.key(),
+            wallet,
+        });
+
+        emit!(AddStakingCapBypassEvent {
+            state: self.state.key(),
+            wallet,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::admin::BlacklistValidatorEvent, state::validator_blacklist::BlacklistedValidator,
+    MarinadeError, State,
+};
+
+#[derive(Accounts)]
+#[instruction(validator_vote: Pubkey)]
+pub struct BlacklistValidator<'info> {
+    #[account(mut, has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<BlacklistedValidator>(),
+        seeds = [
+            &state.key().to_bytes(),
+            BlacklistedValidator::SEED,
+            &validator_vote.to_bytes()
+        ],
+        bump
+    )]
+    pub blacklisted_validator: Account<'info, BlacklistedValidator>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BlacklistValidator<'info> {
+    // fn blacklist_validator()
+    pub fn process(&mut self, validator_vote: Pubkey) -> Result<()> {
+        self.state.blacklisted_validator_count += 1;
+        self.blacklisted_validator.set_inner(BlacklistedValidator {
+            state_address: self.state.key(),
+            validator_account: validator_vote,
+        });
+
+        emit!(BlacklistValidatorEvent {
+            state: self.state.key(),
+            validator: validator_vote,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError, events::admin::CancelConfigProposalEvent,
+    state::config_proposal::ConfigProposal, State,
+};
+
+#[derive(Accounts)]
+pub struct CancelConfigProposal<'info> {
+    #[account(has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = rent_payer,
+        seeds = [
+            &state.key().to_bytes(),
+            ConfigProposal::SEED,
+            &config_proposal.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub config_proposal: Box<Account<'info, ConfigProposal>>,
+    /// CHECK: refunded, checked by has_one
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+impl<'info> CancelConfigProposal<'info> {
+    // fn cancel_config_proposal()
+    pub fn process(&mut self) -> Result<()> {
+        emit!(CancelConfigProposalEvent {
+            state: self.state.key(),
+            config_proposal: self.config_proposal.key(),
+            id: self.config_proposal.id,
+        });
+        Ok(())
+    }
+}
@@ -18,18 +18,19 @@ pub struct ChangeAuthority<'info> {
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, AnchorSerialize, AnchorDeserialize)]
 pub struct ChangeAuthorityData {
+    // nominated only, the new admin signs accept_authority. Pubkey::default() cancels a nomination
     pub admin: Option<Pubkey>,
     pub validator_manager: Option<Pubkey>,
     pub operational_sol_account: Option<Pubkey>,
-    pub treasury_msol_account: Option<Pubkey>,
     pub pause_authority: Option<Pubkey>,
+    pub treasury_authority: Option<Pubkey>,
 }
 
 impl<'info> ChangeAuthority<'info> {
     pub fn process(&mut self, data: ChangeAuthorityData) -> Result<()> {
-        let admin_change = if let Some(admin) = data.admin {
-            let old = self.state.admin_authority;
-            self.state.admin_authority = admin;
+        let pending_admin_change = if let Some(admin) = data.admin {
+            let old = self.state.pending_admin_authority;
+            self.state.pending_admin_authority = admin;
             Some(PubkeyValueChange { old, new: admin })
         } else {
             None
@@ -58,18 +59,6 @@ impl<'info> ChangeAuthority<'info> {
                 None
             };
 
-        let treasury_msol_account_change =
-            if let Some(treasury_msol_account) = data.treasury_msol_account {
-                let old = self.state.treasury_msol_account;
-                self.state.treasury_msol_account = treasury_msol_account;
-                Some(PubkeyValueChange {
-                    old,
-                    new: treasury_msol_account,
-                })
-            } else {
-                None
-            };
-
         let pause_authority_change = if let Some(pause_authority) = data.pause_authority {
             let old = self.state.pause_authority;
             self.state.pause_authority = pause_authority;
@@ -81,13 +70,26 @@ impl<'info> ChangeAuthority<'info> {
             None
         };
 
+        let treasury_authority_change = if let Some(treasury_authority) = data.treasury_authority {
+            let old = self.state.treasury_authority;
+            self.state.treasury_authority = treasury_authority;
+            Some(PubkeyValueChange {
+                old,
+                new: treasury_authority,
+            })
+        } else {
+            None
+        };
+
         emit!(ChangeAuthorityEvent {
             state: self.state.key(),
-            admin_change,
+            admin_change: None,
+            pending_admin_change,
             validator_manager_change,
             operational_sol_account_change,
-            treasury_msol_account_change,
-            pause_authority_change
+            treasury_msol_account_change: None,
+            pause_authority_change,
+            treasury_authority_change,
         });
 
         Ok(())
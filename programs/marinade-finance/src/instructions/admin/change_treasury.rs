@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    events::{admin::ChangeAuthorityEvent, PubkeyValueChange},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct ChangeTreasury<'info> {
+    #[account(
+        mut,
+        has_one = treasury_authority @ MarinadeError::InvalidTreasuryAuthority
+    )]
+    pub state: Account<'info, State>,
+    pub treasury_authority: Signer<'info>,
+}
+
+impl<'info> ChangeTreasury<'info> {
+    // fn change_treasury()
+    pub fn process(&mut self, treasury_msol_account: Pubkey) -> Result<()> {
+        let old = self.state.treasury_msol_account;
+        self.state.treasury_msol_account = treasury_msol_account;
+
+        emit!(ChangeAuthorityEvent {
+            state: self.state.key(),
+            admin_change: None,
+            pending_admin_change: None,
+            validator_manager_change: None,
+            operational_sol_account_change: None,
+            treasury_msol_account_change: Some(PubkeyValueChange {
+                old,
+                new: treasury_msol_account,
+            }),
+            pause_authority_change: None,
+            treasury_authority_change: None,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{
+    events::{admin::ConfigLockedLpEvent, FeeValueChange, PubkeyValueChange},
+    state::{locked_lp::LockedLp, Fee},
+    MarinadeError, State,
+};
+
+#[derive(Accounts)]
+pub struct ConfigLockedLp<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ MarinadeError::InvalidAdminAuthority
+    )]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        token::mint = state.msol_mint,
+        token::authority = locked_lp_authority
+    )]
+    pub boost_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LockedLp::AUTHORITY_SEED
+        ],
+        bump
+    )]
+    pub locked_lp_authority: UncheckedAccount<'info>,
+}
+
+impl<'info> ConfigLockedLp<'info> {
+    // fn config_locked_lp()
+    pub fn process(&mut self, boost_fee_share: Fee, authority_bump_seed: u8) -> Result<()> {
+        let locked_lp = &mut self.state.locked_lp;
+        let boost_vault_change = if locked_lp.boost_vault != self.boost_vault.key() {
+            // the boost accrued so far is paid from the current vault
+            require_eq!(
+                locked_lp.total_weight,
+                0,
+                MarinadeError::LockedLpBoostVaultInUse
+            );
+            let old = locked_lp.boost_vault;
+            locked_lp.boost_vault = self.boost_vault.key();
+            locked_lp.authority_bump_seed = authority_bump_seed;
+            Some(PubkeyValueChange {
+                old,
+                new: locked_lp.boost_vault,
+            })
+        } else {
+            None
+        };
+
+        let boost_fee_share_change = FeeValueChange {
+            old: locked_lp.boost_fee_share,
+            new: boost_fee_share,
+        };
+        locked_lp.boost_fee_share = boost_fee_share;
+        locked_lp.validate()?;
+
+        emit!(ConfigLockedLpEvent {
+            state: self.state.key(),
+            boost_vault_change,
+            boost_fee_share_change,
+        });
+        Ok(())
+    }
+}
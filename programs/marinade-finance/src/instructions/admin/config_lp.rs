@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    events::{admin::ConfigLpEvent, FeeValueChange, U64ValueChange},
-    state::Fee,
+    events::{admin::ConfigLpEvent, BoolValueChange, FeeValueChange, U64ValueChange},
+    state::{fee_rate_limiter::FeeRateLimiter, Fee},
     MarinadeError, State,
 };
 
@@ -12,6 +12,10 @@ pub struct ConfigLpParams {
     pub max_fee: Option<Fee>,
     pub liquidity_target: Option<u64>,
     pub treasury_cut: Option<Fee>,
+    pub removal_dust_to_treasury: Option<bool>,
+    pub removal_event_price_impact: Option<bool>,
+    pub lp_account_cap: Option<u64>,
+    pub max_msol_share: Option<Fee>,
 }
 
 #[derive(Accounts)]
@@ -25,34 +29,55 @@ pub struct ConfigLp<'info> {
 }
 
 impl<'info> ConfigLp<'info> {
-    pub fn process(
-        &mut self,
+    pub fn process(&mut self, params: ConfigLpParams) -> Result<()> {
+        require_eq!(
+            self.state.config_timelock_seconds,
+            0,
+            MarinadeError::ConfigIsTimelocked
+        );
+        Self::apply(&mut self.state, params)
+    }
+
+    /// applies the changes, directly or from an executed ConfigProposal
+    pub fn apply(
+        state: &mut Account<'info, State>,
         ConfigLpParams {
             min_fee,
             max_fee,
             liquidity_target,
             treasury_cut,
+            removal_dust_to_treasury,
+            removal_event_price_impact,
+            lp_account_cap,
+            max_msol_share,
         }: ConfigLpParams,
     ) -> Result<()> {
+        let epoch = Clock::get()?.epoch;
         let min_fee_change = if let Some(min_fee) = min_fee {
-            let old = self.state.liq_pool.lp_min_fee;
-            self.state.liq_pool.lp_min_fee = min_fee;
+            let old = state.liq_pool.lp_min_fee;
+            state
+                .fee_rate_limiter
+                .check_change(FeeRateLimiter::LP_MIN_FEE, old, min_fee, epoch)?;
+            state.liq_pool.lp_min_fee = min_fee;
             Some(FeeValueChange { old, new: min_fee })
         } else {
             None
         };
 
         let max_fee_change = if let Some(max_fee) = max_fee {
-            let old = self.state.liq_pool.lp_max_fee;
-            self.state.liq_pool.lp_max_fee = max_fee;
+            let old = state.liq_pool.lp_max_fee;
+            state
+                .fee_rate_limiter
+                .check_change(FeeRateLimiter::LP_MAX_FEE, old, max_fee, epoch)?;
+            state.liq_pool.lp_max_fee = max_fee;
             Some(FeeValueChange { old, new: max_fee })
         } else {
             None
         };
 
         let liquidity_target_change = if let Some(liquidity_target) = liquidity_target {
-            let old = self.state.liq_pool.lp_liquidity_target;
-            self.state.liq_pool.lp_liquidity_target = liquidity_target;
+            let old = state.liq_pool.lp_liquidity_target;
+            state.liq_pool.lp_liquidity_target = liquidity_target;
             Some(U64ValueChange {
                 old,
                 new: liquidity_target,
@@ -62,8 +87,14 @@ impl<'info> ConfigLp<'info> {
         };
 
         let treasury_cut_change = if let Some(treasury_cut) = treasury_cut {
-            let old = self.state.liq_pool.treasury_cut;
-            self.state.liq_pool.treasury_cut = treasury_cut;
+            let old = state.liq_pool.treasury_cut;
+            state.fee_rate_limiter.check_change(
+                FeeRateLimiter::TREASURY_CUT,
+                old,
+                treasury_cut,
+                epoch,
+            )?;
+            state.liq_pool.treasury_cut = treasury_cut;
             Some(FeeValueChange {
                 old,
                 new: treasury_cut,
@@ -72,14 +103,65 @@ impl<'info> ConfigLp<'info> {
             None
         };
 
-        self.state.liq_pool.validate()?;
+        let removal_dust_to_treasury_change =
+            if let Some(removal_dust_to_treasury) = removal_dust_to_treasury {
+                let old = state.removal_dust_to_treasury;
+                state.removal_dust_to_treasury = removal_dust_to_treasury;
+                Some(BoolValueChange {
+                    old,
+                    new: removal_dust_to_treasury,
+                })
+            } else {
+                None
+            };
+
+        let removal_event_price_impact_change =
+            if let Some(removal_event_price_impact) = removal_event_price_impact {
+                let old = state.removal_event_price_impact;
+                state.removal_event_price_impact = removal_event_price_impact;
+                Some(BoolValueChange {
+                    old,
+                    new: removal_event_price_impact,
+                })
+            } else {
+                None
+            };
+
+        let lp_account_cap_change = if let Some(lp_account_cap) = lp_account_cap {
+            let old = state.lp_account_cap;
+            state.lp_account_cap = lp_account_cap;
+            Some(U64ValueChange {
+                old,
+                new: lp_account_cap,
+            })
+        } else {
+            None
+        };
+
+        let max_msol_share_change = if let Some(max_msol_share) = max_msol_share {
+            max_msol_share.check()?;
+            let old = state.liq_pool_max_msol_share;
+            state.liq_pool_max_msol_share = max_msol_share;
+            Some(FeeValueChange {
+                old,
+                new: max_msol_share,
+            })
+        } else {
+            None
+        };
+
+        state.liq_pool.validate()?;
 
         emit!(ConfigLpEvent {
-            state: self.state.key(),
+            state: state.key(),
             min_fee_change,
             max_fee_change,
             liquidity_target_change,
-            treasury_cut_change
+            treasury_cut_change,
+            removal_dust_to_treasury_change,
+            removal_event_price_impact_change,
+            lp_account_cap_change,
+            max_msol_share_change,
         });
         Ok(())
     }
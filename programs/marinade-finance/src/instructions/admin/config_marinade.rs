@@ -1,10 +1,10 @@
 use crate::events::{
     admin::ConfigMarinadeEvent, BoolValueChange, FeeCentsValueChange, FeeValueChange,
-    U64ValueChange,
+    U32ValueChange, U64ValueChange,
 };
 use crate::{
     require_lte,
-    state::{stake_system::StakeSystem, Fee, FeeCents},
+    state::{fee_rate_limiter::FeeRateLimiter, stake_system::StakeSystem, Fee, FeeCents},
     MarinadeError, State,
 };
 use anchor_lang::prelude::*;
@@ -22,6 +22,25 @@ pub struct ConfigMarinadeParams {
     pub delayed_unstake_fee: Option<FeeCents>,
     pub withdraw_stake_account_fee: Option<FeeCents>,
     pub max_stake_moved_per_epoch: Option<Fee>,
+    pub circuit_breaker_max_price_move: Option<Fee>,
+    pub circuit_breaker_window_slots: Option<u64>,
+    pub instant_withdraw_cap_per_epoch: Option<u64>,
+    pub claim_tip: Option<Fee>,
+    pub directed_stake_max_share: Option<Fee>,
+    pub min_deposit_stake_account: Option<u64>,
+    pub permissionless_validator_score: Option<u32>,
+    pub validator_max_commission: Option<Fee>,
+    pub validator_max_vote_lag_slots: Option<u64>,
+    pub validator_max_stake_share: Option<Fee>,
+    pub delinquent_unstake_slots: Option<u64>,
+    pub commission_ceiling: Option<Fee>,
+    pub gauge_stake_share: Option<Fee>,
+    pub gauge_lock_epochs: Option<u64>,
+    pub bond_stake_multiplier: Option<u32>,
+    pub rebalance_threshold: Option<Fee>,
+    pub crank_tip: Option<Fee>,
+    pub max_crank_tip: Option<u64>,
+    pub config_timelock_seconds: Option<u64>,
 }
 
 #[derive(Accounts)]
@@ -36,8 +55,18 @@ pub struct ConfigMarinade<'info> {
 
 impl<'info> ConfigMarinade<'info> {
     // fn config_marinade()
-    pub fn process(
-        &mut self,
+    pub fn process(&mut self, params: ConfigMarinadeParams) -> Result<()> {
+        require_eq!(
+            self.state.config_timelock_seconds,
+            0,
+            MarinadeError::ConfigIsTimelocked
+        );
+        Self::apply(&mut self.state, params)
+    }
+
+    /// applies the changes, directly or from an executed ConfigProposal
+    pub fn apply(
+        state: &mut Account<'info, State>,
         ConfigMarinadeParams {
             rewards_fee,
             slots_for_stake_delta,
@@ -50,6 +79,25 @@ impl<'info> ConfigMarinade<'info> {
             delayed_unstake_fee,
             withdraw_stake_account_fee,
             max_stake_moved_per_epoch,
+            circuit_breaker_max_price_move,
+            circuit_breaker_window_slots,
+            instant_withdraw_cap_per_epoch,
+            claim_tip,
+            directed_stake_max_share,
+            min_deposit_stake_account,
+            permissionless_validator_score,
+            validator_max_commission,
+            validator_max_vote_lag_slots,
+            validator_max_stake_share,
+            delinquent_unstake_slots,
+            commission_ceiling,
+            gauge_stake_share,
+            gauge_lock_epochs,
+            bond_stake_multiplier,
+            rebalance_threshold,
+            crank_tip,
+            max_crank_tip,
+            config_timelock_seconds,
         }: ConfigMarinadeParams,
     ) -> Result<()> {
         let rewards_fee_change = if let Some(rewards_fee) = rewards_fee {
@@ -58,8 +106,14 @@ impl<'info> ConfigMarinade<'info> {
                 State::MAX_REWARD_FEE,
                 MarinadeError::RewardsFeeIsTooHigh
             );
-            let old = self.state.reward_fee;
-            self.state.reward_fee = rewards_fee;
+            let old = state.reward_fee;
+            state.fee_rate_limiter.check_change(
+                FeeRateLimiter::REWARD_FEE,
+                old,
+                rewards_fee,
+                Clock::get()?.epoch,
+            )?;
+            state.reward_fee = rewards_fee;
             Some(FeeValueChange {
                 old,
                 new: rewards_fee,
@@ -75,8 +129,13 @@ impl<'info> ConfigMarinade<'info> {
                     StakeSystem::MIN_UPDATE_WINDOW,
                     MarinadeError::UpdateWindowIsTooLow
                 );
-                let old = self.state.stake_system.slots_for_stake_delta;
-                self.state.stake_system.slots_for_stake_delta = slots_for_stake_delta;
+                require_gte!(
+                    StakeSystem::MAX_UPDATE_WINDOW,
+                    slots_for_stake_delta,
+                    MarinadeError::UpdateWindowIsTooHigh
+                );
+                let old = state.stake_system.slots_for_stake_delta;
+                state.stake_system.slots_for_stake_delta = slots_for_stake_delta;
                 Some(U64ValueChange {
                     old,
                     new: slots_for_stake_delta,
@@ -91,8 +150,13 @@ impl<'info> ConfigMarinade<'info> {
                 State::MIN_STAKE_LOWER_LIMIT,
                 MarinadeError::MinStakeIsTooLow
             );
-            let old = self.state.stake_system.min_stake;
-            self.state.stake_system.min_stake = min_stake;
+            require_gte!(
+                State::MIN_STAKE_UPPER_LIMIT,
+                min_stake,
+                MarinadeError::MinStakeIsTooHigh
+            );
+            let old = state.stake_system.min_stake;
+            state.stake_system.min_stake = min_stake;
             Some(U64ValueChange {
                 old,
                 new: min_stake,
@@ -105,8 +169,8 @@ impl<'info> ConfigMarinade<'info> {
             // It is not dangerous to skip value checks because it is deposit only action
             // We can use u64::MAX to stop accepting deposits
             // or 0 to accept 1 lamport
-            let old = self.state.min_deposit;
-            self.state.min_deposit = min_deposit;
+            let old = state.min_deposit;
+            state.min_deposit = min_deposit;
             Some(U64ValueChange {
                 old,
                 new: min_deposit,
@@ -121,8 +185,8 @@ impl<'info> ConfigMarinade<'info> {
                 State::MAX_WITHDRAW_ATOM,
                 MarinadeError::MinWithdrawIsTooHigh
             );
-            let old = self.state.min_withdraw;
-            self.state.min_withdraw = min_withdraw;
+            let old = state.min_withdraw;
+            state.min_withdraw = min_withdraw;
             Some(U64ValueChange {
                 old,
                 new: min_withdraw,
@@ -132,8 +196,8 @@ impl<'info> ConfigMarinade<'info> {
         };
 
         let staking_sol_cap_change = if let Some(staking_sol_cap) = staking_sol_cap {
-            let old = self.state.staking_sol_cap;
-            self.state.staking_sol_cap = staking_sol_cap;
+            let old = state.staking_sol_cap;
+            state.staking_sol_cap = staking_sol_cap;
             Some(U64ValueChange {
                 old,
                 new: staking_sol_cap,
@@ -143,8 +207,8 @@ impl<'info> ConfigMarinade<'info> {
         };
 
         let liquidity_sol_cap_change = if let Some(liquidity_sol_cap) = liquidity_sol_cap {
-            let old = self.state.liq_pool.liquidity_sol_cap;
-            self.state.liq_pool.liquidity_sol_cap = liquidity_sol_cap;
+            let old = state.liq_pool.liquidity_sol_cap;
+            state.liq_pool.liquidity_sol_cap = liquidity_sol_cap;
             Some(U64ValueChange {
                 old,
                 new: liquidity_sol_cap,
@@ -155,8 +219,8 @@ impl<'info> ConfigMarinade<'info> {
 
         let withdraw_stake_account_enabled_change =
             if let Some(withdraw_stake_account_enabled) = withdraw_stake_account_enabled {
-                let old = self.state.withdraw_stake_account_enabled;
-                self.state.withdraw_stake_account_enabled = withdraw_stake_account_enabled;
+                let old = state.withdraw_stake_account_enabled;
+                state.withdraw_stake_account_enabled = withdraw_stake_account_enabled;
                 Some(BoolValueChange {
                     old,
                     new: withdraw_stake_account_enabled,
@@ -171,8 +235,8 @@ impl<'info> ConfigMarinade<'info> {
                 State::MAX_DELAYED_UNSTAKE_FEE,
                 MarinadeError::DelayedUnstakeFeeIsTooHigh
             );
-            let old = self.state.delayed_unstake_fee;
-            self.state.delayed_unstake_fee = delayed_unstake_fee;
+            let old = state.delayed_unstake_fee;
+            state.delayed_unstake_fee = delayed_unstake_fee;
             Some(FeeCentsValueChange {
                 old,
                 new: delayed_unstake_fee,
@@ -188,8 +252,8 @@ impl<'info> ConfigMarinade<'info> {
                     State::MAX_WITHDRAW_STAKE_ACCOUNT_FEE,
                     MarinadeError::WithdrawStakeAccountFeeIsTooHigh
                 );
-                let old = self.state.withdraw_stake_account_fee;
-                self.state.withdraw_stake_account_fee = withdraw_stake_account_fee;
+                let old = state.withdraw_stake_account_fee;
+                state.withdraw_stake_account_fee = withdraw_stake_account_fee;
                 Some(FeeCentsValueChange {
                     old,
                     new: withdraw_stake_account_fee,
@@ -204,8 +268,8 @@ impl<'info> ConfigMarinade<'info> {
                 // we need to move the same stake multiple times,
                 // for example to fix some incident
                 // max_stake_moved_per_epoch.check()?;
-                let old = self.state.max_stake_moved_per_epoch;
-                self.state.max_stake_moved_per_epoch = max_stake_moved_per_epoch;
+                let old = state.max_stake_moved_per_epoch;
+                state.max_stake_moved_per_epoch = max_stake_moved_per_epoch;
                 Some(FeeValueChange {
                     old,
                     new: max_stake_moved_per_epoch,
@@ -214,8 +278,252 @@ impl<'info> ConfigMarinade<'info> {
                 None
             };
 
+        let circuit_breaker_max_price_move_change =
+            if let Some(max_price_move) = circuit_breaker_max_price_move {
+                max_price_move.check()?;
+                let old = state.price_circuit_breaker.max_price_move;
+                state.price_circuit_breaker.max_price_move = max_price_move;
+                Some(FeeValueChange {
+                    old,
+                    new: max_price_move,
+                })
+            } else {
+                None
+            };
+
+        let circuit_breaker_window_slots_change =
+            if let Some(window_slots) = circuit_breaker_window_slots {
+                let old = state.price_circuit_breaker.window_slots;
+                state.price_circuit_breaker.window_slots = window_slots;
+                Some(U64ValueChange {
+                    old,
+                    new: window_slots,
+                })
+            } else {
+                None
+            };
+
+        let instant_withdraw_cap_per_epoch_change =
+            if let Some(instant_withdraw_cap_per_epoch) = instant_withdraw_cap_per_epoch {
+                let old = state.instant_withdraw_cap_per_epoch;
+                state.instant_withdraw_cap_per_epoch = instant_withdraw_cap_per_epoch;
+                Some(U64ValueChange {
+                    old,
+                    new: instant_withdraw_cap_per_epoch,
+                })
+            } else {
+                None
+            };
+
+        let claim_tip_change = if let Some(claim_tip) = claim_tip {
+            require_lte!(
+                claim_tip,
+                State::MAX_CLAIM_TIP,
+                MarinadeError::ClaimTipIsTooHigh
+            );
+            let old = state.claim_tip;
+            state.claim_tip = claim_tip;
+            Some(FeeValueChange {
+                old,
+                new: claim_tip,
+            })
+        } else {
+            None
+        };
+
+        let directed_stake_max_share_change =
+            if let Some(directed_stake_max_share) = directed_stake_max_share {
+                directed_stake_max_share.check()?;
+                let old = state.directed_stake_max_share;
+                state.directed_stake_max_share = directed_stake_max_share;
+                Some(FeeValueChange {
+                    old,
+                    new: directed_stake_max_share,
+                })
+            } else {
+                None
+            };
+
+        let min_deposit_stake_account_change =
+            if let Some(min_deposit_stake_account) = min_deposit_stake_account {
+                // deposit only, same as min_deposit
+                let old = state.min_deposit_stake_account;
+                state.min_deposit_stake_account = min_deposit_stake_account;
+                Some(U64ValueChange {
+                    old,
+                    new: min_deposit_stake_account,
+                })
+            } else {
+                None
+            };
+
+        let permissionless_validator_score_change =
+            if let Some(permissionless_validator_score) = permissionless_validator_score {
+                let old = state.validator_criteria.permissionless_score;
+                state.validator_criteria.permissionless_score = permissionless_validator_score;
+                Some(U32ValueChange {
+                    old,
+                    new: permissionless_validator_score,
+                })
+            } else {
+                None
+            };
+
+        let validator_max_commission_change =
+            if let Some(validator_max_commission) = validator_max_commission {
+                validator_max_commission.check()?;
+                let old = state.validator_criteria.max_commission;
+                state.validator_criteria.max_commission = validator_max_commission;
+                Some(FeeValueChange {
+                    old,
+                    new: validator_max_commission,
+                })
+            } else {
+                None
+            };
+
+        let validator_max_vote_lag_slots_change =
+            if let Some(validator_max_vote_lag_slots) = validator_max_vote_lag_slots {
+                let old = state.validator_criteria.max_vote_lag_slots;
+                state.validator_criteria.max_vote_lag_slots = validator_max_vote_lag_slots;
+                Some(U64ValueChange {
+                    old,
+                    new: validator_max_vote_lag_slots,
+                })
+            } else {
+                None
+            };
+
+        let validator_max_stake_share_change =
+            if let Some(validator_max_stake_share) = validator_max_stake_share {
+                validator_max_stake_share.check()?;
+                let old = state.validator_max_stake_share;
+                state.validator_max_stake_share = validator_max_stake_share;
+                Some(FeeValueChange {
+                    old,
+                    new: validator_max_stake_share,
+                })
+            } else {
+                None
+            };
+
+        let delinquent_unstake_slots_change =
+            if let Some(delinquent_unstake_slots) = delinquent_unstake_slots {
+                let old = state.delinquent_unstake_slots;
+                state.delinquent_unstake_slots = delinquent_unstake_slots;
+                Some(U64ValueChange {
+                    old,
+                    new: delinquent_unstake_slots,
+                })
+            } else {
+                None
+            };
+
+        let commission_ceiling_change = if let Some(commission_ceiling) = commission_ceiling {
+            commission_ceiling.check()?;
+            let old = state.commission_ceiling;
+            state.commission_ceiling = commission_ceiling;
+            Some(FeeValueChange {
+                old,
+                new: commission_ceiling,
+            })
+        } else {
+            None
+        };
+
+        let gauge_stake_share_change = if let Some(gauge_stake_share) = gauge_stake_share {
+            gauge_stake_share.check()?;
+            let old = state.gauge_stake_share;
+            state.gauge_stake_share = gauge_stake_share;
+            Some(FeeValueChange {
+                old,
+                new: gauge_stake_share,
+            })
+        } else {
+            None
+        };
+
+        let gauge_lock_epochs_change = if let Some(gauge_lock_epochs) = gauge_lock_epochs {
+            let old = state.gauge_lock_epochs;
+            state.gauge_lock_epochs = gauge_lock_epochs;
+            Some(U64ValueChange {
+                old,
+                new: gauge_lock_epochs,
+            })
+        } else {
+            None
+        };
+
+        let bond_stake_multiplier_change =
+            if let Some(bond_stake_multiplier) = bond_stake_multiplier {
+                let old = state.bond_stake_multiplier;
+                state.bond_stake_multiplier = bond_stake_multiplier;
+                Some(U32ValueChange {
+                    old,
+                    new: bond_stake_multiplier,
+                })
+            } else {
+                None
+            };
+
+        let rebalance_threshold_change = if let Some(rebalance_threshold) = rebalance_threshold {
+            rebalance_threshold.check()?;
+            let old = state.rebalance_threshold;
+            state.rebalance_threshold = rebalance_threshold;
+            Some(FeeValueChange {
+                old,
+                new: rebalance_threshold,
+            })
+        } else {
+            None
+        };
+
+        let crank_tip_change = if let Some(crank_tip) = crank_tip {
+            require_lte!(
+                crank_tip,
+                State::MAX_CRANK_TIP,
+                MarinadeError::CrankTipIsTooHigh
+            );
+            let old = state.crank_tip;
+            state.crank_tip = crank_tip;
+            Some(FeeValueChange {
+                old,
+                new: crank_tip,
+            })
+        } else {
+            None
+        };
+
+        let max_crank_tip_change = if let Some(max_crank_tip) = max_crank_tip {
+            let old = state.max_crank_tip;
+            state.max_crank_tip = max_crank_tip;
+            Some(U64ValueChange {
+                old,
+                new: max_crank_tip,
+            })
+        } else {
+            None
+        };
+
+        let config_timelock_seconds_change =
+            if let Some(config_timelock_seconds) = config_timelock_seconds {
+                require_gte!(
+                    State::MAX_CONFIG_TIMELOCK_SECONDS,
+                    config_timelock_seconds,
+                    MarinadeError::ConfigTimelockIsTooHigh
+                );
+                let old = state.config_timelock_seconds;
+                state.config_timelock_seconds = config_timelock_seconds;
+                Some(U64ValueChange {
+                    old,
+                    new: config_timelock_seconds,
+                })
+            } else {
+                None
+            };
+
         emit!(ConfigMarinadeEvent {
-            state: self.state.key(),
+            state: state.key(),
             rewards_fee_change,
             slots_for_stake_delta_change,
             min_stake_change,
@@ -227,6 +535,25 @@ impl<'info> ConfigMarinade<'info> {
             delayed_unstake_fee_change,
             withdraw_stake_account_fee_change,
             max_stake_moved_per_epoch_change,
+            circuit_breaker_max_price_move_change,
+            circuit_breaker_window_slots_change,
+            instant_withdraw_cap_per_epoch_change,
+            claim_tip_change,
+            directed_stake_max_share_change,
+            min_deposit_stake_account_change,
+            permissionless_validator_score_change,
+            validator_max_commission_change,
+            validator_max_vote_lag_slots_change,
+            validator_max_stake_share_change,
+            delinquent_unstake_slots_change,
+            commission_ceiling_change,
+            gauge_stake_share_change,
+            gauge_lock_epochs_change,
+            bond_stake_multiplier_change,
+            rebalance_threshold_change,
+            crank_tip_change,
+            max_crank_tip_change,
+            config_timelock_seconds_change,
         });
 
         Ok(())
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::admin::ConfigUnstakeFeeCurveEvent,
+    state::unstake_fee_curve::{FeeCurvePoint, UnstakeFeeCurve},
+    MarinadeError, State,
+};
+
+#[derive(Accounts)]
+pub struct ConfigUnstakeFeeCurve<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ MarinadeError::InvalidAdminAuthority
+    )]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+}
+
+impl<'info> ConfigUnstakeFeeCurve<'info> {
+    // fn config_unstake_fee_curve()
+    pub fn process(&mut self, points: Vec<FeeCurvePoint>) -> Result<()> {
+        let old_points = self.state.unstake_fee_curve.points().to_vec();
+        self.state.unstake_fee_curve = UnstakeFeeCurve::new(&points)?;
+
+        emit!(ConfigUnstakeFeeCurveEvent {
+            state: self.state.key(),
+            old_points,
+            new_points: points,
+        });
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{MarinadeError, State};
+use crate::{
+    events::{admin::ConfigValidatorSystemEvent, U32ValueChange},
+    MarinadeError, State,
+};
 
 #[derive(Accounts)]
 pub struct ConfigValidatorSystem<'info> {
@@ -15,7 +18,15 @@ pub struct ConfigValidatorSystem<'info> {
 
 impl<'info> ConfigValidatorSystem<'info> {
     pub fn process(&mut self, extra_runs: u32) -> Result<()> {
+        let old = self.state.stake_system.extra_stake_delta_runs;
         self.state.stake_system.extra_stake_delta_runs = extra_runs; // TODO: think about is it stake or validator thing?
+        emit!(ConfigValidatorSystemEvent {
+            state: self.state.key(),
+            extra_runs_change: U32ValueChange {
+                old,
+                new: extra_runs,
+            },
+        });
         Ok(())
     }
 }
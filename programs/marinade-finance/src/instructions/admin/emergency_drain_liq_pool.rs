@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{
+    transfer as transfer_token, Token, TokenAccount, Transfer as TransferToken,
+};
+
+use crate::{
+    error::MarinadeError,
+    events::admin::EmergencyDrainLiqPoolEvent,
+    state::{liq_pool::LiqPool, liq_pool_drain::LiqPoolDrain},
+    State,
+};
+
+// incident response: moves both legs out of reach of the liq-pool instructions
+#[derive(Accounts)]
+pub struct EmergencyDrainLiqPool<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ MarinadeError::InvalidAdminAuthority
+    )]
+    pub state: Box<Account<'info, State>>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::SOL_LEG_SEED
+        ],
+        bump = state.liq_pool.sol_leg_bump_seed
+    )]
+    pub liq_pool_sol_leg_pda: SystemAccount<'info>,
+    #[account(
+        mut,
+        address = state.liq_pool.msol_leg
+    )]
+    pub liq_pool_msol_leg: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::MSOL_LEG_AUTHORITY_SEED
+        ],
+        bump = state.liq_pool.msol_leg_authority_bump_seed
+    )]
+    pub liq_pool_msol_leg_authority: UncheckedAccount<'info>,
+
+    // holds the SOL leg and owns msol_escrow
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPoolDrain::ESCROW_SEED
+        ],
+        bump
+    )]
+    pub escrow: SystemAccount<'info>,
+    #[account(
+        mut,
+        token::mint = state.msol_mint,
+        token::authority = escrow
+    )]
+    pub msol_escrow: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> EmergencyDrainLiqPool<'info> {
+    // fn emergency_drain_liq_pool()
+    pub fn process(&mut self, escrow_bump: u8) -> Result<()> {
+        require!(self.state.paused, MarinadeError::NotPaused);
+        self.state.flash_remove.check_not_in_progress()?;
+        self.state.flash_loan.check_not_in_progress()?;
+
+        // the sol leg keeps its rent exempt reserve
+        let drained_lamports = self
+            .liq_pool_sol_leg_pda
+            .lamports()
+            .saturating_sub(self.state.rent_exempt_for_token_acc);
+        let drained_msol = self.liq_pool_msol_leg.amount;
+        self.state.liq_pool_drain.drain(
+            escrow_bump,
+            self.msol_escrow.key(),
+            drained_lamports,
+            drained_msol,
+        )?;
+
+        if drained_lamports > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.liq_pool_sol_leg_pda.to_account_info(),
+                        to: self.escrow.to_account_info(),
+                    },
+                    &[&[
+                        &self.state.key().to_bytes(),
+                        LiqPool::SOL_LEG_SEED,
+                        &[self.state.liq_pool.sol_leg_bump_seed],
+                    ]],
+                ),
+                drained_lamports,
+            )?;
+        }
+
+        if drained_msol > 0 {
+            transfer_token(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferToken {
+                        from: self.liq_pool_msol_leg.to_account_info(),
+                        to: self.msol_escrow.to_account_info(),
+                        authority: self.liq_pool_msol_leg_authority.to_account_info(),
+                    },
+                    &[&[
+                        &self.state.key().to_bytes(),
+                        LiqPool::MSOL_LEG_AUTHORITY_SEED,
+                        &[self.state.liq_pool.msol_leg_authority_bump_seed],
+                    ]],
+                ),
+                drained_msol,
+            )?;
+        }
+
+        emit!(EmergencyDrainLiqPoolEvent {
+            state: self.state.key(),
+            escrow: self.escrow.key(),
+            msol_escrow: self.msol_escrow.key(),
+            drained_lamports,
+            drained_msol,
+            lp_supply: self.state.liq_pool.lp_supply,
+        });
+
+        Ok(())
+    }
+}
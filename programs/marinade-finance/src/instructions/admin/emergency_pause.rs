@@ -2,11 +2,12 @@ use anchor_lang::prelude::*;
 
 use crate::{
     error::MarinadeError,
-    events::admin::{EmergencyPauseEvent, ResumeEvent},
+    events::admin::{EmergencyPauseEvent, ResumeEvent, SetPauseFlagsEvent},
+    state::pause_flags::PauseFlags,
     State,
 };
 
-// this account struct is used for pause() and resume() instructions (see lib.rs)
+// this account struct is used for pause(), resume() and set_pause_flags() instructions (see lib.rs)
 #[derive(Accounts)]
 pub struct EmergencyPause<'info> {
     #[account(
@@ -36,4 +37,26 @@ impl<'info> EmergencyPause<'info> {
         });
         Ok(())
     }
+
+    /// pauses the PauseFlags groups set in pause_flags and resumes the others
+    pub fn set_pause_flags(&mut self, pause_flags: u8) -> Result<()> {
+        require_eq!(
+            pause_flags & !PauseFlags::ALL,
+            0,
+            MarinadeError::InvalidPauseFlags
+        );
+        require_eq!(
+            pause_flags & self.state.guardian_pause_flags,
+            self.state.guardian_pause_flags,
+            MarinadeError::PausedByGuardians
+        );
+        let old_pause_flags = self.state.pause_flags;
+        self.state.pause_flags = pause_flags;
+        emit!(SetPauseFlagsEvent {
+            state: self.state.key(),
+            old_pause_flags,
+            new_pause_flags: pause_flags,
+        });
+        Ok(())
+    }
 }
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    events::admin::{ExecuteConfigProposalEvent, SetPauseFlagsEvent},
+    state::config_proposal::{ConfigChange, ConfigProposal},
+    State,
+};
+
+use super::{ConfigLp, ConfigMarinade};
+
+#[derive(Accounts)]
+pub struct ExecuteConfigProposal<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ MarinadeError::InvalidAdminAuthority
+    )]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = rent_payer,
+        seeds = [
+            &state.key().to_bytes(),
+            ConfigProposal::SEED,
+            &config_proposal.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub config_proposal: Box<Account<'info, ConfigProposal>>,
+    /// CHECK: refunded, checked by has_one
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ExecuteConfigProposal<'info> {
+    // fn execute_config_proposal()
+    pub fn process(&mut self) -> Result<()> {
+        require_gte!(
+            self.clock.unix_timestamp,
+            self.config_proposal
+                .executable_at(self.state.config_timelock_seconds),
+            MarinadeError::ConfigTimelockNotElapsed
+        );
+
+        match self.config_proposal.change.clone() {
+            ConfigChange::Marinade(params) => ConfigMarinade::apply(&mut self.state, *params)?,
+            ConfigChange::Lp(params) => ConfigLp::apply(&mut self.state, params)?,
+            ConfigChange::ResumeGuardianPause(pause_flags) => {
+                let old_pause_flags = self.state.pause_flags;
+                self.state.guardian_pause_flags &= !pause_flags;
+                self.state.pause_flags &= !pause_flags;
+                emit!(SetPauseFlagsEvent {
+                    state: self.state.key(),
+                    old_pause_flags,
+                    new_pause_flags: self.state.pause_flags,
+                });
+            }
+        }
+
+        emit!(ExecuteConfigProposalEvent {
+            state: self.state.key(),
+            config_proposal: self.config_proposal.key(),
+            id: self.config_proposal.id,
+        });
+        Ok(())
+    }
+}
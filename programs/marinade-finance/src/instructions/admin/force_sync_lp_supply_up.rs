@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    error::MarinadeError,
+    events::{admin::ForceSyncLpSupplyUpEvent, U64ValueChange},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct ForceSyncLpSupplyUp<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ MarinadeError::InvalidAdminAuthority
+    )]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(address = state.liq_pool.lp_mint)]
+    pub lp_mint: Account<'info, Mint>,
+}
+
+impl<'info> ForceSyncLpSupplyUp<'info> {
+    // fn force_sync_lp_supply_up() // accept LP minted outside of the program
+    pub fn process(&mut self) -> Result<()> {
+        let new = self.lp_mint.supply;
+        let old = self.state.liq_pool.force_sync_lp_supply_up(new)?;
+
+        emit!(ForceSyncLpSupplyUpEvent {
+            state: self.state.key(),
+            lp_supply_change: U64ValueChange { old, new },
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    events::admin::GuardianPauseEvent,
+    state::{pause_flags::PauseFlags, pause_guardians::PauseGuardians},
+    State,
+};
+
+// the guardians sign as remaining accounts
+#[derive(Accounts)]
+pub struct GuardianPause<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            PauseGuardians::SEED
+        ],
+        bump
+    )]
+    pub pause_guardians: Box<Account<'info, PauseGuardians>>,
+}
+
+impl<'info> GuardianPause<'info> {
+    // fn guardian_pause()
+    pub fn process(&mut self, pause_flags: u8, signers: &[AccountInfo<'info>]) -> Result<()> {
+        require!(
+            pause_flags != 0 && pause_flags & !PauseFlags::ALL == 0,
+            MarinadeError::InvalidPauseFlags
+        );
+        let guardians = self.pause_guardians.check_quorum(signers)?;
+
+        let old_pause_flags = self.state.pause_flags;
+        self.state.pause_flags |= pause_flags;
+        self.state.guardian_pause_flags |= pause_flags;
+
+        emit!(GuardianPauseEvent {
+            state: self.state.key(),
+            old_pause_flags,
+            new_pause_flags: self.state.pause_flags,
+            guardians,
+        });
+        Ok(())
+    }
+}
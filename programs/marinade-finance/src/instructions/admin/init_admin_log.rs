@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    events::admin::InitAdminLogEvent,
+    state::admin_log::{AdminLog, AdminLogEntry, ADMIN_LOG_CAPACITY},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct InitAdminLog<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ MarinadeError::InvalidAdminAuthority
+    )]
+    pub state: Box<Account<'info, State>>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + AdminLog {
+            state_address: Pubkey::default(),
+            count: 0,
+            entries: [AdminLogEntry::default(); ADMIN_LOG_CAPACITY],
+        }.try_to_vec().unwrap().len(),
+        seeds = [
+            &state.key().to_bytes(),
+            AdminLog::SEED
+        ],
+        bump
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitAdminLog<'info> {
+    /// from now on the privileged instructions require the log
+    // fn init_admin_log()
+    pub fn process(&mut self) -> Result<()> {
+        self.admin_log.state_address = self.state.key();
+        self.admin_log.count = 0;
+        self.admin_log.entries = [AdminLogEntry::default(); ADMIN_LOG_CAPACITY];
+        self.state.admin_log_enabled = true;
+
+        emit!(InitAdminLogEvent {
+            state: self.state.key(),
+            admin_log: self.admin_log.key(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    events::admin::SetPauseGuardiansEvent,
+    state::pause_guardians::{PauseGuardians, MAX_PAUSE_GUARDIANS},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct InitPauseGuardians<'info> {
+    #[account(has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Box<Account<'info, State>>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<PauseGuardians>(),
+        seeds = [
+            &state.key().to_bytes(),
+            PauseGuardians::SEED
+        ],
+        bump
+    )]
+    pub pause_guardians: Box<Account<'info, PauseGuardians>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitPauseGuardians<'info> {
+    // fn init_pause_guardians()
+    pub fn process(&mut self, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        self.pause_guardians.state_address = self.state.key();
+        self.pause_guardians.guardians = [Pubkey::default(); MAX_PAUSE_GUARDIANS];
+        self.pause_guardians.set(&guardians, threshold)?;
+
+        emit!(SetPauseGuardiansEvent {
+            state: self.state.key(),
+            guardians,
+            threshold,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::admin::InitReferralEvent,
+    require_lte,
+    state::{referral::ReferralState, Fee},
+    MarinadeError, State,
+};
+
+#[derive(Accounts)]
+#[instruction(partner: Pubkey)]
+pub struct InitReferral<'info> {
+    #[account(has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<ReferralState>(),
+        seeds = [
+            &state.key().to_bytes(),
+            ReferralState::SEED,
+            &partner.to_bytes()
+        ],
+        bump
+    )]
+    pub referral: Box<Account<'info, ReferralState>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitReferral<'info> {
+    // fn init_referral() // register a partner credited with `fee` of the rewards of the mSOL it refers
+    pub fn process(&mut self, partner: Pubkey, fee: Fee) -> Result<()> {
+        fee.check()?;
+        require_lte!(
+            fee,
+            ReferralState::MAX_FEE,
+            MarinadeError::ReferralFeeIsTooHigh
+        );
+
+        self.referral.set_inner(ReferralState {
+            state_address: self.state.key(),
+            partner,
+            fee,
+            referred_msol: 0,
+            accrued_msol: 0,
+            claimed_msol: 0,
+            settled_msol_price: self.state.msol_price,
+        });
+
+        emit!(InitReferralEvent {
+            state: self.state.key(),
+            partner,
+            referral: self.referral.key(),
+            fee,
+        });
+        Ok(())
+    }
+}
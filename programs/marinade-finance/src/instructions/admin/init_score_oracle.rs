@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::admin::InitScoreOracleEvent, state::score_oracle::ScoreOracle, MarinadeError, State,
+};
+
+#[derive(Accounts)]
+pub struct InitScoreOracle<'info> {
+    #[account(has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Box<Account<'info, State>>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = ScoreOracle::SPACE,
+        seeds = [
+            &state.key().to_bytes(),
+            ScoreOracle::SEED
+        ],
+        bump
+    )]
+    pub score_oracle: Box<Account<'info, ScoreOracle>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitScoreOracle<'info> {
+    // fn init_score_oracle()
+    pub fn process(&mut self, scorer: Pubkey, max_age_epochs: u64) -> Result<()> {
+        self.score_oracle.set_inner(ScoreOracle {
+            state_address: self.state.key(),
+            scorer,
+            max_age_epochs,
+            scores: vec![],
+        });
+
+        emit!(InitScoreOracleEvent {
+            state: self.state.key(),
+            score_oracle: self.score_oracle.key(),
+            scorer,
+            max_age_epochs,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    events::admin::SetTreasurySplitEvent,
+    state::treasury_split::{TreasurySplit, TreasurySplitEntry, MAX_TREASURY_SPLIT_ENTRIES},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct InitTreasurySplit<'info> {
+    #[account(
+        mut,
+        has_one = treasury_authority @ MarinadeError::InvalidTreasuryAuthority
+    )]
+    pub state: Box<Account<'info, State>>,
+    pub treasury_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<TreasurySplit>(),
+        seeds = [
+            &state.key().to_bytes(),
+            TreasurySplit::SEED
+        ],
+        bump
+    )]
+    pub treasury_split: Box<Account<'info, TreasurySplit>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitTreasurySplit<'info> {
+    // fn init_treasury_split()
+    pub fn process(&mut self, entries: Vec<TreasurySplitEntry>) -> Result<()> {
+        self.treasury_split.state_address = self.state.key();
+        self.treasury_split.entries = [TreasurySplitEntry::default(); MAX_TREASURY_SPLIT_ENTRIES];
+        self.treasury_split.set(&entries)?;
+        self.state.treasury_split_enabled = !entries.is_empty();
+
+        emit!(SetTreasurySplitEvent {
+            state: self.state.key(),
+            entries,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{
+    error::MarinadeError,
+    events::admin::InitTreasuryVestingEvent,
+    state::treasury_vesting::{TreasuryVesting, MAX_TREASURY_VESTING_EPOCHS},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct InitTreasuryVesting<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ MarinadeError::InvalidAdminAuthority
+    )]
+    pub state: Box<Account<'info, State>>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<TreasuryVesting>(),
+        seeds = [
+            &state.key().to_bytes(),
+            TreasuryVesting::SEED
+        ],
+        bump
+    )]
+    pub treasury_vesting: Box<Account<'info, TreasuryVesting>>,
+    #[account(
+        token::mint = state.msol_mint,
+        token::authority = treasury_vesting,
+        constraint = msol_escrow.delegate.is_none() && msol_escrow.close_authority.is_none()
+            @ MarinadeError::WrongTokenOwnerOrDelegate
+    )]
+    pub msol_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitTreasuryVesting<'info> {
+    /// from now on the reward fees unlock over vesting_epochs, it can not be turned off
+    // fn init_treasury_vesting()
+    pub fn process(&mut self, vesting_epochs: u64, bump: u8) -> Result<()> {
+        TreasuryVesting::check_vesting_epochs(vesting_epochs)?;
+        self.treasury_vesting.set_inner(TreasuryVesting {
+            state_address: self.state.key(),
+            msol_escrow: self.msol_escrow.key(),
+            bump,
+            vesting_epochs,
+            bucket_epochs: [0; MAX_TREASURY_VESTING_EPOCHS],
+            bucket_amounts: [0; MAX_TREASURY_VESTING_EPOCHS],
+            released: 0,
+            claimed: 0,
+        });
+        self.state.treasury_vesting_enabled = true;
+
+        emit!(InitTreasuryVestingEvent {
+            state: self.state.key(),
+            treasury_vesting: self.treasury_vesting.key(),
+            msol_escrow: self.msol_escrow.key(),
+            vesting_epochs,
+        });
+        Ok(())
+    }
+}
@@ -7,7 +7,11 @@ use crate::{
     events::admin::InitializeEvent,
     require_lte,
     state::{
-        fee::FeeCents, liq_pool::LiqPool, stake_system::StakeSystem,
+        fee::FeeCents, fee_rate_limiter::FeeRateLimiter, flash_loan::FlashLoan,
+        flash_remove::FlashRemove, liq_pool::LiqPool, liq_pool_drain::LiqPoolDrain,
+        locked_lp::LockedLp, lp_fee_accrual::LpFeeAccrual,
+        price_circuit_breaker::PriceCircuitBreaker, stake_system::StakeSystem,
+        unstake_fee_curve::UnstakeFeeCurve, validator_criteria::ValidatorCriteria,
         validator_system::ValidatorSystem, Fee,
     },
     State, ID,
@@ -68,6 +72,7 @@ pub struct InitializeData {
     pub additional_validator_record_space: u32,
     pub slots_for_stake_delta: u64,
     pub pause_authority: Pubkey,
+    pub max_fee_change_per_epoch: Fee,
 }
 
 #[derive(Accounts)]
@@ -121,6 +126,7 @@ impl<'info> Initialize<'info> {
             additional_validator_record_space,
             slots_for_stake_delta,
             pause_authority,
+            max_fee_change_per_epoch,
         }: InitializeData,
         reserve_pda_bump: u8,
     ) -> Result<()> {
@@ -137,12 +143,22 @@ impl<'info> Initialize<'info> {
             StakeSystem::MIN_UPDATE_WINDOW,
             MarinadeError::UpdateWindowIsTooLow
         );
+        require_gte!(
+            StakeSystem::MAX_UPDATE_WINDOW,
+            slots_for_stake_delta,
+            MarinadeError::UpdateWindowIsTooHigh
+        );
         let rent_exempt_for_token_acc = self.rent.minimum_balance(spl_token::state::Account::LEN);
         require_gte!(
             min_stake,
             State::MIN_STAKE_LOWER_LIMIT,
             MarinadeError::MinStakeIsTooLow
         );
+        require_gte!(
+            State::MIN_STAKE_UPPER_LIMIT,
+            min_stake,
+            MarinadeError::MinStakeIsTooHigh
+        );
         self.check_reserve_pda(rent_exempt_for_token_acc)?;
         let msol_mint_authority_bump_seed = self.check_msol_mint()?;
         self.state.set_inner(State {
@@ -188,6 +204,47 @@ impl<'info> Initialize<'info> {
             last_stake_move_epoch: 0,
             stake_moved: 0,
             max_stake_moved_per_epoch: Fee::from_basis_points(10000), // 100% of total_lamports_under_control
+            flash_remove: FlashRemove::default(),
+            removal_dust_to_treasury: false,
+            price_circuit_breaker: PriceCircuitBreaker::default(), // disabled
+            removal_event_price_impact: false,
+            unstake_fee_curve: UnstakeFeeCurve::default(), // linear liq_pool fee
+            lp_account_cap: 0,
+            locked_lp: LockedLp::default(), // not configured
+            lp_fee_accrual: LpFeeAccrual::default(),
+            flash_loan: FlashLoan::default(),
+            liq_pool_max_msol_share: Fee::from_basis_points(0), // no rebalance
+            liq_pool_drain: LiqPoolDrain::default(),
+            instant_withdraw_cap_per_epoch: 0, // disabled
+            last_instant_withdraw_epoch: 0,
+            instant_withdrawn: 0,
+            claim_tip: Fee::from_basis_points(0),
+            directed_stake_max_share: Fee::from_basis_points(0), // disabled
+            total_directed_stake: 0,
+            min_deposit_stake_account: 0, // min_stake only
+            validator_criteria: ValidatorCriteria::default(), // permissionless add disabled
+            validator_max_stake_share: Fee::from_basis_points(0), // no cap
+            delinquent_unstake_slots: 0,  // disabled
+            commission_ceiling: Fee::from_basis_points(0), // not enforced
+            gauge_stake_share: Fee::from_basis_points(0), // disabled
+            gauge_lock_epochs: 0,
+            total_gauge_weight: 0,
+            bond_stake_multiplier: 0, // bonds not required
+            rebalance_threshold: Fee::from_basis_points(0), // any deviation
+            crank_tip: Fee::from_basis_points(0),
+            max_crank_tip: 0, // disabled
+            pending_admin_authority: Pubkey::default(),
+            config_timelock_seconds: 0, // no timelock
+            pause_flags: 0,
+            guardian_pause_flags: 0,
+            fee_rate_limiter: FeeRateLimiter::new(max_fee_change_per_epoch)?,
+            treasury_split_enabled: false,
+            treasury_authority: admin_authority,
+            admin_log_enabled: false,
+            treasury_vesting_enabled: false,
+            validator_stake_cap_count: 0,
+            blacklisted_validator_count: 0,
+            protected_validator_count: 0,
         });
 
         emit!(InitializeEvent {
@@ -201,7 +258,8 @@ impl<'info> Initialize<'info> {
                 additional_stake_record_space,
                 additional_validator_record_space,
                 slots_for_stake_delta,
-                pause_authority
+                pause_authority,
+                max_fee_change_per_epoch,
             },
             stake_list: self.stake_list.key(),
             validator_list: self.validator_list.key(),
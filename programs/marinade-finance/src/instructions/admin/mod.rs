@@ -1,17 +1,81 @@
+pub mod accept_authority;
+pub mod accept_authority_governance;
+pub mod add_lp_cap_bypass;
+pub mod add_staking_cap_bypass;
+pub mod blacklist_validator;
+pub mod cancel_config_proposal;
 pub mod change_authority;
+pub mod change_treasury;
+pub mod config_locked_lp;
 pub mod config_lp;
 pub mod config_marinade;
+pub mod config_unstake_fee_curve;
 pub mod config_validator_system;
+pub mod emergency_drain_liq_pool;
 pub mod emergency_pause;
+pub mod execute_config_proposal;
+pub mod force_sync_lp_supply_up;
+pub mod guardian_pause;
+pub mod init_admin_log;
+pub mod init_pause_guardians;
+pub mod init_referral;
+pub mod init_score_oracle;
+pub mod init_treasury_split;
+pub mod init_treasury_vesting;
 pub mod initialize;
+pub mod propose_config;
 pub mod realloc_stake_list;
+pub mod realloc_state;
 pub mod realloc_validator_list;
+pub mod remove_lp_cap_bypass;
+pub mod remove_staking_cap_bypass;
+pub mod reset_circuit_breaker;
+pub mod restore_liq_pool;
+pub mod set_config_param;
+pub mod set_lp_mint_metadata;
+pub mod set_pause_guardians;
+pub mod set_treasury_split;
+pub mod set_validator_stake_caps;
+pub mod slash_validator_bond;
+pub mod unblacklist_validator;
 
+pub use accept_authority::*;
+pub use accept_authority_governance::*;
+pub use add_lp_cap_bypass::*;
+pub use add_staking_cap_bypass::*;
+pub use blacklist_validator::*;
+pub use cancel_config_proposal::*;
 pub use change_authority::*;
+pub use change_treasury::*;
+pub use config_locked_lp::*;
 pub use config_lp::*;
 pub use config_marinade::*;
+pub use config_unstake_fee_curve::*;
 pub use config_validator_system::*;
+pub use emergency_drain_liq_pool::*;
 pub use emergency_pause::*;
+pub use execute_config_proposal::*;
+pub use force_sync_lp_supply_up::*;
+pub use guardian_pause::*;
+pub use init_admin_log::*;
+pub use init_pause_guardians::*;
+pub use init_referral::*;
+pub use init_score_oracle::*;
+pub use init_treasury_split::*;
+pub use init_treasury_vesting::*;
 pub use initialize::*;
+pub use propose_config::*;
 pub use realloc_stake_list::*;
+pub use realloc_state::*;
 pub use realloc_validator_list::*;
+pub use remove_lp_cap_bypass::*;
+pub use remove_staking_cap_bypass::*;
+pub use reset_circuit_breaker::*;
+pub use restore_liq_pool::*;
+pub use set_config_param::*;
+pub use set_lp_mint_metadata::*;
+pub use set_pause_guardians::*;
+pub use set_treasury_split::*;
+pub use set_validator_stake_caps::*;
+pub use slash_validator_bond::*;
+pub use unblacklist_validator::*;
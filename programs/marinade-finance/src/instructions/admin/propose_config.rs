@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    events::admin::ProposeConfigEvent,
+    state::config_proposal::{ConfigChange, ConfigProposal},
+    State,
+};
+
+#[derive(Accounts)]
+#[instruction(id: u64, change: ConfigChange)]
+pub struct ProposeConfig<'info> {
+    #[account(has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = ConfigProposal::space(&change),
+        seeds = [
+            &state.key().to_bytes(),
+            ConfigProposal::SEED,
+            &id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub config_proposal: Box<Account<'info, ConfigProposal>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub clock: Sysvar<'info, Clock>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeConfig<'info> {
+    // fn propose_config()
+    pub fn process(&mut self, id: u64, change: ConfigChange) -> Result<()> {
+        self.config_proposal.set_inner(ConfigProposal {
+            state_address: self.state.key(),
+            id,
+            change: change.clone(),
+            proposed_at: self.clock.unix_timestamp,
+            rent_payer: self.rent_payer.key(),
+        });
+
+        emit!(ProposeConfigEvent {
+            state: self.state.key(),
+            config_proposal: self.config_proposal.key(),
+            id,
+            change,
+            executable_at: self
+                .config_proposal
+                .executable_at(self.state.config_timelock_seconds),
+        });
+        Ok(())
+    }
+}
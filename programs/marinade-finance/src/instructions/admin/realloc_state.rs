@@ -0,0 +1,92 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, transfer, Transfer},
+    Discriminator,
+};
+
+use crate::{
+    error::MarinadeError,
+    events::admin::ReallocStateEvent,
+    state::{fee_rate_limiter::FeeRateLimiter, Fee},
+    State, ID,
+};
+
+#[derive(Accounts)]
+pub struct ReallocState<'info> {
+    /// CHECK: State account created before the last fields were appended, does not deserialize yet
+    #[account(mut, owner = ID)]
+    pub state: UncheckedAccount<'info>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        owner = system_program::ID,
+    )]
+    pub rent_funds: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Migration of a State account sized at initialize by an older program version.
+/// The fields appended since then are zero-filled, which is their disabled value,
+/// except the ones initialize sets to something else
+impl<'info> ReallocState<'info> {
+    const ADMIN_AUTHORITY_OFFSET: usize = 8 + 32; // discriminator, msol_mint
+
+    pub fn process(&mut self, max_fee_change_per_epoch: Fee) -> Result<()> {
+        let old_len = self.state.data_len();
+        let new_len = State::serialized_len();
+        {
+            let data = self.state.try_borrow_data()?;
+            require!(
+                data.len() >= Self::ADMIN_AUTHORITY_OFFSET + 32
+                    && data[..8] == State::DISCRIMINATOR,
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+            let admin_authority = Pubkey::try_from(
+                &data[Self::ADMIN_AUTHORITY_OFFSET..Self::ADMIN_AUTHORITY_OFFSET + 32],
+            )
+            .unwrap();
+            require_keys_eq!(
+                self.admin_authority.key(),
+                admin_authority,
+                MarinadeError::InvalidAdminAuthority
+            );
+        }
+        require_gt!(new_len, old_len, MarinadeError::StateAlreadyReallocated);
+
+        let rent_lamports = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(self.state.lamports());
+        if rent_lamports > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.rent_funds.to_account_info(),
+                        to: self.state.to_account_info(),
+                    },
+                ),
+                rent_lamports,
+            )?;
+        }
+        self.state.realloc(new_len, true)?;
+
+        let state_info = self.state.to_account_info();
+        let mut state = Account::<State>::try_from(&state_info)?;
+        if state.treasury_authority == Pubkey::default() {
+            state.treasury_authority = state.admin_authority;
+        }
+        if state.fee_rate_limiter == FeeRateLimiter::default() {
+            state.fee_rate_limiter = FeeRateLimiter::new(max_fee_change_per_epoch)?;
+        }
+        state.exit(&ID)?;
+
+        emit!(ReallocStateEvent {
+            state: self.state.key(),
+            old_len: old_len as u32,
+            new_len: new_len as u32,
+        });
+        Ok(())
+    }
+}
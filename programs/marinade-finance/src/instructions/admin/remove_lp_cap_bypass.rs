@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::admin::RemoveLpCapBypassEvent, state::lp_cap_bypass::LpCapBypass, MarinadeError, State,
+};
+
+#[derive(Accounts)]
+pub struct RemoveLpCapBypass<'info> {
+    #[account(has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_receiver,
+        constraint = lp_cap_bypass.state_address == state.key()
+            @ MarinadeError::InvalidLpCapBypass
+    )]
+    pub lp_cap_bypass: Account<'info, LpCapBypass>,
+
+    #[account(mut)]
+    pub rent_receiver: SystemAccount<'info>,
+}
+
+impl<'info> RemoveLpCapBypass<'info> {
+    // fn remove_lp_cap_bypass() // the wallet is subject to the deposit caps again
+    pub fn process(&mut self) -> Result<()> {
+        emit!(RemoveLpCapBypassEvent {
+            state: self.state.key(),
+            wallet: self.lp_cap_bypass.wallet,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::admin::RemoveStakingCapBypassEvent, state::staking_cap_bypass::StakingCapBypass,
+    MarinadeError, State,
+};
+
+#[derive(Accounts)]
+pub struct RemoveStakingCapBypass<'info> {
+    #[account(has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_receiver,
+        constraint = staking_cap_bypass.state_address == state.key()
+            @ MarinadeError::InvalidStakingCapBypass
+    )]
+    pub staking_cap_bypass: Account<'info, StakingCapBypass>,
+
+    #[account(mut)]
+    pub rent_receiver: SystemAccount<'info>,
+}
+
+impl<'info> RemoveStakingCapBypass<'info> {
+    // fn remove_staking_cap_bypass() // the wallet is subject to staking_sol_cap again
+    pub fn process(&mut self) -> Result<()> {
+        emit!(RemoveStakingCapBypassEvent {
+            state: self.state.key(),
+            wallet: self.staking_cap_bypass.wallet,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::MarinadeError, events::admin::ResetCircuitBreakerEvent, State};
+
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ MarinadeError::InvalidAdminAuthority
+    )]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ResetCircuitBreaker<'info> {
+    // fn reset_circuit_breaker() // accept the current mSOL price as the new reference
+    pub fn process(&mut self) -> Result<()> {
+        let reference_price = self.state.msol_price;
+        let reference_slot = self.clock.slot;
+        self.state
+            .price_circuit_breaker
+            .reset(reference_price, reference_slot);
+
+        emit!(ResetCircuitBreakerEvent {
+            state: self.state.key(),
+            reference_price,
+            reference_slot,
+        });
+        Ok(())
+    }
+}
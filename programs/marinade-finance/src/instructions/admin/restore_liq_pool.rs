@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{
+    transfer as transfer_token, Token, TokenAccount, Transfer as TransferToken,
+};
+
+use crate::{
+    error::MarinadeError,
+    events::admin::RestoreLiqPoolEvent,
+    state::{liq_pool::LiqPool, liq_pool_drain::LiqPoolDrain},
+    State,
+};
+
+// reverts emergency_drain_liq_pool
+#[derive(Accounts)]
+pub struct RestoreLiqPool<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ MarinadeError::InvalidAdminAuthority
+    )]
+    pub state: Box<Account<'info, State>>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::SOL_LEG_SEED
+        ],
+        bump = state.liq_pool.sol_leg_bump_seed
+    )]
+    pub liq_pool_sol_leg_pda: SystemAccount<'info>,
+    #[account(
+        mut,
+        address = state.liq_pool.msol_leg
+    )]
+    pub liq_pool_msol_leg: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPoolDrain::ESCROW_SEED
+        ],
+        bump = state.liq_pool_drain.escrow_bump_seed
+    )]
+    pub escrow: SystemAccount<'info>,
+    #[account(
+        mut,
+        address = state.liq_pool_drain.msol_escrow
+    )]
+    pub msol_escrow: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RestoreLiqPool<'info> {
+    // fn restore_liq_pool() // everything in the escrow goes back, including later donations
+    pub fn process(&mut self) -> Result<()> {
+        require!(self.state.paused, MarinadeError::NotPaused);
+        let LiqPoolDrain {
+            escrow_bump_seed,
+            drained_lamports,
+            drained_msol,
+            ..
+        } = self.state.liq_pool_drain;
+        self.state.liq_pool_drain.restore()?;
+
+        let restored_lamports = self.escrow.lamports();
+        let restored_msol = self.msol_escrow.amount;
+        let escrow_seeds: &[&[u8]] = &[
+            &self.state.key().to_bytes(),
+            LiqPoolDrain::ESCROW_SEED,
+            &[escrow_bump_seed],
+        ];
+
+        if restored_lamports > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.escrow.to_account_info(),
+                        to: self.liq_pool_sol_leg_pda.to_account_info(),
+                    },
+                    &[escrow_seeds],
+                ),
+                restored_lamports,
+            )?;
+        }
+
+        if restored_msol > 0 {
+            transfer_token(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferToken {
+                        from: self.msol_escrow.to_account_info(),
+                        to: self.liq_pool_msol_leg.to_account_info(),
+                        authority: self.escrow.to_account_info(),
+                    },
+                    &[escrow_seeds],
+                ),
+                restored_msol,
+            )?;
+        }
+
+        emit!(RestoreLiqPoolEvent {
+            state: self.state.key(),
+            drained_lamports,
+            drained_msol,
+            restored_lamports,
+            restored_msol,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,307 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    state::{config_proposal::ConfigChange, Fee, FeeCents},
+    State,
+};
+
+use super::{ConfigLp, ConfigLpParams, ConfigMarinade, ConfigMarinadeParams};
+
+/// A single field of ConfigMarinadeParams or ConfigLpParams,
+/// applied with the same checks and events as config_marinade and config_lp
+#[derive(Clone, Copy, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum ConfigParam {
+    RewardsFee(Fee),
+    SlotsForStakeDelta(u64),
+    MinStake(u64),
+    MinDeposit(u64),
+    MinWithdraw(u64),
+    StakingSolCap(u64),
+    LiquiditySolCap(u64),
+    WithdrawStakeAccountEnabled(bool),
+    DelayedUnstakeFee(FeeCents),
+    WithdrawStakeAccountFee(FeeCents),
+    MaxStakeMovedPerEpoch(Fee),
+    CircuitBreakerMaxPriceMove(Fee),
+    CircuitBreakerWindowSlots(u64),
+    InstantWithdrawCapPerEpoch(u64),
+    ClaimTip(Fee),
+    DirectedStakeMaxShare(Fee),
+    MinDepositStakeAccount(u64),
+    PermissionlessValidatorScore(u32),
+    ValidatorMaxCommission(Fee),
+    ValidatorMaxVoteLagSlots(u64),
+    ValidatorMaxStakeShare(Fee),
+    DelinquentUnstakeSlots(u64),
+    CommissionCeiling(Fee),
+    GaugeStakeShare(Fee),
+    GaugeLockEpochs(u64),
+    BondStakeMultiplier(u32),
+    RebalanceThreshold(Fee),
+    CrankTip(Fee),
+    MaxCrankTip(u64),
+    ConfigTimelockSeconds(u64),
+    LpMinFee(Fee),
+    LpMaxFee(Fee),
+    LpLiquidityTarget(u64),
+    LpTreasuryCut(Fee),
+    LpRemovalDustToTreasury(bool),
+    LpRemovalEventPriceImpact(bool),
+    LpAccountCap(u64),
+    LpMaxMsolShare(Fee),
+}
+
+impl ConfigParam {
+    /// the config_marinade or config_lp change setting only this field,
+    /// also usable as a ConfigProposal change
+    pub fn into_change(self) -> ConfigChange {
+        match self {
+            Self::RewardsFee(value) => ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                rewards_fee: Some(value),
+                ..Default::default()
+            })),
+            Self::SlotsForStakeDelta(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    slots_for_stake_delta: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::MinStake(value) => ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                min_stake: Some(value),
+                ..Default::default()
+            })),
+            Self::MinDeposit(value) => ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                min_deposit: Some(value),
+                ..Default::default()
+            })),
+            Self::MinWithdraw(value) => ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                min_withdraw: Some(value),
+                ..Default::default()
+            })),
+            Self::StakingSolCap(value) => ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                staking_sol_cap: Some(value),
+                ..Default::default()
+            })),
+            Self::LiquiditySolCap(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    liquidity_sol_cap: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::WithdrawStakeAccountEnabled(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    withdraw_stake_account_enabled: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::DelayedUnstakeFee(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    delayed_unstake_fee: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::WithdrawStakeAccountFee(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    withdraw_stake_account_fee: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::MaxStakeMovedPerEpoch(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    max_stake_moved_per_epoch: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::CircuitBreakerMaxPriceMove(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    circuit_breaker_max_price_move: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::CircuitBreakerWindowSlots(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    circuit_breaker_window_slots: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::InstantWithdrawCapPerEpoch(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    instant_withdraw_cap_per_epoch: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::ClaimTip(value) => ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                claim_tip: Some(value),
+                ..Default::default()
+            })),
+            Self::DirectedStakeMaxShare(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    directed_stake_max_share: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::MinDepositStakeAccount(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    min_deposit_stake_account: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::PermissionlessValidatorScore(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    permissionless_validator_score: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::ValidatorMaxCommission(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    validator_max_commission: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::ValidatorMaxVoteLagSlots(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    validator_max_vote_lag_slots: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::ValidatorMaxStakeShare(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    validator_max_stake_share: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::DelinquentUnstakeSlots(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    delinquent_unstake_slots: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::CommissionCeiling(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    commission_ceiling: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::GaugeStakeShare(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    gauge_stake_share: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::GaugeLockEpochs(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    gauge_lock_epochs: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::BondStakeMultiplier(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    bond_stake_multiplier: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::RebalanceThreshold(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    rebalance_threshold: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::CrankTip(value) => ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                crank_tip: Some(value),
+                ..Default::default()
+            })),
+            Self::MaxCrankTip(value) => ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                max_crank_tip: Some(value),
+                ..Default::default()
+            })),
+            Self::ConfigTimelockSeconds(value) => {
+                ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                    config_timelock_seconds: Some(value),
+                    ..Default::default()
+                }))
+            }
+            Self::LpMinFee(value) => ConfigChange::Lp(ConfigLpParams {
+                min_fee: Some(value),
+                ..Default::default()
+            }),
+            Self::LpMaxFee(value) => ConfigChange::Lp(ConfigLpParams {
+                max_fee: Some(value),
+                ..Default::default()
+            }),
+            Self::LpLiquidityTarget(value) => ConfigChange::Lp(ConfigLpParams {
+                liquidity_target: Some(value),
+                ..Default::default()
+            }),
+            Self::LpTreasuryCut(value) => ConfigChange::Lp(ConfigLpParams {
+                treasury_cut: Some(value),
+                ..Default::default()
+            }),
+            Self::LpRemovalDustToTreasury(value) => ConfigChange::Lp(ConfigLpParams {
+                removal_dust_to_treasury: Some(value),
+                ..Default::default()
+            }),
+            Self::LpRemovalEventPriceImpact(value) => ConfigChange::Lp(ConfigLpParams {
+                removal_event_price_impact: Some(value),
+                ..Default::default()
+            }),
+            Self::LpAccountCap(value) => ConfigChange::Lp(ConfigLpParams {
+                lp_account_cap: Some(value),
+                ..Default::default()
+            }),
+            Self::LpMaxMsolShare(value) => ConfigChange::Lp(ConfigLpParams {
+                max_msol_share: Some(value),
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct SetConfigParam<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ MarinadeError::InvalidAdminAuthority
+    )]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+}
+
+impl<'info> SetConfigParam<'info> {
+    // fn set_config_param()
+    pub fn process(&mut self, param: ConfigParam) -> Result<()> {
+        require_eq!(
+            self.state.config_timelock_seconds,
+            0,
+            MarinadeError::ConfigIsTimelocked
+        );
+        match param.into_change() {
+            ConfigChange::Marinade(params) => ConfigMarinade::apply(&mut self.state, *params),
+            ConfigChange::Lp(params) => ConfigLp::apply(&mut self.state, params),
+            ConfigChange::ResumeGuardianPause(_) => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_change() {
+        assert_eq!(
+            ConfigParam::MinStake(1_000_000_000).into_change(),
+            ConfigChange::Marinade(Box::new(ConfigMarinadeParams {
+                min_stake: Some(1_000_000_000),
+                ..Default::default()
+            }))
+        );
+        assert_eq!(
+            ConfigParam::LpTreasuryCut(Fee::from_basis_points(2500)).into_change(),
+            ConfigChange::Lp(ConfigLpParams {
+                treasury_cut: Some(Fee::from_basis_points(2500)),
+                ..Default::default()
+            })
+        );
+    }
+}
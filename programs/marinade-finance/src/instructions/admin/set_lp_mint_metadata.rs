@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{
+    create_metadata_accounts_v3, update_metadata_accounts_v2, CreateMetadataAccountsV3, Metadata,
+    UpdateMetadataAccountsV2,
+};
+use anchor_spl::token::Mint;
+use mpl_token_metadata::{pda::find_metadata_account, state::DataV2};
+
+use crate::{
+    events::admin::SetLpMintMetadataEvent, state::liq_pool::LiqPool, MarinadeError, State,
+};
+
+#[derive(Accounts)]
+pub struct SetLpMintMetadata<'info> {
+    #[account(has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(address = state.liq_pool.lp_mint)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    /// CHECK: PDA, mint and metadata update authority
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::LP_MINT_AUTHORITY_SEED
+        ],
+        bump = state.liq_pool.lp_mint_authority_bump_seed
+    )]
+    pub lp_mint_authority: UncheckedAccount<'info>,
+    /// CHECK: created or updated by the metadata program
+    #[account(
+        mut,
+        address = find_metadata_account(&lp_mint.key()).0
+    )]
+    pub lp_mint_metadata: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> SetLpMintMetadata<'info> {
+    // fn set_lp_mint_metadata() // creates the LP mint metadata on first call, updates it after
+    pub fn process(&mut self, name: String, symbol: String, uri: String) -> Result<()> {
+        let data = DataV2 {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        };
+        let lp_mint_authority_seeds: &[&[u8]] = &[
+            &self.state.key().to_bytes(),
+            LiqPool::LP_MINT_AUTHORITY_SEED,
+            &[self.state.liq_pool.lp_mint_authority_bump_seed],
+        ];
+
+        let created = self.lp_mint_metadata.data_is_empty();
+        if created {
+            create_metadata_accounts_v3(
+                CpiContext::new_with_signer(
+                    self.metadata_program.to_account_info(),
+                    CreateMetadataAccountsV3 {
+                        metadata: self.lp_mint_metadata.to_account_info(),
+                        mint: self.lp_mint.to_account_info(),
+                        mint_authority: self.lp_mint_authority.to_account_info(),
+                        payer: self.rent_payer.to_account_info(),
+                        update_authority: self.lp_mint_authority.to_account_info(),
+                        system_program: self.system_program.to_account_info(),
+                        rent: self.rent.to_account_info(),
+                    },
+                    &[lp_mint_authority_seeds],
+                ),
+                data,
+                true, // mutable, so this instruction can update it
+                true,
+                None,
+            )?;
+        } else {
+            update_metadata_accounts_v2(
+                CpiContext::new_with_signer(
+                    self.metadata_program.to_account_info(),
+                    UpdateMetadataAccountsV2 {
+                        metadata: self.lp_mint_metadata.to_account_info(),
+                        update_authority: self.lp_mint_authority.to_account_info(),
+                    },
+                    &[lp_mint_authority_seeds],
+                ),
+                None,
+                Some(data),
+                None,
+                None,
+            )?;
+        }
+
+        emit!(SetLpMintMetadataEvent {
+            state: self.state.key(),
+            lp_mint: self.lp_mint.key(),
+            metadata: self.lp_mint_metadata.key(),
+            created,
+            name,
+            symbol,
+            uri,
+        });
+        Ok(())
+    }
+}
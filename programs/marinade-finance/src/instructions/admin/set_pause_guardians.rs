@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError, events::admin::SetPauseGuardiansEvent,
+    state::pause_guardians::PauseGuardians, State,
+};
+
+#[derive(Accounts)]
+pub struct SetPauseGuardians<'info> {
+    #[account(has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Box<Account<'info, State>>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            PauseGuardians::SEED
+        ],
+        bump
+    )]
+    pub pause_guardians: Box<Account<'info, PauseGuardians>>,
+}
+
+impl<'info> SetPauseGuardians<'info> {
+    // fn set_pause_guardians()
+    pub fn process(&mut self, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        self.pause_guardians.set(&guardians, threshold)?;
+
+        emit!(SetPauseGuardiansEvent {
+            state: self.state.key(),
+            guardians,
+            threshold,
+        });
+        Ok(())
+    }
+}
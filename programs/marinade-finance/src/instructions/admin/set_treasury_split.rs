@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    events::admin::SetTreasurySplitEvent,
+    state::treasury_split::{TreasurySplit, TreasurySplitEntry},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct SetTreasurySplit<'info> {
+    #[account(
+        mut,
+        has_one = treasury_authority @ MarinadeError::InvalidTreasuryAuthority
+    )]
+    pub state: Box<Account<'info, State>>,
+    pub treasury_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            TreasurySplit::SEED
+        ],
+        bump
+    )]
+    pub treasury_split: Box<Account<'info, TreasurySplit>>,
+}
+
+impl<'info> SetTreasurySplit<'info> {
+    /// an empty split sends the reward fees back to state.treasury_msol_account
+    // fn set_treasury_split()
+    pub fn process(&mut self, entries: Vec<TreasurySplitEntry>) -> Result<()> {
+        self.treasury_split.set(&entries)?;
+        self.state.treasury_split_enabled = !entries.is_empty();
+
+        emit!(SetTreasurySplitEvent {
+            state: self.state.key(),
+            entries,
+        });
+        Ok(())
+    }
+}
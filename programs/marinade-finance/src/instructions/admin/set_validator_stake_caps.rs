@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+
+use crate::{
+    error::MarinadeError, events::admin::SetValidatorStakeCapEvent,
+    state::validator_stake_cap::ValidatorStakeCap, State,
+};
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq)]
+pub struct ValidatorStakeCapUpdate {
+    pub validator_account: Pubkey,
+    pub max_stake: u64, // lamports, 0 = no cap
+}
+
+// set the stake caps of several validators, the ValidatorStakeCap PDAs are the remaining accounts,
+// one per update (after the AdminLog when enabled). Missing ones are created, paid by rent_payer
+#[derive(Accounts)]
+pub struct SetValidatorStakeCaps<'info> {
+    #[account(mut, has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Box<Account<'info, State>>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetValidatorStakeCaps<'info> {
+    pub const MAX_CAPS: usize = 16;
+
+    // same as the anchor `init` constraint, also when the address was pre-funded
+    fn create_cap_account(&self, info: &AccountInfo<'info>, seeds: &[&[u8]]) -> Result<()> {
+        let rent = Rent::get()?;
+        let required_lamports = rent.minimum_balance(ValidatorStakeCap::SPACE);
+        if info.lamports() == 0 {
+            create_account(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    CreateAccount {
+                        from: self.rent_payer.to_account_info(),
+                        to: info.clone(),
+                    },
+                    &[seeds],
+                ),
+                required_lamports,
+                ValidatorStakeCap::SPACE as u64,
+                &crate::ID,
+            )?;
+        } else {
+            let top_up = required_lamports.saturating_sub(info.lamports());
+            if top_up > 0 {
+                transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        Transfer {
+                            from: self.rent_payer.to_account_info(),
+                            to: info.clone(),
+                        },
+                    ),
+                    top_up,
+                )?;
+            }
+            allocate(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Allocate {
+                        account_to_allocate: info.clone(),
+                    },
+                    &[seeds],
+                ),
+                ValidatorStakeCap::SPACE as u64,
+            )?;
+            assign(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Assign {
+                        account_to_assign: info.clone(),
+                    },
+                    &[seeds],
+                ),
+                &crate::ID,
+            )?;
+        }
+        Ok(())
+    }
+
+    // fn set_validator_stake_caps()
+    pub fn process(
+        &mut self,
+        caps: Vec<ValidatorStakeCapUpdate>,
+        cap_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            !caps.is_empty() && caps.len() <= Self::MAX_CAPS && caps.len() == cap_accounts.len(),
+            MarinadeError::InvalidValidatorStakeCaps
+        );
+
+        let state_address = self.state.key();
+        for (update, info) in caps.iter().zip(cap_accounts) {
+            let (address, bump) =
+                ValidatorStakeCap::find_address(&state_address, &update.validator_account);
+            require_keys_eq!(info.key(), address, MarinadeError::WrongValidatorStakeCap);
+            require!(info.is_writable, ErrorCode::ConstraintMut);
+
+            let old_max_stake = if info.owner == &crate::ID {
+                let mut cap = Account::<ValidatorStakeCap>::try_from(info)?;
+                let old_max_stake = cap.max_stake;
+                cap.max_stake = update.max_stake;
+                cap.exit(&crate::ID)?;
+                old_max_stake
+            } else {
+                self.create_cap_account(
+                    info,
+                    &[
+                        &state_address.to_bytes(),
+                        ValidatorStakeCap::SEED,
+                        &update.validator_account.to_bytes(),
+                        &[bump],
+                    ],
+                )?;
+                let mut data = info.try_borrow_mut_data()?;
+                ValidatorStakeCap {
+                    state_address,
+                    validator_account: update.validator_account,
+                    max_stake: update.max_stake,
+                }
+                .try_serialize(&mut &mut data[..])?;
+                0
+            };
+
+            match (old_max_stake > 0, update.max_stake > 0) {
+                (false, true) => self.state.validator_stake_cap_count += 1,
+                (true, false) => self.state.validator_stake_cap_count -= 1,
+                _ => {}
+            }
+
+            emit!(SetValidatorStakeCapEvent {
+                state: state_address,
+                validator: update.validator_account,
+                old_max_stake,
+                new_max_stake: update.max_stake,
+            });
+        }
+        Ok(())
+    }
+}
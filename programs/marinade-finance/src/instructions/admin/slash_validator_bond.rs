@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError, events::admin::SlashValidatorBondEvent,
+    state::validator_bond::ValidatorBond, State,
+};
+
+#[derive(Accounts)]
+pub struct SlashValidatorBond<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ MarinadeError::InvalidAdminAuthority
+    )]
+    pub state: Box<Account<'info, State>>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = validator_bond.state_address == state.key()
+            @ MarinadeError::WrongValidatorBond
+    )]
+    pub validator_bond: Box<Account<'info, ValidatorBond>>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            State::RESERVE_SEED
+        ],
+        bump = state.reserve_bump_seed
+    )]
+    pub reserve_pda: SystemAccount<'info>,
+}
+
+impl<'info> SlashValidatorBond<'info> {
+    /// moves bonded lamports into the reserve, to the mSOL holders
+    // fn slash_validator_bond()
+    pub fn process(&mut self, lamports: u64) -> Result<()> {
+        require_gte!(
+            self.validator_bond.bonded_lamports,
+            lamports,
+            MarinadeError::BondIsTooLow
+        );
+        self.validator_bond.bonded_lamports -= lamports;
+
+        **self
+            .validator_bond
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= lamports;
+        **self.reserve_pda.try_borrow_mut_lamports()? += lamports;
+        self.state.on_transfer_to_reserve(lamports);
+
+        emit!(SlashValidatorBondEvent {
+            state: self.state.key(),
+            validator: self.validator_bond.validator_account,
+            lamports,
+            bonded_lamports: self.validator_bond.bonded_lamports,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::admin::UnblacklistValidatorEvent, state::validator_blacklist::BlacklistedValidator,
+    MarinadeError, State,
+};
+
+#[derive(Accounts)]
+pub struct UnblacklistValidator<'info> {
+    #[account(mut, has_one = admin_authority @ MarinadeError::InvalidAdminAuthority)]
+    pub state: Account<'info, State>,
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_receiver,
+        constraint = blacklisted_validator.state_address == state.key()
+            @ MarinadeError::InvalidBlacklistedValidator
+    )]
+    pub blacklisted_validator: Account<'info, BlacklistedValidator>,
+
+    #[account(mut)]
+    pub rent_receiver: SystemAccount<'info>,
+}
+
+impl<'info> UnblacklistValidator<'info> {
+    // fn unblacklist_validator()
+    pub fn process(&mut self) -> Result<()> {
+        self.state.blacklisted_validator_count -= 1;
+        emit!(UnblacklistValidatorEvent {
+            state: self.state.key(),
+            validator: self.blacklisted_validator.validator_account,
+        });
+        Ok(())
+    }
+}
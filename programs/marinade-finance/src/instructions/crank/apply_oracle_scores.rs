@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::crank::ApplyOracleScoresEvent,
+    state::{pause_flags::PauseFlags, score_oracle::ScoreOracle, validator_system::ValidatorList},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct ApplyOracleScores<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+    #[account(
+        mut,
+        address = state.validator_system.validator_list.account,
+    )]
+    pub validator_list: Account<'info, ValidatorList>,
+
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            ScoreOracle::SEED
+        ],
+        bump
+    )]
+    pub score_oracle: Box<Account<'info, ScoreOracle>>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ApplyOracleScores<'info> {
+    /// copies the fresh oracle scores of validators [start_index, start_index + count)
+    /// into their records. Stale or mismatched entries leave the last known score
+    // fn apply_oracle_scores()
+    pub fn process(&mut self, start_index: u32, count: u32) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
+
+        let end_index = start_index
+            .saturating_add(count)
+            .min(self.state.validator_system.validator_count());
+        let mut applied = 0;
+        for index in start_index..end_index {
+            let mut validator = self.state.validator_system.get(
+                &self.validator_list.to_account_info().data.as_ref().borrow(),
+                index,
+            )?;
+            if let Some(score) =
+                self.score_oracle
+                    .fresh_score(index, &validator.validator_account, self.clock.epoch)
+            {
+                self.state.validator_system.total_validator_score -= validator.score;
+                validator.score = score;
+                self.state.validator_system.total_validator_score += score;
+                self.state.validator_system.set(
+                    &mut self
+                        .validator_list
+                        .to_account_info()
+                        .data
+                        .as_ref()
+                        .borrow_mut(),
+                    index,
+                    validator,
+                )?;
+                applied += 1;
+            }
+        }
+
+        emit!(ApplyOracleScoresEvent {
+            state: self.state.key(),
+            epoch: self.clock.epoch,
+            start_index,
+            end_index,
+            applied,
+            total_validator_score: self.state.validator_system.total_validator_score,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+use crate::{
+    error::MarinadeError,
+    events::crank::ClaimVestedFeesEvent,
+    state::{
+        pause_flags::PauseFlags, treasury_split::TreasurySplit, treasury_vesting::TreasuryVesting,
+    },
+    State,
+};
+
+#[derive(Accounts)]
+pub struct ClaimVestedFees<'info> {
+    #[account(has_one = treasury_msol_account)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            TreasuryVesting::SEED
+        ],
+        bump = treasury_vesting.bump
+    )]
+    pub treasury_vesting: Box<Account<'info, TreasuryVesting>>,
+    #[account(
+        mut,
+        address = treasury_vesting.msol_escrow
+    )]
+    pub msol_escrow: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: in code
+    #[account(mut)]
+    pub treasury_msol_account: UncheckedAccount<'info>,
+    // receives the fees instead of treasury_msol_account, required while state.treasury_split_enabled
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            TreasurySplit::SEED
+        ],
+        bump
+    )]
+    pub treasury_split: Option<Box<Account<'info, TreasurySplit>>>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// delivers the vested reward fees to the treasury, anyone can call it
+impl<'info> ClaimVestedFees<'info> {
+    // fn claim_vested_fees()
+    pub fn process(&mut self, treasury_destinations: &[AccountInfo<'info>]) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
+
+        let epoch = self.clock.epoch;
+        let msol_amount = self.treasury_vesting.claimable(epoch)?;
+        require_gt!(msol_amount, 0, MarinadeError::NothingVestedYet);
+
+        if self.state.treasury_split_enabled {
+            let treasury_split = self
+                .treasury_split
+                .as_ref()
+                .ok_or_else(|| error!(MarinadeError::MissingTreasurySplit))?;
+            require!(
+                treasury_split.check_destinations(&self.state, treasury_destinations)?,
+                MarinadeError::WrongTreasurySplitDestination
+            );
+            for (destination, share) in treasury_destinations
+                .iter()
+                .zip(treasury_split.split(msol_amount)?)
+            {
+                self.transfer_to(destination.clone(), share)?;
+            }
+        } else {
+            require!(
+                self.state
+                    .get_treasury_msol_balance(&self.treasury_msol_account)
+                    .is_some(),
+                MarinadeError::WrongTokenOwnerOrDelegate
+            );
+            self.transfer_to(self.treasury_msol_account.to_account_info(), msol_amount)?;
+        }
+        self.treasury_vesting.claimed += msol_amount;
+
+        emit!(ClaimVestedFeesEvent {
+            state: self.state.key(),
+            epoch,
+            msol_amount,
+            vested: self.treasury_vesting.vested(epoch)?,
+            claimed: self.treasury_vesting.claimed,
+        });
+        Ok(())
+    }
+
+    fn transfer_to(&self, to: AccountInfo<'info>, msol_amount: u64) -> Result<()> {
+        if msol_amount == 0 {
+            return Ok(());
+        }
+        transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.msol_escrow.to_account_info(),
+                    to,
+                    authority: self.treasury_vesting.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    TreasuryVesting::SEED,
+                    &[self.treasury_vesting.bump],
+                ]],
+            ),
+            msol_amount,
+        )
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::events::crank::CrankClaimEvent;
+use crate::instructions::delayed_unstake::claim::*;
+
+// claim of a due ticket for its beneficiary, paying the cranker State::claim_tip,
+// at most the tip the beneficiary opted into when ordering the ticket
+#[derive(Accounts)]
+pub struct CrankClaim<'info> {
+    pub claim: Claim<'info>,
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+}
+
+impl<'info> CrankClaim<'info> {
+    /// permissionless
+    /// pub fn crank_claim()
+    pub fn process(&mut self) -> Result<()> {
+        let ticket = self.claim.ticket_account.key();
+        let beneficiary = self.claim.ticket_account.beneficiary;
+        let lamports = self.claim.ticket_account.lamports_amount;
+        let tip = self
+            .claim
+            .process_with_tip(None, Some(&self.cranker.to_account_info()))?;
+
+        emit!(CrankClaimEvent {
+            state: self.claim.state.key(),
+            ticket,
+            beneficiary,
+            cranker: self.cranker.key(),
+            lamports,
+            tip,
+        });
+        Ok(())
+    }
+}
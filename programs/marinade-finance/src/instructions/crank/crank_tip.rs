@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{events::crank::CrankTipEvent, State};
+
+/// pays the crank tip for moving cranked_lamports from the reserve to tip_to.
+/// Returns the tip, 0 when disabled or when the reserve has nothing to spare
+pub fn pay_crank_tip<'info>(
+    state: &mut State,
+    state_address: Pubkey,
+    reserve_pda: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    tip_to: AccountInfo<'info>,
+    cranked_lamports: u64,
+) -> Result<u64> {
+    let tip = state.crank_tip_for(cranked_lamports);
+    if tip == 0 {
+        return Ok(0);
+    }
+    transfer(
+        CpiContext::new_with_signer(
+            system_program,
+            Transfer {
+                from: reserve_pda,
+                to: tip_to.clone(),
+            },
+            &[&[
+                &state_address.to_bytes(),
+                State::RESERVE_SEED,
+                &[state.reserve_bump_seed],
+            ]],
+        ),
+        tip,
+    )?;
+    state.on_transfer_from_reserve(tip);
+
+    emit!(CrankTipEvent {
+        state: state_address,
+        tip_to: tip_to.key(),
+        cranked_lamports,
+        tip,
+    });
+    Ok(tip)
+}
@@ -3,7 +3,14 @@ use crate::{
     events::crank::{DeactivateStakeEvent, SplitStakeAccountInfo},
     require_lt,
     state::{
+        directed_stake::DirectedStakeRecord,
+        epoch_report::EpochReport,
+        gauge::Gauge,
+        protected_validator::ProtectedValidator,
         stake_system::{StakeList, StakeSystem},
+        validator_blacklist::BlacklistedValidator,
+        validator_bond::ValidatorBond,
+        validator_stake_cap::ValidatorStakeCap,
         validator_system::ValidatorList,
     },
     State,
@@ -19,7 +26,10 @@ use anchor_spl::stake::{
     DeactivateStake as SolanaDeactivateStake, Stake, StakeAccount, Withdraw,
 };
 
-use crate::checks::check_stake_amount_and_validator;
+use crate::checks::{check_feature_account, check_stake_amount_and_validator};
+
+use super::crank_tip::pay_crank_tip;
+use crate::state::pause_flags::PauseFlags;
 
 #[derive(Accounts)]
 pub struct DeactivateStake<'info> {
@@ -77,6 +87,104 @@ pub struct DeactivateStake<'info> {
 
     pub system_program: Program<'info, System>,
     pub stake_program: Program<'info, Stake>,
+
+    /// CHECK: ValidatorBond of the validator, may not be initialized.
+    /// Required while State::bond_stake_multiplier is set
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            ValidatorBond::SEED,
+            &stake_account
+                .delegation()
+                .map(|delegation| delegation.voter_pubkey)
+                .unwrap_or_default().to_bytes(),
+        ],
+        bump,
+    )]
+    pub validator_bond: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: must not be initialized for the stake voter, see ProtectedValidator.
+    /// Required while any validator is protected
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            ProtectedValidator::SEED,
+            &stake_account
+                .delegation()
+                .map(|delegation| delegation.voter_pubkey)
+                .unwrap_or_default().to_bytes(),
+        ],
+        bump,
+    )]
+    pub protected_validator: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: DirectedStakeRecord of the validator, may not be initialized.
+    /// Required while State::directed_stake_max_share is set
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            DirectedStakeRecord::SEED,
+            &stake_account
+                .delegation()
+                .map(|delegation| delegation.voter_pubkey)
+                .unwrap_or_default().to_bytes(),
+        ],
+        bump,
+    )]
+    pub directed_stake: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: ValidatorStakeCap of the validator, may not be initialized.
+    /// Required while any validator has a cap
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            ValidatorStakeCap::SEED,
+            &stake_account
+                .delegation()
+                .map(|delegation| delegation.voter_pubkey)
+                .unwrap_or_default().to_bytes(),
+        ],
+        bump,
+    )]
+    pub validator_stake_cap: Option<UncheckedAccount<'info>>,
+
+    // blacklist entry of the validator, its stake target is 0 when passed
+    #[account(
+        constraint = blacklisted_validator.state_address == state.key()
+            @ MarinadeError::InvalidBlacklistedValidator
+    )]
+    pub blacklisted_validator: Option<Box<Account<'info, BlacklistedValidator>>>,
+
+    /// CHECK: Gauge of the validator, may not be initialized.
+    /// Required while gauge weights spread a share of the stake target
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            Gauge::SEED,
+            &stake_account
+                .delegation()
+                .map(|delegation| delegation.voter_pubkey)
+                .unwrap_or_default().to_bytes(),
+        ],
+        bump,
+    )]
+    pub gauge: Option<UncheckedAccount<'info>>,
+
+    // receives State::crank_tip of the unstaked lamports, when passed
+    #[account(mut)]
+    pub crank_tip_to: Option<SystemAccount<'info>>,
+
+    // report of the current epoch, updated when passed
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            EpochReport::SEED,
+            &clock.epoch.to_le_bytes()
+        ],
+        bump
+    )]
+    pub epoch_report: Option<Box<Account<'info, EpochReport>>>,
 }
 
 impl<'info> DeactivateStake<'info> {
@@ -84,7 +192,23 @@ impl<'info> DeactivateStake<'info> {
     // fn deactivate_stake()
     //
     pub fn process(&mut self, stake_index: u32, validator_index: u32) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
+        check_feature_account(
+            &self.directed_stake,
+            self.state.is_directed_stake_enabled(),
+            "directed_stake",
+        )?;
+        check_feature_account(
+            &self.validator_stake_cap,
+            self.state.has_validator_stake_caps(),
+            "validator_stake_cap",
+        )?;
+        check_feature_account(&self.gauge, self.state.is_gauge_stake_enabled(), "gauge")?;
+        check_feature_account(
+            &self.validator_bond,
+            self.state.is_bond_required(),
+            "validator_bond",
+        )?;
 
         let mut stake = self.state.stake_system.get_checked(
             &self.stake_list.to_account_info().data.as_ref().borrow(),
@@ -136,11 +260,24 @@ impl<'info> DeactivateStake<'info> {
             &validator.validator_account,
         )?;
 
-        // compute target for this particular validator (total_stake_target * score/total_score)
-        let validator_stake_target = self
-            .state
-            .validator_system
-            .validator_stake_target(&validator, total_stake_target)?;
+        // compute target for this particular validator (directed + rest * score/total_score)
+        let validator_stake_target = if let Some(blacklisted) = &self.blacklisted_validator {
+            require_keys_eq!(
+                blacklisted.validator_account,
+                validator.validator_account,
+                MarinadeError::InvalidBlacklistedValidator
+            );
+            0 // unwind all the stake of a blacklisted validator
+        } else {
+            self.state.validator_stake_target(
+                &validator,
+                DirectedStakeRecord::load(self.directed_stake.as_deref())?.as_ref(),
+                Gauge::load(self.gauge.as_deref())?.as_ref(),
+                ValidatorStakeCap::load(self.validator_stake_cap.as_deref())?.as_ref(),
+                ValidatorBond::load(self.validator_bond.as_deref())?.as_ref(),
+                total_stake_target,
+            )?
+        };
 
         // compute how much we should unstake from this validator
         let validator_active_balance = validator.active_balance; // record for event
@@ -153,6 +290,12 @@ impl<'info> DeactivateStake<'info> {
             self.return_unused_split_stake_account_rent()?;
             return Ok(()); // Not an error. Don't fail other instructions in tx
         }
+        check_feature_account(
+            &self.protected_validator,
+            self.state.has_protected_validators(),
+            "protected_validator",
+        )?;
+        ProtectedValidator::check_not_protected(self.protected_validator.as_deref())?;
         let unstake_from_validator = validator_active_balance - validator_stake_target;
         msg!(
             "unstake {} from_validator {}",
@@ -307,6 +450,21 @@ impl<'info> DeactivateStake<'info> {
             validator,
         )?;
 
+        if let Some(epoch_report) = &mut self.epoch_report {
+            epoch_report.stake_deactivated += unstaked_amount;
+        }
+        if let Some(crank_tip_to) = &self.crank_tip_to {
+            let state_address = self.state.key();
+            pay_crank_tip(
+                &mut self.state,
+                state_address,
+                self.reserve_pda.to_account_info(),
+                self.system_program.to_account_info(),
+                crank_tip_to.to_account_info(),
+                unstaked_amount,
+            )?;
+        }
+
         emit!(DeactivateStakeEvent {
             state: self.state.key(),
             epoch: self.clock.epoch,
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::crank::InitEpochReportEvent, state::epoch_report::EpochReport, State};
+
+#[derive(Accounts)]
+pub struct InitEpochReport<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<EpochReport>(),
+        seeds = [
+            &state.key().to_bytes(),
+            EpochReport::SEED,
+            &clock.epoch.to_le_bytes()
+        ],
+        bump
+    )]
+    pub epoch_report: Box<Account<'info, EpochReport>>,
+
+    pub clock: Sysvar<'info, Clock>,
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitEpochReport<'info> {
+    // fn init_epoch_report()
+    pub fn process(&mut self) -> Result<()> {
+        let stake_accounts_to_update = self.state.stake_system.stake_count();
+        self.epoch_report.set_inner(EpochReport {
+            state_address: self.state.key(),
+            epoch: self.clock.epoch,
+            rewards: 0,
+            treasury_msol_fees: 0,
+            stake_activated: 0,
+            stake_deactivated: 0,
+            stake_accounts_to_update,
+            stake_accounts_updated: 0,
+            complete: stake_accounts_to_update == 0,
+        });
+
+        emit!(InitEpochReportEvent {
+            state: self.state.key(),
+            epoch: self.clock.epoch,
+            epoch_report: self.epoch_report.key(),
+            stake_accounts_to_update,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::crank::InitValidatorHistoryEvent,
+    state::{
+        validator_history::{ValidatorEpochRecord, ValidatorHistory, VALIDATOR_HISTORY_EPOCHS},
+        validator_system::ValidatorList,
+    },
+    State,
+};
+
+#[derive(Accounts)]
+pub struct InitValidatorHistory<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(address = state.validator_system.validator_list.account)]
+    pub validator_list: Account<'info, ValidatorList>,
+    /// CHECK: must be in the validator list
+    pub validator_vote: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = ValidatorHistory::SPACE,
+        seeds = [
+            &state.key().to_bytes(),
+            ValidatorHistory::SEED,
+            &validator_vote.key().to_bytes()
+        ],
+        bump
+    )]
+    pub validator_history: Box<Account<'info, ValidatorHistory>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitValidatorHistory<'info> {
+    // fn init_validator_history()
+    pub fn process(&mut self, validator_index: u32) -> Result<()> {
+        self.state
+            .validator_system
+            .get_checked(
+                &self.validator_list.to_account_info().data.as_ref().borrow(),
+                validator_index,
+                self.validator_vote.key,
+            )
+            .map_err(|e| e.with_account_name("validator_vote"))?;
+
+        self.validator_history.set_inner(ValidatorHistory {
+            state_address: self.state.key(),
+            validator_account: self.validator_vote.key(),
+            next_index: 0,
+            records: [ValidatorEpochRecord::default(); VALIDATOR_HISTORY_EPOCHS],
+        });
+
+        emit!(InitValidatorHistoryEvent {
+            state: self.state.key(),
+            validator: self.validator_vote.key(),
+            validator_history: self.validator_history.key(),
+        });
+        Ok(())
+    }
+}
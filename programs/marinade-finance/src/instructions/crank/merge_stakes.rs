@@ -4,6 +4,7 @@ use anchor_lang::solana_program::{program::invoke_signed, stake};
 use anchor_spl::stake::{withdraw, Stake, StakeAccount, Withdraw};
 
 use crate::events::crank::MergeStakesEvent;
+use crate::state::pause_flags::PauseFlags;
 use crate::state::stake_system::StakeList;
 use crate::state::validator_system::ValidatorList;
 use crate::{error::MarinadeError, state::stake_system::StakeSystem, State};
@@ -66,8 +67,30 @@ impl<'info> MergeStakes<'info> {
         source_stake_index: u32,
         validator_index: u32,
     ) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
+        let source_stake = self.source_stake.clone();
+        self.merge_source(
+            destination_stake_index,
+            &source_stake,
+            source_stake_index,
+            validator_index,
+        )?;
+        // Call this last because of index invalidation
+        self.state.stake_system.remove(
+            &mut self.stake_list.to_account_info().data.as_ref().borrow_mut(),
+            source_stake_index,
+        )
+    }
 
+    /// merges source_stake into destination_stake and updates the balances.
+    /// The source record is left in the stake list for the caller to remove
+    pub fn merge_source(
+        &mut self,
+        destination_stake_index: u32,
+        source_stake: &Account<'info, StakeAccount>,
+        source_stake_index: u32,
+        validator_index: u32,
+    ) -> Result<()> {
         let mut validator = self.state.validator_system.get(
             &self.validator_list.to_account_info().data.as_ref().borrow(),
             validator_index,
@@ -112,9 +135,9 @@ impl<'info> MergeStakes<'info> {
         let source_stake_info = self.state.stake_system.get_checked(
             &self.stake_list.to_account_info().data.as_ref().borrow(),
             source_stake_index,
-            self.source_stake.to_account_info().key,
+            source_stake.to_account_info().key,
         )?;
-        let source_delegation = if let Some(delegation) = self.source_stake.delegation() {
+        let source_delegation = if let Some(delegation) = source_stake.delegation() {
             delegation
         } else {
             return err!(MarinadeError::SourceStakeMustBeDelegated)
@@ -132,8 +155,8 @@ impl<'info> MergeStakes<'info> {
         );
 
         require_eq!(
-            self.source_stake.to_account_info().lamports(),
-            source_delegation.stake + self.source_stake.meta().unwrap().rent_exempt_reserve,
+            source_stake.to_account_info().lamports(),
+            source_delegation.stake + source_stake.meta().unwrap().rent_exempt_reserve,
             MarinadeError::SourceStakeMustBeUpdated
         );
 
@@ -145,13 +168,13 @@ impl<'info> MergeStakes<'info> {
         invoke_signed(
             &stake::instruction::merge(
                 self.destination_stake.to_account_info().key,
-                self.source_stake.to_account_info().key,
+                source_stake.to_account_info().key,
                 self.stake_deposit_authority.to_account_info().key,
             )[0],
             &[
                 self.stake_program.to_account_info(),
                 self.destination_stake.to_account_info(),
-                self.source_stake.to_account_info(),
+                source_stake.to_account_info(),
                 self.clock.to_account_info(),
                 self.stake_history.to_account_info(),
                 self.stake_deposit_authority.to_account_info(),
@@ -175,7 +198,7 @@ impl<'info> MergeStakes<'info> {
         // In normal cases (the bot merging to active accounts) the *rent-lamports* go to dest account *native lamports*,
         // so the destination account will have double the rent-exempt lamports
         let returned_stake_rent =
-            self.source_stake.meta().unwrap().rent_exempt_reserve - extra_delegated;
+            source_stake.meta().unwrap().rent_exempt_reserve - extra_delegated;
         // update validator.active_balance
         validator.active_balance += extra_delegated;
         // store in list
@@ -199,11 +222,6 @@ impl<'info> MergeStakes<'info> {
             destination_stake_index,
             destination_stake_info,
         )?;
-        // Call this last because of index invalidation
-        self.state.stake_system.remove(
-            &mut self.stake_list.to_account_info().data.as_ref().borrow_mut(),
-            source_stake_index,
-        )?;
         if returned_stake_rent > 0 {
             // withdraw the rent-exempt lamports part of merged stake to operational_sol_account for the future recreation of this slot's account
             withdraw(
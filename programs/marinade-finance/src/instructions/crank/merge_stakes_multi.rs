@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::stake::StakeAccount;
+
+use crate::error::MarinadeError;
+
+use super::merge_stakes::*;
+use crate::state::pause_flags::PauseFlags;
+
+// merge of several stake accounts of the same validator into destination_stake in one instruction.
+// merge_stakes.source_stake is the first source, the others are passed as remaining accounts.
+// source_stake_indexes must be strictly descending so removing a record
+// never moves another source of the batch
+#[derive(Accounts)]
+pub struct MergeStakesMulti<'info> {
+    pub merge_stakes: MergeStakes<'info>,
+}
+
+impl<'info> MergeStakesMulti<'info> {
+    pub const MAX_SOURCES: usize = 8;
+
+    // fn merge_stakes_multi()
+    pub fn process(
+        &mut self,
+        destination_stake_index: u32,
+        source_stake_indexes: Vec<u32>,
+        validator_index: u32,
+        other_sources: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        self.merge_stakes
+            .state
+            .check_not_paused(PauseFlags::CRANKS)?;
+        require!(
+            source_stake_indexes.len() == other_sources.len() + 1
+                && source_stake_indexes.len() <= Self::MAX_SOURCES,
+            MarinadeError::InvalidStakeAccountBatch
+        );
+        require!(
+            source_stake_indexes.windows(2).all(|w| w[0] > w[1])
+                && !source_stake_indexes.contains(&destination_stake_index),
+            MarinadeError::InvalidStakeAccountBatch
+        );
+
+        // the stake program averages unmatched credits observed, the batch does not allow it
+        let credits_observed = self
+            .merge_stakes
+            .destination_stake
+            .stake()
+            .ok_or_else(|| error!(MarinadeError::DestinationStakeMustBeDelegated))?
+            .credits_observed;
+
+        let mut sources = vec![(*self.merge_stakes.source_stake).clone()];
+        for source in other_sources {
+            sources.push(Account::<StakeAccount>::try_from(source)?);
+        }
+
+        for (source_stake, &source_stake_index) in sources.iter().zip(&source_stake_indexes) {
+            require_eq!(
+                source_stake
+                    .stake()
+                    .ok_or_else(|| error!(MarinadeError::SourceStakeMustBeDelegated))?
+                    .credits_observed,
+                credits_observed,
+                MarinadeError::StakeCreditsObservedMismatch
+            );
+            self.merge_stakes.merge_source(
+                destination_stake_index,
+                source_stake,
+                source_stake_index,
+                validator_index,
+            )?;
+        }
+
+        for &source_stake_index in &source_stake_indexes {
+            self.merge_stakes.state.stake_system.remove(
+                &mut self
+                    .merge_stakes
+                    .stake_list
+                    .to_account_info()
+                    .data
+                    .as_ref()
+                    .borrow_mut(),
+                source_stake_index,
+            )?;
+        }
+        Ok(())
+    }
+}
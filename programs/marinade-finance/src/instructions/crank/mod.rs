@@ -1,11 +1,33 @@
+pub mod apply_oracle_scores;
+pub mod claim_vested_fees;
+pub mod crank_claim;
+pub mod crank_tip;
 pub mod deactivate_stake;
+pub mod init_epoch_report;
+pub mod init_validator_history;
 pub mod merge_stakes;
+pub mod merge_stakes_multi;
+pub mod rebalance_liq_pool;
 pub mod redelegate;
+pub mod remove_dead_validator;
 pub mod stake_reserve;
+pub mod tally_gauge;
+pub mod unstake_delinquent;
 pub mod update;
 
+pub use apply_oracle_scores::*;
+pub use claim_vested_fees::*;
+pub use crank_claim::*;
+pub use crank_tip::*;
 pub use deactivate_stake::*;
+pub use init_epoch_report::*;
+pub use init_validator_history::*;
 pub use merge_stakes::*;
+pub use merge_stakes_multi::*;
+pub use rebalance_liq_pool::*;
 pub use redelegate::*;
+pub use remove_dead_validator::*;
 pub use stake_reserve::*;
+pub use tally_gauge::*;
+pub use unstake_delinquent::*;
 pub use update::*;
@@ -0,0 +1,133 @@
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    error::MarinadeError, events::crank::RebalanceLiqPoolEvent, state::liq_pool::LiqPool, State,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct RebalanceLiqPool<'info> {
+    #[account(
+        mut,
+        has_one = msol_mint
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(mut)]
+    pub msol_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::SOL_LEG_SEED
+        ],
+        bump = state.liq_pool.sol_leg_bump_seed
+    )]
+    pub liq_pool_sol_leg_pda: SystemAccount<'info>,
+    #[account(
+        mut,
+        address = state.liq_pool.msol_leg
+    )]
+    pub liq_pool_msol_leg: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::MSOL_LEG_AUTHORITY_SEED
+        ],
+        bump = state.liq_pool.msol_leg_authority_bump_seed
+    )]
+    pub liq_pool_msol_leg_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            State::RESERVE_SEED
+        ],
+        bump = state.reserve_bump_seed
+    )]
+    pub reserve_pda: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RebalanceLiqPool<'info> {
+    /// permissionless
+    /// Unstakes the mSOL of the liq-pool above liq_pool_max_msol_share against the reserve, at the mSOL price and without fee.
+    /// Only the reserve lamports not needed for delayed-unstake tickets are used
+    /// pub fn rebalance_liq_pool()
+    pub fn process(&mut self) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
+        self.state.check_liq_pool_not_lent()?;
+
+        let sol_leg_balance = self.liq_pool_sol_leg_pda.lamports();
+        let msol_leg_balance = self.liq_pool_msol_leg.amount;
+        let excess_value = LiqPool::excess_msol_value(
+            sol_leg_balance.saturating_sub(self.state.rent_exempt_for_token_acc),
+            self.state.msol_to_sol(msol_leg_balance)?,
+            self.state.liq_pool_max_msol_share,
+        );
+        let reserve_balance = self.reserve_pda.lamports();
+        let reserve_free = u64::try_from(self.state.stake_delta(reserve_balance).max(0))
+            .map_err(|_| error!(MarinadeError::CalculationFailure))?;
+
+        // price rounding goes against the pool, the mSOL price can only go up
+        let msol_amount = self
+            .state
+            .calc_msol_from_lamports(excess_value.min(reserve_free))?
+            .min(msol_leg_balance);
+        let lamports = self.state.msol_to_sol(msol_amount)?;
+        require_gt!(lamports, 0, MarinadeError::LiqPoolRebalanceNotNeeded);
+
+        burn(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.msol_mint.to_account_info(),
+                    from: self.liq_pool_msol_leg.to_account_info(),
+                    authority: self.liq_pool_msol_leg_authority.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    LiqPool::MSOL_LEG_AUTHORITY_SEED,
+                    &[self.state.liq_pool.msol_leg_authority_bump_seed],
+                ]],
+            ),
+            msol_amount,
+        )?;
+        self.state.on_msol_burn(msol_amount);
+
+        transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.reserve_pda.to_account_info(),
+                    to: self.liq_pool_sol_leg_pda.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    State::RESERVE_SEED,
+                    &[self.state.reserve_bump_seed],
+                ]],
+            ),
+            lamports,
+        )?;
+        self.state.on_transfer_from_reserve(lamports);
+
+        emit!(RebalanceLiqPoolEvent {
+            state: self.state.key(),
+            sol_leg_balance,
+            msol_leg_balance,
+            reserve_balance,
+            max_msol_share: self.state.liq_pool_max_msol_share,
+            msol_burned: msol_amount,
+            lamports,
+        });
+
+        Ok(())
+    }
+}
@@ -1,15 +1,19 @@
 use crate::{
-    checks::check_stake_amount_and_validator,
+    checks::{check_feature_account, check_stake_amount_and_validator},
     error::MarinadeError,
     events::crank::{RedelegateEvent, SplitStakeAccountInfo},
     state::{
+        protected_validator::ProtectedValidator,
         stake_system::{StakeList, StakeRecord, StakeSystem},
+        validator_blacklist::BlacklistedValidator,
+        validator_stake_cap::ValidatorStakeCap,
         validator_system::ValidatorList,
     },
     State,
 };
 use std::{cmp::min, convert::TryFrom};
 
+use crate::state::pause_flags::PauseFlags;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{
     program::invoke_signed,
@@ -85,6 +89,45 @@ pub struct ReDelegate<'info> {
 
     pub system_program: Program<'info, System>,
     pub stake_program: Program<'info, Stake>,
+
+    /// CHECK: must not be initialized for the destination, see BlacklistedValidator
+    /// Required while any validator is blacklisted
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            BlacklistedValidator::SEED,
+            &dest_validator_account.key().to_bytes(),
+        ],
+        bump,
+    )]
+    pub blacklisted_validator: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: must not be initialized for the source, see ProtectedValidator.
+    /// Required while any validator is protected
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            ProtectedValidator::SEED,
+            &stake_account
+                .delegation()
+                .map(|delegation| delegation.voter_pubkey)
+                .unwrap_or_default().to_bytes(),
+        ],
+        bump,
+    )]
+    pub protected_validator: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: ValidatorStakeCap of the destination, may not be initialized.
+    /// Required while any validator has a cap
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            ValidatorStakeCap::SEED,
+            &dest_validator_account.key().to_bytes(),
+        ],
+        bump,
+    )]
+    pub dest_validator_stake_cap: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> ReDelegate<'info> {
@@ -105,7 +148,24 @@ impl<'info> ReDelegate<'info> {
         source_validator_index: u32,
         dest_validator_index: u32,
     ) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
+        check_feature_account(
+            &self.blacklisted_validator,
+            self.state.has_blacklisted_validators(),
+            "blacklisted_validator",
+        )?;
+        BlacklistedValidator::check_not_blacklisted(self.blacklisted_validator.as_deref())?;
+        check_feature_account(
+            &self.protected_validator,
+            self.state.has_protected_validators(),
+            "protected_validator",
+        )?;
+        ProtectedValidator::check_not_protected(self.protected_validator.as_deref())?;
+        check_feature_account(
+            &self.dest_validator_stake_cap,
+            self.state.has_validator_stake_caps(),
+            "dest_validator_stake_cap",
+        )?;
 
         require_neq!(
             source_validator_index,
@@ -170,9 +230,13 @@ impl<'info> ReDelegate<'info> {
         // if validator is already on-target (or the split will be lower than min_stake), exit now
         if source_validator.active_balance
             < source_validator_stake_target + self.state.stake_system.min_stake
+            || !self.state.is_off_target(
+                source_validator.active_balance,
+                source_validator_stake_target,
+            )
         {
             msg!(
-                "Source validator {} stake {} is <= target {} +min_stake",
+                "Source validator {} stake {} is <= target {} +min_stake or within rebalance threshold",
                 source_validator.validator_account,
                 source_validator.active_balance,
                 source_validator_stake_target
@@ -202,17 +266,30 @@ impl<'info> ReDelegate<'info> {
             .map_err(|e| e.with_account_name("dest_validator_account"))?;
         let dest_validator_balance = dest_validator.active_balance;
 
-        // compute dest validator target
-        let dest_validator_stake_target = self
-            .state
-            .validator_system
-            .validator_stake_target(&dest_validator, total_stake_target)?;
+        // compute dest validator target, at most its stake cap
+        let dest_stake_cap = ValidatorStakeCap::load(self.dest_validator_stake_cap.as_deref())?;
+        if let Some(dest_stake_cap) = &dest_stake_cap {
+            require_keys_eq!(
+                dest_stake_cap.validator_account,
+                dest_validator.validator_account,
+                MarinadeError::WrongValidatorStakeCap
+            );
+        }
+        let dest_validator_stake_target = ValidatorStakeCap::apply(
+            dest_stake_cap.as_ref(),
+            self.state
+                .validator_system
+                .validator_stake_target(&dest_validator, total_stake_target)?,
+        );
         // verify: dest validator must be under target
         if dest_validator.active_balance + self.state.stake_system.min_stake
             > dest_validator_stake_target
+            || !self
+                .state
+                .is_off_target(dest_validator.active_balance, dest_validator_stake_target)
         {
             msg!(
-                "Dest validator {} stake+min_stake {} is > target {}",
+                "Dest validator {} stake+min_stake {} is > target {} or within rebalance threshold",
                 dest_validator.validator_account,
                 dest_validator.active_balance,
                 dest_validator_stake_target
@@ -1,8 +1,15 @@
 use crate::{
+    checks::check_feature_account,
     error::MarinadeError,
     events::crank::StakeReserveEvent,
     state::{
+        directed_stake::DirectedStakeRecord,
+        epoch_report::EpochReport,
+        gauge::Gauge,
         stake_system::{StakeList, StakeSystem},
+        validator_blacklist::BlacklistedValidator,
+        validator_bond::ValidatorBond,
+        validator_stake_cap::ValidatorStakeCap,
         validator_system::ValidatorList,
     },
     State, ID,
@@ -26,6 +33,9 @@ use anchor_lang::{
 use anchor_spl::stake::{withdraw, Stake, StakeAccount, Withdraw};
 use std::convert::TryFrom;
 
+use super::crank_tip::pay_crank_tip;
+use crate::state::pause_flags::PauseFlags;
+
 #[derive(Accounts)]
 pub struct StakeReserve<'info> {
     #[account(mut)]
@@ -86,6 +96,82 @@ pub struct StakeReserve<'info> {
 
     pub system_program: Program<'info, System>,
     pub stake_program: Program<'info, Stake>,
+
+    /// CHECK: must not be initialized, see BlacklistedValidator
+    /// Required while any validator is blacklisted
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            BlacklistedValidator::SEED,
+            &validator_vote.key().to_bytes(),
+        ],
+        bump,
+    )]
+    pub blacklisted_validator: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: ValidatorBond of the validator, may not be initialized.
+    /// Required while State::bond_stake_multiplier is set
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            ValidatorBond::SEED,
+            &validator_vote.key().to_bytes(),
+        ],
+        bump,
+    )]
+    pub validator_bond: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: DirectedStakeRecord of the validator, may not be initialized.
+    /// Required while State::directed_stake_max_share is set
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            DirectedStakeRecord::SEED,
+            &validator_vote.key().to_bytes()
+        ],
+        bump
+    )]
+    pub directed_stake: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: ValidatorStakeCap of the validator, may not be initialized.
+    /// Required while any validator has a cap
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            ValidatorStakeCap::SEED,
+            &validator_vote.key().to_bytes()
+        ],
+        bump
+    )]
+    pub validator_stake_cap: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Gauge of the validator, may not be initialized.
+    /// Required while gauge weights spread a share of the stake target
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            Gauge::SEED,
+            &validator_vote.key().to_bytes()
+        ],
+        bump
+    )]
+    pub gauge: Option<UncheckedAccount<'info>>,
+
+    // receives State::crank_tip of the staked lamports, when passed
+    #[account(mut)]
+    pub crank_tip_to: Option<SystemAccount<'info>>,
+
+    // report of the current epoch, updated when passed
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            EpochReport::SEED,
+            &clock.epoch.to_le_bytes()
+        ],
+        bump
+    )]
+    pub epoch_report: Option<Box<Account<'info, EpochReport>>>,
 }
 
 impl<'info> StakeReserve<'info> {
@@ -94,7 +180,29 @@ impl<'info> StakeReserve<'info> {
     /// stakes from available delta-stake in data.validator_index
     /// pub fn stake_reserve()
     pub fn process(&mut self, validator_index: u32) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
+        check_feature_account(
+            &self.directed_stake,
+            self.state.is_directed_stake_enabled(),
+            "directed_stake",
+        )?;
+        check_feature_account(
+            &self.validator_stake_cap,
+            self.state.has_validator_stake_caps(),
+            "validator_stake_cap",
+        )?;
+        check_feature_account(&self.gauge, self.state.is_gauge_stake_enabled(), "gauge")?;
+        check_feature_account(
+            &self.validator_bond,
+            self.state.is_bond_required(),
+            "validator_bond",
+        )?;
+        check_feature_account(
+            &self.blacklisted_validator,
+            self.state.has_blacklisted_validators(),
+            "blacklisted_validator",
+        )?;
+        BlacklistedValidator::check_not_blacklisted(self.blacklisted_validator.as_deref())?;
 
         sol_log_compute_units();
 
@@ -174,10 +282,14 @@ impl<'info> StakeReserve<'info> {
             MarinadeError::TooEarlyForStakeDelta
         );
 
-        let validator_stake_target = self
-            .state
-            .validator_system
-            .validator_stake_target(&validator, total_stake_target)?;
+        let validator_stake_target = self.state.validator_stake_target(
+            &validator,
+            DirectedStakeRecord::load(self.directed_stake.as_deref())?.as_ref(),
+            Gauge::load(self.gauge.as_deref())?.as_ref(),
+            ValidatorStakeCap::load(self.validator_stake_cap.as_deref())?.as_ref(),
+            ValidatorBond::load(self.validator_bond.as_deref())?.as_ref(),
+            total_stake_target,
+        )?;
 
         //verify the validator is under-staked
         if validator_active_balance >= validator_stake_target {
@@ -300,6 +412,21 @@ impl<'info> StakeReserve<'info> {
         // update also total_active_balance
         self.state.validator_system.total_active_balance += stake_target;
 
+        if let Some(epoch_report) = &mut self.epoch_report {
+            epoch_report.stake_activated += stake_target;
+        }
+        if let Some(crank_tip_to) = &self.crank_tip_to {
+            let state_address = self.state.key();
+            pay_crank_tip(
+                &mut self.state,
+                state_address,
+                self.reserve_pda.to_account_info(),
+                self.system_program.to_account_info(),
+                crank_tip_to.to_account_info(),
+                stake_target,
+            )?;
+        }
+
         emit!(StakeReserveEvent {
             state: self.state.key(),
             epoch: self.clock.epoch,
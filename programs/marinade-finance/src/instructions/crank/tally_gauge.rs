@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{error::MarinadeError, events::crank::TallyGaugeEvent, state::gauge::Gauge, State};
+
+#[derive(Accounts)]
+pub struct TallyGauge<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        constraint = gauge.state_address == state.key() @ MarinadeError::WrongGauge
+    )]
+    pub gauge: Box<Account<'info, Gauge>>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> TallyGauge<'info> {
+    /// the mSOL locked in the gauge becomes its weight for the stake targets, once per epoch
+    // fn tally_gauge()
+    pub fn process(&mut self) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
+
+        let old_weight = self.gauge.tally(self.clock.epoch)?;
+        self.state.total_gauge_weight =
+            self.state.total_gauge_weight - old_weight + self.gauge.weight;
+
+        emit!(TallyGaugeEvent {
+            state: self.state.key(),
+            epoch: self.clock.epoch,
+            gauge: self.gauge.key(),
+            validator: self.gauge.validator_account,
+            old_weight,
+            new_weight: self.gauge.weight,
+            total_gauge_weight: self.state.total_gauge_weight,
+        });
+        Ok(())
+    }
+}
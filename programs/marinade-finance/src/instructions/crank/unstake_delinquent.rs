@@ -0,0 +1,154 @@
+use crate::{
+    checks::check_stake_amount_and_validator,
+    error::MarinadeError,
+    events::crank::UnstakeDelinquentEvent,
+    state::{
+        stake_system::{StakeList, StakeSystem},
+        validator_criteria::VoteAccountSummary,
+        validator_system::ValidatorList,
+    },
+    State,
+};
+
+use crate::state::pause_flags::PauseFlags;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote;
+use anchor_spl::stake::{deactivate_stake, DeactivateStake, Stake, StakeAccount};
+
+// emergency_unstake without the manager, for validators that did not vote
+// in the last state.delinquent_unstake_slots slots
+#[derive(Accounts)]
+pub struct UnstakeDelinquent<'info> {
+    #[account(mut)]
+    pub state: Account<'info, State>,
+    #[account(
+        mut,
+        address = state.validator_system.validator_list.account,
+    )]
+    pub validator_list: Account<'info, ValidatorList>,
+    #[account(
+        mut,
+        address = state.stake_system.stake_list.account,
+    )]
+    pub stake_list: Account<'info, StakeList>,
+    #[account(mut)]
+    pub stake_account: Account<'info, StakeAccount>,
+    /// CHECK: parsed by VoteAccountSummary
+    #[account(owner = vote::program::ID @ MarinadeError::InvalidVoteAccount)]
+    pub validator_vote: UncheckedAccount<'info>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            StakeSystem::STAKE_DEPOSIT_SEED
+        ],
+        bump = state.stake_system.stake_deposit_bump_seed
+    )]
+    pub stake_deposit_authority: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    pub stake_program: Program<'info, Stake>,
+}
+
+impl<'info> UnstakeDelinquent<'info> {
+    // fn unstake_delinquent()
+    pub fn process(&mut self, stake_index: u32, validator_index: u32) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
+        require_gt!(
+            self.state.delinquent_unstake_slots,
+            0,
+            MarinadeError::DelinquentUnstakeDisabled
+        );
+
+        let mut stake = self.state.stake_system.get_checked(
+            &self.stake_list.to_account_info().data.as_ref().borrow(),
+            stake_index,
+            self.stake_account.to_account_info().key,
+        )?;
+        require_eq!(
+            stake.is_emergency_unstaking,
+            0,
+            MarinadeError::StakeAccountIsEmergencyUnstaking
+        );
+
+        let mut validator = self.state.validator_system.get_checked(
+            &self.validator_list.to_account_info().data.as_ref().borrow(),
+            validator_index,
+            self.validator_vote.key,
+        )?;
+
+        let vote = VoteAccountSummary::load(&self.validator_vote)?;
+        require!(
+            vote.is_silent_for(self.state.delinquent_unstake_slots, self.clock.slot),
+            MarinadeError::ValidatorNotDelinquent
+        );
+
+        // check that the account is delegated to the right validator
+        check_stake_amount_and_validator(
+            &self.stake_account,
+            stake.last_update_delegated_lamports,
+            &validator.validator_account,
+        )?;
+
+        // mark the validator for exit: no stake targets it anymore
+        let old_score = validator.score;
+        self.state.validator_system.total_validator_score -= old_score;
+        validator.score = 0;
+
+        let unstake_amount = stake.last_update_delegated_lamports;
+        self.state.on_stake_moved(unstake_amount, &self.clock)?;
+        msg!("Deactivate whole stake {}", stake.stake_account);
+        deactivate_stake(CpiContext::new_with_signer(
+            self.stake_program.to_account_info(),
+            DeactivateStake {
+                stake: self.stake_account.to_account_info(),
+                staker: self.stake_deposit_authority.to_account_info(),
+                clock: self.clock.to_account_info(),
+            },
+            &[&[
+                &self.state.key().to_bytes(),
+                StakeSystem::STAKE_DEPOSIT_SEED,
+                &[self.state.stake_system.stake_deposit_bump_seed],
+            ]],
+        ))?;
+        stake.is_emergency_unstaking = 1;
+
+        // same accounting as emergency_unstake
+        validator.active_balance -= unstake_amount;
+        self.state.validator_system.total_active_balance -= unstake_amount;
+        self.state.emergency_cooling_down += unstake_amount;
+
+        self.state.stake_system.set(
+            &mut self.stake_list.to_account_info().data.as_ref().borrow_mut(),
+            stake_index,
+            stake,
+        )?;
+        self.state.validator_system.set(
+            &mut self
+                .validator_list
+                .to_account_info()
+                .data
+                .as_ref()
+                .borrow_mut(),
+            validator_index,
+            validator,
+        )?;
+
+        emit!(UnstakeDelinquentEvent {
+            state: self.state.key(),
+            epoch: self.clock.epoch,
+            stake_index,
+            stake_account: self.stake_account.key(),
+            validator_index,
+            validator_vote: self.validator_vote.key(),
+            last_vote_slot: vote.last_vote_slot,
+            old_score,
+            unstaked_amount: unstake_amount,
+            total_active_balance: self.state.validator_system.total_active_balance,
+            emergency_cooling_down: self.state.emergency_cooling_down,
+        });
+
+        Ok(())
+    }
+}
@@ -8,16 +8,27 @@ use anchor_lang::system_program::{transfer, Transfer};
 use anchor_spl::stake::{withdraw, Stake, StakeAccount, Withdraw};
 use anchor_spl::token::{mint_to, Mint, MintTo, Token};
 
-use crate::events::crank::{UpdateActiveEvent, UpdateDeactivatedEvent};
+use crate::events::crank::{
+    CircuitBreakerTrippedEvent, CommissionViolationEvent, StakeFlaggedEvent, UpdateActiveEvent,
+    UpdateDeactivatedEvent,
+};
 use crate::events::U64ValueChange;
+use crate::state::epoch_report::EpochReport;
 use crate::state::stake_system::StakeList;
-use crate::state::validator_system::ValidatorList;
+use crate::state::treasury_split::TreasurySplit;
+use crate::state::treasury_vesting::TreasuryVesting;
+use crate::state::validator_criteria::VoteAccountSummary;
+use crate::state::validator_history::ValidatorHistory;
+use crate::state::validator_system::{ValidatorList, ValidatorRecord};
 use crate::{
     error::MarinadeError,
     state::stake_system::{StakeRecord, StakeSystem},
     State,
 };
 
+use super::crank_tip::pay_crank_tip;
+use crate::state::pause_flags::PauseFlags;
+
 #[derive(Accounts)]
 pub struct UpdateCommon<'info> {
     #[account(
@@ -74,6 +85,30 @@ pub struct UpdateCommon<'info> {
 
     pub stake_program: Program<'info, Stake>,
     pub token_program: Program<'info, Token>,
+
+    // receives the reward fees instead of treasury_msol_account, required while state.treasury_split_enabled
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            TreasurySplit::SEED
+        ],
+        bump
+    )]
+    pub treasury_split: Option<Box<Account<'info, TreasurySplit>>>,
+
+    // escrow of the reward fees, required while state.treasury_vesting_enabled
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            TreasuryVesting::SEED
+        ],
+        bump = treasury_vesting.bump
+    )]
+    pub treasury_vesting: Option<Box<Account<'info, TreasuryVesting>>>,
+    /// CHECK: must be treasury_vesting.msol_escrow, checked in code
+    #[account(mut)]
+    pub treasury_vesting_escrow: Option<UncheckedAccount<'info>>,
 }
 
 #[derive(Accounts)]
@@ -84,6 +119,40 @@ pub struct UpdateActive<'info> {
         address = common.state.validator_system.validator_list.account,
     )]
     pub validator_list: Account<'info, ValidatorList>,
+
+    /// CHECK: parsed by VoteAccountSummary
+    // vote account of the stake, required while state.commission_ceiling is set
+    #[account(
+        address = common.stake_account.delegation()
+            .map(|delegation| delegation.voter_pubkey)
+            .unwrap_or_default()
+    )]
+    pub validator_vote: Option<UncheckedAccount<'info>>,
+
+    // performance history of the validator, recorded when passed
+    #[account(
+        mut,
+        constraint = validator_history.state_address == common.state.key()
+            @ MarinadeError::WrongValidatorHistory
+    )]
+    pub validator_history: Option<Box<Account<'info, ValidatorHistory>>>,
+
+    // receives State::crank_tip of the rewards, when passed along with system_program
+    #[account(mut)]
+    pub crank_tip_to: Option<SystemAccount<'info>>,
+    pub system_program: Option<Program<'info, System>>,
+
+    // report of the current epoch, updated when passed
+    #[account(
+        mut,
+        seeds = [
+            &common.state.key().to_bytes(),
+            EpochReport::SEED,
+            &common.clock.epoch.to_le_bytes()
+        ],
+        bump
+    )]
+    pub epoch_report: Option<Box<Account<'info, EpochReport>>>,
 }
 
 impl<'info> Deref for UpdateActive<'info> {
@@ -112,6 +181,22 @@ pub struct UpdateDeactivated<'info> {
     pub operational_sol_account: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    // receives State::crank_tip of the withdrawn lamports, when passed
+    #[account(mut)]
+    pub crank_tip_to: Option<SystemAccount<'info>>,
+
+    // report of the current epoch, updated when passed
+    #[account(
+        mut,
+        seeds = [
+            &common.state.key().to_bytes(),
+            EpochReport::SEED,
+            &common.clock.epoch.to_le_bytes()
+        ],
+        bump
+    )]
+    pub epoch_report: Option<Box<Account<'info, EpochReport>>>,
 }
 
 impl<'info> Deref for UpdateDeactivated<'info> {
@@ -134,11 +219,12 @@ struct BeginOutput {
 }
 
 impl<'info> UpdateCommon<'info> {
-    fn begin(&mut self, stake_index: u32) -> Result<BeginOutput> {
-        let is_treasury_msol_ready_for_transfer = self
-            .state
-            .get_treasury_msol_balance(&self.treasury_msol_account)
-            .is_some();
+    fn begin(
+        &mut self,
+        stake_index: u32,
+        treasury_destinations: &[AccountInfo<'info>],
+    ) -> Result<BeginOutput> {
+        let is_treasury_msol_ready_for_transfer = self.is_treasury_ready(treasury_destinations)?;
 
         let virtual_reserve_balance =
             self.state.available_reserve_balance + self.state.rent_exempt_for_token_acc;
@@ -183,6 +269,33 @@ impl<'info> UpdateCommon<'info> {
         })
     }
 
+    /// the fees are taken only when all the destinations can receive them
+    fn is_treasury_ready(&self, treasury_destinations: &[AccountInfo<'info>]) -> Result<bool> {
+        if self.state.treasury_vesting_enabled {
+            // the escrow is ready, the destinations are checked by claim_vested_fees
+            let treasury_vesting = self
+                .treasury_vesting
+                .as_ref()
+                .ok_or_else(|| error!(MarinadeError::MissingTreasuryVesting))?;
+            let treasury_vesting_escrow = self
+                .treasury_vesting_escrow
+                .as_ref()
+                .ok_or_else(|| error!(MarinadeError::MissingTreasuryVesting))?;
+            require_keys_eq!(treasury_vesting_escrow.key(), treasury_vesting.msol_escrow);
+            Ok(true)
+        } else if self.state.treasury_split_enabled {
+            self.treasury_split
+                .as_ref()
+                .ok_or_else(|| error!(MarinadeError::MissingTreasurySplit))?
+                .check_destinations(&self.state, treasury_destinations)
+        } else {
+            Ok(self
+                .state
+                .get_treasury_msol_balance(&self.treasury_msol_account)
+                .is_some())
+        }
+    }
+
     pub fn withdraw_to_reserve(&mut self, amount: u64) -> Result<()> {
         if amount > 0 {
             // Move unstaked + rewards for restaking
@@ -210,14 +323,18 @@ impl<'info> UpdateCommon<'info> {
         Ok(())
     }
 
-    pub fn mint_to_treasury(&mut self, msol_lamports: u64) -> Result<()> {
+    pub fn mint_to_treasury(
+        &mut self,
+        treasury_msol_account: AccountInfo<'info>,
+        msol_lamports: u64,
+    ) -> Result<()> {
         if msol_lamports > 0 {
             mint_to(
                 CpiContext::new_with_signer(
                     self.token_program.to_account_info(),
                     MintTo {
                         mint: self.msol_mint.to_account_info(),
-                        to: self.treasury_msol_account.to_account_info(),
+                        to: treasury_msol_account,
                         authority: self.msol_mint_authority.to_account_info(),
                     },
                     &[&[
@@ -240,6 +357,22 @@ impl<'info> UpdateCommon<'info> {
         // DIVIDED by msol_supply
         let old = self.state.msol_price;
         self.state.msol_price = self.state.msol_to_sol(State::PRICE_DENOMINATOR)?; // store binary-denominated mSOL price
+        let breaker = self.state.price_circuit_breaker;
+        let msol_price = self.state.msol_price;
+        if self
+            .state
+            .price_circuit_breaker
+            .on_price_update(msol_price, self.clock.slot)
+        {
+            msg!("mSOL price circuit breaker tripped");
+            emit!(CircuitBreakerTrippedEvent {
+                state: self.state.key(),
+                reference_price: breaker.reference_price,
+                reference_slot: breaker.reference_slot,
+                msol_price,
+                slot: self.clock.slot,
+            });
+        }
         Ok(U64ValueChange {
             old,
             new: self.state.msol_price,
@@ -247,18 +380,109 @@ impl<'info> UpdateCommon<'info> {
     }
 
     // returns fees in msol
-    pub fn mint_protocol_fees(&mut self, lamports_incoming: u64) -> Result<u64> {
+    pub fn mint_protocol_fees(
+        &mut self,
+        lamports_incoming: u64,
+        treasury_destinations: &[AccountInfo<'info>],
+    ) -> Result<u64> {
         // apply x% protocol fee on staking rewards (do this before updating validators' balance, so it's 1% at old, lower, price)
         let protocol_rewards_fee = self.state.reward_fee.apply(lamports_incoming);
         msg!("protocol_rewards_fee {}", protocol_rewards_fee);
         // compute mSOL amount for protocol_rewards_fee
         let fee_as_msol_amount = self.state.calc_msol_from_lamports(protocol_rewards_fee)?;
-        self.mint_to_treasury(fee_as_msol_amount)?;
+        if self.state.treasury_vesting_enabled {
+            // both checked by begin
+            let escrow = self
+                .treasury_vesting_escrow
+                .as_ref()
+                .unwrap()
+                .to_account_info();
+            self.mint_to_treasury(escrow, fee_as_msol_amount)?;
+            let epoch = self.clock.epoch;
+            self.treasury_vesting
+                .as_mut()
+                .unwrap()
+                .deposit(epoch, fee_as_msol_amount);
+        } else if self.state.treasury_split_enabled {
+            let shares = self
+                .treasury_split
+                .as_ref()
+                .ok_or_else(|| error!(MarinadeError::MissingTreasurySplit))?
+                .split(fee_as_msol_amount)?;
+            for (destination, share) in treasury_destinations.iter().zip(shares) {
+                self.mint_to_treasury(destination.clone(), share)?;
+            }
+        } else {
+            let treasury_msol_account = self.treasury_msol_account.to_account_info();
+            self.mint_to_treasury(treasury_msol_account, fee_as_msol_amount)?;
+        }
         Ok(fee_as_msol_amount)
     }
 }
 
 impl<'info> UpdateActive<'info> {
+    /// the rewards paid at the start of this epoch are the results of the previous one
+    fn record_history(
+        &mut self,
+        validator: &ValidatorRecord,
+        effective_stake: u64,
+        rewards: u64,
+    ) -> Result<()> {
+        let validator_history = match &mut self.validator_history {
+            Some(validator_history) => validator_history,
+            None => return Ok(()),
+        };
+        require_keys_eq!(
+            validator_history.validator_account,
+            validator.validator_account,
+            MarinadeError::WrongValidatorHistory
+        );
+        let epoch = self.common.clock.epoch.saturating_sub(1);
+        let credits = self
+            .validator_vote
+            .as_ref()
+            .map(|validator_vote| {
+                VoteAccountSummary::epoch_credits(&validator_vote.data.borrow(), epoch)
+            })
+            .transpose()?;
+        validator_history.record(epoch, credits, effective_stake, rewards);
+        Ok(())
+    }
+
+    /// zero the score of a validator charging more than state.commission_ceiling,
+    /// so the rebalancing cranks unstake from it
+    fn check_commission_ceiling(
+        &mut self,
+        validator: &mut ValidatorRecord,
+        validator_index: u32,
+    ) -> Result<()> {
+        let commission_ceiling = self.state.commission_ceiling;
+        if commission_ceiling.basis_points == 0 {
+            return Ok(());
+        }
+        let validator_vote = self
+            .validator_vote
+            .as_ref()
+            .ok_or_else(|| error!(MarinadeError::MissingValidatorVote))?;
+        let commission = VoteAccountSummary::load(validator_vote)?.commission_fee();
+        if commission <= commission_ceiling || validator.score == 0 {
+            return Ok(());
+        }
+        let old_score = validator.score;
+        self.state.validator_system.total_validator_score -= old_score;
+        validator.score = 0;
+        emit!(CommissionViolationEvent {
+            state: self.state.key(),
+            epoch: self.clock.epoch,
+            validator_index,
+            validator_vote: validator.validator_account,
+            commission,
+            commission_ceiling,
+            old_score,
+        });
+        Ok(())
+    }
+
     /// Compute rewards for a single stake account
     /// take 1% protocol fee for treasury & add the rest to validator_system.total_balance
     /// update mSOL price accordingly
@@ -266,15 +490,20 @@ impl<'info> UpdateActive<'info> {
     /// (cool-down period is complete) delete-withdraw the stake-account, send SOL to reserve-account
     //
     // fn update_active()
-    pub fn process(&mut self, stake_index: u32, validator_index: u32) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+    pub fn process(
+        &mut self,
+        stake_index: u32,
+        validator_index: u32,
+        treasury_destinations: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
 
         let total_virtual_staked_lamports = self.state.total_virtual_staked_lamports();
         let msol_supply = self.state.msol_supply;
         let BeginOutput {
             mut stake,
             is_treasury_msol_ready_for_transfer,
-        } = self.begin(stake_index)?;
+        } = self.begin(stake_index, treasury_destinations)?;
 
         let delegation = self.stake_account.delegation().ok_or_else(|| {
             error!(MarinadeError::RequiredDelegatedStake).with_account_name("stake_account")
@@ -312,7 +541,7 @@ impl<'info> UpdateActive<'info> {
             self.withdraw_to_reserve(extra_lamports)?;
             // after sending to reserve, we take protocol_fees as minted mSOL
             if is_treasury_msol_ready_for_transfer {
-                Some(self.mint_protocol_fees(extra_lamports)?)
+                Some(self.mint_protocol_fees(extra_lamports, treasury_destinations)?)
             } else {
                 None
             }
@@ -332,7 +561,7 @@ impl<'info> UpdateActive<'info> {
                 msg!("Staking rewards: {}", rewards);
 
                 let delegation_growth_msol_fees = if is_treasury_msol_ready_for_transfer {
-                    Some(self.mint_protocol_fees(rewards)?)
+                    Some(self.mint_protocol_fees(rewards, treasury_destinations)?)
                 } else {
                     None
                 };
@@ -346,6 +575,20 @@ impl<'info> UpdateActive<'info> {
                 //slashed
                 let slashed = stake.last_update_delegated_lamports - delegated_lamports;
                 msg!("slashed {}", slashed);
+                let flag_recorded = self.state.stake_system.can_flag_stakes();
+                if flag_recorded {
+                    stake.flagged = 1;
+                }
+                emit!(StakeFlaggedEvent {
+                    state: self.state.key(),
+                    epoch: self.clock.epoch,
+                    stake_index,
+                    stake_account: stake.stake_account,
+                    validator_vote: delegation.voter_pubkey,
+                    last_update_delegated_lamports: stake.last_update_delegated_lamports,
+                    observed_lamports: delegated_lamports,
+                    flag_recorded,
+                });
                 //validator balance is updated with slashed
                 validator.active_balance = validator.active_balance.saturating_sub(slashed);
                 self.state.validator_system.total_active_balance =
@@ -358,6 +601,7 @@ impl<'info> UpdateActive<'info> {
             };
 
         // mark stake-account as visited
+        let first_update_in_epoch = stake.last_update_epoch < self.clock.epoch;
         stake.last_update_epoch = self.clock.epoch;
         let delegation_change = {
             let old = stake.last_update_delegated_lamports;
@@ -368,6 +612,34 @@ impl<'info> UpdateActive<'info> {
             }
         };
 
+        self.check_commission_ceiling(&mut validator, validator_index)?;
+        let rewards = delegated_lamports.saturating_sub(delegation_change.old) + extra_lamports;
+        self.record_history(&validator, delegation_change.old, rewards)?;
+        if first_update_in_epoch {
+            if let Some(epoch_report) = &mut self.epoch_report {
+                epoch_report.on_stake_account_updated(
+                    rewards,
+                    extra_msol_fees.unwrap_or(0) + delegation_growth_msol_fees.unwrap_or(0),
+                );
+            }
+        }
+        if let Some(crank_tip_to) = &self.crank_tip_to {
+            let system_program = self
+                .system_program
+                .as_ref()
+                .ok_or_else(|| error!(MarinadeError::MissingSystemProgram))?
+                .to_account_info();
+            let state_address = self.state.key();
+            pay_crank_tip(
+                &mut self.common.state,
+                state_address,
+                self.common.reserve_pda.to_account_info(),
+                system_program,
+                crank_tip_to.to_account_info(),
+                rewards,
+            )?;
+        }
+
         //update validator-list
         self.state.validator_system.set(
             &mut self
@@ -421,8 +693,12 @@ impl<'info> UpdateDeactivated<'info> {
     /// update mSOL price accordingly
     /// Optional Future Expansion: Partial: If the stake-account is a fully-deactivated stake account ready to withdraw,
     /// (cool-down period is complete) delete-withdraw the stake-account, send SOL to reserve-account
-    pub fn process(&mut self, stake_index: u32) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+    pub fn process(
+        &mut self,
+        stake_index: u32,
+        treasury_destinations: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
 
         let total_virtual_staked_lamports = self.state.total_virtual_staked_lamports();
         let msol_supply = self.state.msol_supply;
@@ -430,7 +706,7 @@ impl<'info> UpdateDeactivated<'info> {
         let BeginOutput {
             stake,
             is_treasury_msol_ready_for_transfer,
-        } = self.begin(stake_index)?;
+        } = self.begin(stake_index, treasury_destinations)?;
 
         let delegation = self.stake_account.delegation().ok_or_else(|| {
             error!(MarinadeError::RequiredDelegatedStake).with_account_name("stake_account")
@@ -464,7 +740,7 @@ impl<'info> UpdateDeactivated<'info> {
             let rewards = stake_balance_without_rent - stake.last_update_delegated_lamports;
             msg!("Staking rewards: {}", rewards);
             if is_treasury_msol_ready_for_transfer {
-                Some(self.mint_protocol_fees(rewards)?)
+                Some(self.mint_protocol_fees(rewards, treasury_destinations)?)
             } else {
                 None
             }
@@ -472,6 +748,17 @@ impl<'info> UpdateDeactivated<'info> {
             // less than observed last time
             let slashed = stake.last_update_delegated_lamports - stake_balance_without_rent;
             msg!("Slashed {}", slashed);
+            // the record is removed below, only the alert remains
+            emit!(StakeFlaggedEvent {
+                state: self.state.key(),
+                epoch: self.clock.epoch,
+                stake_index,
+                stake_account: stake.stake_account,
+                validator_vote: delegation.voter_pubkey,
+                last_update_delegated_lamports: stake.last_update_delegated_lamports,
+                observed_lamports: stake_balance_without_rent,
+                flag_recorded: false,
+            });
             if is_treasury_msol_ready_for_transfer {
                 Some(0)
             } else {
@@ -479,6 +766,15 @@ impl<'info> UpdateDeactivated<'info> {
             }
         };
 
+        if stake.last_update_epoch < self.clock.epoch {
+            if let Some(epoch_report) = &mut self.epoch_report {
+                epoch_report.on_stake_account_updated(
+                    stake_balance_without_rent.saturating_sub(stake.last_update_delegated_lamports),
+                    msol_fees.unwrap_or(0),
+                );
+            }
+        }
+
         // withdraw all to reserve (the stake account will be marked for deletion by the system)
         self.common
             .withdraw_to_reserve(self.stake_account.to_account_info().lamports())?;
@@ -500,6 +796,18 @@ impl<'info> UpdateDeactivated<'info> {
         )?;
         self.state.on_transfer_from_reserve(rent);
 
+        if let Some(crank_tip_to) = &self.crank_tip_to {
+            let state_address = self.state.key();
+            pay_crank_tip(
+                &mut self.common.state,
+                state_address,
+                self.common.reserve_pda.to_account_info(),
+                self.system_program.to_account_info(),
+                crank_tip_to.to_account_info(),
+                stake_balance_without_rent,
+            )?;
+        }
+
         if stake.last_update_delegated_lamports != 0 {
             if stake.is_emergency_unstaking == 0 {
                 // remove from delayed_unstake_cooling_down (amount is now in the reserve, is no longer cooling-down)
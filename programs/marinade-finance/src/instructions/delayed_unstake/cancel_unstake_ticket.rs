@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+
+use crate::{
+    error::MarinadeError,
+    events::delayed_unstake::CancelUnstakeTicketEvent,
+    state::{delayed_unstake_ticket::TicketAccountData, ticket_registry::TicketRegistry},
+    State,
+};
+
+use super::claim::WAIT_EPOCHS;
+use crate::state::pause_flags::PauseFlags;
+
+#[derive(Accounts)]
+pub struct CancelUnstakeTicket<'info> {
+    #[account(
+        mut,
+        has_one = msol_mint
+    )]
+    pub state: Box<Account<'info, State>>,
+    #[account(mut)]
+    pub msol_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        close = beneficiary,
+        constraint = ticket_account.state_address == state.key() @ MarinadeError::InvalidDelayedUnstakeTicket
+    )]
+    pub ticket_account: Box<Account<'info, TicketAccountData>>,
+    #[account(
+        mut,
+        address = ticket_account.beneficiary @ MarinadeError::WrongBeneficiary
+    )]
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = state.msol_mint
+    )]
+    pub mint_to: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            State::MSOL_MINT_AUTHORITY_SEED
+        ],
+        bump = state.msol_mint_authority_bump_seed
+    )]
+    pub msol_mint_authority: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub token_program: Program<'info, Token>,
+
+    // TicketRegistry of the beneficiary, the ticket is removed from the list
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            TicketRegistry::SEED,
+            &ticket_account.beneficiary.to_bytes()
+        ],
+        bump
+    )]
+    pub ticket_registry: Option<Box<Account<'info, TicketRegistry>>>,
+}
+
+impl<'info> CancelUnstakeTicket<'info> {
+    // fn cancel_unstake_ticket() // voids a ticket that is not due yet, the delayed unstake fee is not returned
+    pub fn process(&mut self) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::DELAYED_UNSTAKE)?;
+
+        let lamports = self.ticket_account.lamports_amount;
+        require_neq!(lamports, 0, MarinadeError::ReusingDelayedUnstakeTicket);
+        require_gt!(
+            self.ticket_account.created_epoch + WAIT_EPOCHS,
+            self.clock.epoch,
+            MarinadeError::TicketAlreadyDue
+        );
+
+        // tickets without the record could re-mint more than was burned
+        let msol_burned = self.ticket_account.msol_burned();
+        require_neq!(msol_burned, 0, MarinadeError::TicketNotCancelable);
+
+        // the ticket is already out of the mSOL price, minting at it keeps the price unchanged.
+        // At most the mSOL burned for the ticket: cancelling after a price rise is no free option,
+        // the value above it stays with the mSOL holders
+        let total_virtual_staked_lamports = self.state.total_virtual_staked_lamports();
+        let msol_supply = self.state.msol_supply;
+        let msol_amount = self
+            .state
+            .calc_msol_from_lamports(lamports)?
+            .min(msol_burned);
+
+        self.state.circulating_ticket_balance -= lamports;
+        self.state.circulating_ticket_count -= 1;
+        self.ticket_account.take_lamports(lamports)?;
+        if let Some(ticket_registry) = &mut self.ticket_registry {
+            ticket_registry.remove(&self.ticket_account.key());
+        }
+
+        mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: self.msol_mint.to_account_info(),
+                    to: self.mint_to.to_account_info(),
+                    authority: self.msol_mint_authority.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    State::MSOL_MINT_AUTHORITY_SEED,
+                    &[self.state.msol_mint_authority_bump_seed],
+                ]],
+            ),
+            msol_amount,
+        )?;
+        self.state.on_msol_mint(msol_amount);
+
+        emit!(CancelUnstakeTicketEvent {
+            state: self.state.key(),
+            ticket: self.ticket_account.key(),
+            beneficiary: self.beneficiary.key(),
+            ticket_epoch: self.ticket_account.created_epoch,
+            lamports_amount: lamports,
+            minted_msol: msol_amount,
+            circulating_ticket_balance: self.state.circulating_ticket_balance,
+            circulating_ticket_count: self.state.circulating_ticket_count,
+            total_virtual_staked_lamports,
+            msol_supply,
+        });
+
+        Ok(())
+    }
+}
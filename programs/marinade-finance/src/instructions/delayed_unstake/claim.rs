@@ -1,13 +1,16 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
 
+use crate::checks::{check_token_mint, check_token_source_account};
 use crate::events::delayed_unstake::ClaimEvent;
-use crate::state::delayed_unstake_ticket::TicketAccountData;
+use crate::state::pause_flags::PauseFlags;
+use crate::state::{delayed_unstake_ticket::TicketAccountData, ticket_registry::TicketRegistry};
 use crate::MarinadeError;
 use crate::State;
 
 ///How many epochs to wats for ticket. e.g.: Ticket created on epoch 14, ticket is due on epoch 15
-const WAIT_EPOCHS: u64 = 1;
+pub(crate) const WAIT_EPOCHS: u64 = 1;
 ///Wait 30 extra minutes from epochs start so the bot has time to withdraw SOL from inactive stake-accounts
 const EXTRA_WAIT_SECONDS: i64 = 30 * 60;
 
@@ -25,28 +28,52 @@ pub struct Claim<'info> {
     )]
     pub reserve_pda: SystemAccount<'info>,
 
-    #[account(
-        mut,
-        close = transfer_sol_to,
-        // at the end of this instruction, all lamports from ticket_account go to transfer_sol_to
-    )]
+    // closed to transfer_sol_to once fully claimed
+    #[account(mut)]
     pub ticket_account: Account<'info, TicketAccountData>,
 
+    // ticket_account.beneficiary, or any account for a tokenized ticket or when beneficiary signs
+    #[account(mut)]
+    pub transfer_sol_to: SystemAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    pub system_program: Program<'info, System>,
+
+    // tokenized ticket only: the ticket token is burned from ticket_token_account
     #[account(
         mut,
         address = ticket_account.beneficiary @ MarinadeError::WrongBeneficiary
     )]
-    pub transfer_sol_to: SystemAccount<'info>,
+    pub ticket_mint: Option<Box<Account<'info, Mint>>>,
+    #[account(mut)]
+    pub ticket_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    pub ticket_token_authority: Option<Signer<'info>>,
+    pub token_program: Option<Program<'info, Token>>,
 
-    pub clock: Sysvar<'info, Clock>,
+    // TicketRegistry of the beneficiary, the ticket is removed from the list once fully claimed.
+    // Left out, the entry stays until prune_ticket_registry
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            TicketRegistry::SEED,
+            &ticket_account.beneficiary.to_bytes()
+        ],
+        bump
+    )]
+    pub ticket_registry: Option<Box<Account<'info, TicketRegistry>>>,
 
-    pub system_program: Program<'info, System>,
+    // signing beneficiary, lets transfer_sol_to be any account
+    #[account(address = ticket_account.beneficiary @ MarinadeError::WrongBeneficiary)]
+    pub beneficiary: Option<Signer<'info>>,
 }
 
 /// Claim instruction: a user claims a Ticket-account
 /// This is done once tickets are due, meaning enough time has passed for the
 /// bot to complete the unstake process and transfer the requested SOL to reserve_pda.
-/// Checks that transfer request amount is less than total requested for unstake
+/// Checks that transfer request amount is less than total requested for unstake.
+/// A partial claim leaves the rest in the ticket, claimable later
 impl<'info> Claim<'info> {
     //
     fn check_ticket_account(&self) -> Result<()> {
@@ -85,9 +112,57 @@ impl<'info> Claim<'info> {
         Ok(())
     }
 
+    fn burn_ticket_token(&self) -> Result<()> {
+        let (
+            Some(ticket_mint),
+            Some(ticket_token_account),
+            Some(ticket_token_authority),
+            Some(token_program),
+        ) = (
+            &self.ticket_mint,
+            &self.ticket_token_account,
+            &self.ticket_token_authority,
+            &self.token_program,
+        )
+        else {
+            return err!(MarinadeError::TicketTokenAccountsMissing);
+        };
+        check_token_mint(
+            ticket_token_account,
+            &ticket_mint.key(),
+            "ticket_token_account",
+        )?;
+        check_token_source_account(ticket_token_account, ticket_token_authority.key, 1)
+            .map_err(|e| e.with_account_name("ticket_token_account"))?;
+        burn(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Burn {
+                    mint: ticket_mint.to_account_info(),
+                    from: ticket_token_account.to_account_info(),
+                    authority: ticket_token_authority.to_account_info(),
+                },
+            ),
+            1,
+        )
+    }
+
     // fn claim()
-    pub fn process(&mut self) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+    // lamports: None = the whole ticket
+    pub fn process(&mut self, lamports: Option<u64>) -> Result<()> {
+        self.process_with_tip(lamports, None)?;
+        Ok(())
+    }
+
+    /// claim paying State::claim_tip of the claimed lamports to tip_to, when set,
+    /// at most the max_claim_tip the beneficiary opted into when ordering the ticket.
+    /// Returns the tip
+    pub fn process_with_tip(
+        &mut self,
+        lamports: Option<u64>,
+        tip_to: Option<&AccountInfo<'info>>,
+    ) -> Result<u64> {
+        self.state.check_not_paused(PauseFlags::DELAYED_UNSTAKE)?;
 
         self.check_ticket_account()
             .map_err(|e| e.with_account_name("ticket_account"))?;
@@ -95,7 +170,24 @@ impl<'info> Claim<'info> {
         // record for event, use real balance not virtual field
         let user_balance = self.transfer_sol_to.lamports();
         let reserve_balance = self.reserve_pda.lamports();
-        let lamports = self.ticket_account.lamports_amount;
+        let ticket_balance = self.ticket_account.lamports_amount;
+        let lamports = lamports.unwrap_or(ticket_balance);
+        require!(
+            lamports > 0 && lamports <= ticket_balance,
+            MarinadeError::InvalidClaimAmount
+        );
+        let ticket_balance_left = ticket_balance - lamports;
+        if self.ticket_mint.is_some() {
+            // the ticket token is burned, so a tokenized ticket is claimed whole
+            require_eq!(ticket_balance_left, 0, MarinadeError::InvalidClaimAmount);
+            self.burn_ticket_token()?;
+        } else if self.beneficiary.is_none() {
+            require_keys_eq!(
+                self.transfer_sol_to.key(),
+                self.ticket_account.beneficiary,
+                MarinadeError::WrongBeneficiary
+            );
+        }
 
         // use real balance not virtual field
         let available_for_claim = reserve_balance - self.state.rent_exempt_for_token_acc;
@@ -109,14 +201,36 @@ impl<'info> Claim<'info> {
             return err!(MarinadeError::TicketNotReady);
         }
 
+        let tip = if tip_to.is_some() {
+            self.state
+                .claim_tip
+                .min(self.ticket_account.max_claim_tip())
+                .apply(lamports)
+        } else {
+            0
+        };
+
         // record for event and then update
         let circulating_ticket_balance = self.state.circulating_ticket_balance;
         let circulating_ticket_count = self.state.circulating_ticket_count;
         // If circulating_ticket_balance = sum(ticket.balance) is violated we can have a problem
         self.state.circulating_ticket_balance -= lamports;
-        self.state.circulating_ticket_count -= 1;
-        // disable ticket-account
-        self.ticket_account.lamports_amount = 0;
+        self.ticket_account.take_lamports(lamports)?;
+        if ticket_balance_left == 0 {
+            self.state.circulating_ticket_count -= 1;
+            if let Some(ticket_registry) = &mut self.ticket_registry {
+                ticket_registry.remove(&self.ticket_account.key());
+            }
+            // all lamports from ticket_account (rent) go to transfer_sol_to
+            self.ticket_account
+                .close(self.transfer_sol_to.to_account_info())?;
+        }
+
+        let reserve_seeds: &[&[u8]] = &[
+            &self.state.key().to_bytes(),
+            State::RESERVE_SEED,
+            &[self.state.reserve_bump_seed],
+        ];
 
         // transfer sol from reserve_pda to user
         transfer(
@@ -126,14 +240,25 @@ impl<'info> Claim<'info> {
                     from: self.reserve_pda.to_account_info(),
                     to: self.transfer_sol_to.to_account_info(),
                 },
-                &[&[
-                    &self.state.key().to_bytes(),
-                    State::RESERVE_SEED,
-                    &[self.state.reserve_bump_seed],
-                ]],
+                &[reserve_seeds],
             ),
-            lamports,
+            lamports - tip,
         )?;
+        if let Some(tip_to) = tip_to {
+            if tip > 0 {
+                transfer(
+                    CpiContext::new_with_signer(
+                        self.system_program.to_account_info(),
+                        Transfer {
+                            from: self.reserve_pda.to_account_info(),
+                            to: tip_to.clone(),
+                        },
+                        &[reserve_seeds],
+                    ),
+                    tip,
+                )?;
+            }
+        }
         self.state.on_transfer_from_reserve(lamports);
 
         emit!(ClaimEvent {
@@ -146,8 +271,10 @@ impl<'info> Claim<'info> {
             reserve_balance,
             user_balance,
             amount: lamports,
+            ticket_balance_left,
+            transfer_sol_to: self.transfer_sol_to.key(),
         });
 
-        Ok(())
+        Ok(tip)
     }
 }
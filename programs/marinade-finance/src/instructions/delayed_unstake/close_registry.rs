@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::delayed_unstake::CloseRegistryEvent, state::ticket_registry::TicketRegistry, State,
+};
+
+#[derive(Accounts)]
+pub struct CloseRegistry<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    #[account(
+        mut,
+        close = beneficiary,
+        seeds = [
+            &state.key().to_bytes(),
+            TicketRegistry::SEED,
+            &beneficiary.key().to_bytes()
+        ],
+        bump
+    )]
+    pub ticket_registry: Box<Account<'info, TicketRegistry>>,
+}
+
+impl<'info> CloseRegistry<'info> {
+    // fn close_registry() // rent back to the beneficiary, the listed tickets are not affected
+    pub fn process(&mut self) -> Result<()> {
+        emit!(CloseRegistryEvent {
+            state: self.state.key(),
+            beneficiary: self.beneficiary.key(),
+            ticket_registry: self.ticket_registry.key(),
+            tickets_listed: self.ticket_registry.tickets.len() as u32,
+        });
+        Ok(())
+    }
+}
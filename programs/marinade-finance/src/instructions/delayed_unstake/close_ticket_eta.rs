@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError, events::delayed_unstake::CloseTicketEtaEvent,
+    state::ticket_eta::TicketEta, ID,
+};
+
+#[derive(Accounts)]
+pub struct CloseTicketEta<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            &ticket_eta.ticket.to_bytes(),
+            TicketEta::SEED
+        ],
+        bump
+    )]
+    pub ticket_eta: Box<Account<'info, TicketEta>>,
+    /// CHECK: closed by claim, cancel_unstake_ticket or gc_ticket
+    #[account(address = ticket_eta.ticket)]
+    pub ticket_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = ticket_eta.rent_payer @ MarinadeError::WrongRentPayer
+    )]
+    pub rent_payer: SystemAccount<'info>,
+}
+
+impl<'info> CloseTicketEta<'info> {
+    /// permissionless
+    /// pub fn close_ticket_eta()
+    pub fn process(&mut self) -> Result<()> {
+        require!(
+            self.ticket_account.owner != &ID || self.ticket_account.data_is_empty(),
+            MarinadeError::TicketNotClosed
+        );
+
+        emit!(CloseTicketEtaEvent {
+            ticket_eta: self.ticket_eta.key(),
+            ticket: self.ticket_account.key(),
+            rent_payer: self.rent_payer.key(),
+            rent: self.ticket_eta.to_account_info().lamports(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    events::delayed_unstake::GcTicketEvent,
+    state::{delayed_unstake_ticket::TicketAccountData, ticket_registry::TicketRegistry},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct GcTicket<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        constraint = ticket_account.state_address == state.key() @ MarinadeError::InvalidDelayedUnstakeTicket,
+        constraint = ticket_account.lamports_amount == 0 @ MarinadeError::TicketNotEmpty
+    )]
+    pub ticket_account: Box<Account<'info, TicketAccountData>>,
+    // recorded in the ticket, the beneficiary for the tickets without the record
+    #[account(
+        mut,
+        address = ticket_account.rent_payer() @ MarinadeError::WrongRentPayer
+    )]
+    pub rent_payer: SystemAccount<'info>,
+
+    // TicketRegistry of the beneficiary, the ticket is removed from the list
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            TicketRegistry::SEED,
+            &ticket_account.beneficiary.to_bytes()
+        ],
+        bump
+    )]
+    pub ticket_registry: Option<Box<Account<'info, TicketRegistry>>>,
+}
+
+impl<'info> GcTicket<'info> {
+    /// permissionless
+    /// Closes a ticket worth 0 lamports. Claimed and cancelled tickets are closed already,
+    /// these are left by order_unstake while min_withdraw is 0
+    /// pub fn gc_ticket()
+    pub fn process(&mut self) -> Result<()> {
+        // booked by order_unstake, not claimed
+        self.state.circulating_ticket_count -= 1;
+        if let Some(ticket_registry) = &mut self.ticket_registry {
+            ticket_registry.remove(&self.ticket_account.key());
+        }
+
+        emit!(GcTicketEvent {
+            state: self.state.key(),
+            ticket: self.ticket_account.key(),
+            beneficiary: self.ticket_account.beneficiary,
+            rent_payer: self.rent_payer.key(),
+            rent: self.ticket_account.to_account_info().lamports(),
+            circulating_ticket_count: self.state.circulating_ticket_count,
+        });
+        Ok(())
+    }
+}
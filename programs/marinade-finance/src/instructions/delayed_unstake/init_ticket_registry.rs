@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::delayed_unstake::InitTicketRegistryEvent, state::ticket_registry::TicketRegistry, State,
+};
+
+#[derive(Accounts)]
+pub struct InitTicketRegistry<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    /// CHECK: any beneficiary, the registry is only an index
+    pub beneficiary: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = rent_payer,
+        space = TicketRegistry::SPACE,
+        seeds = [
+            &state.key().to_bytes(),
+            TicketRegistry::SEED,
+            &beneficiary.key().to_bytes()
+        ],
+        bump
+    )]
+    pub ticket_registry: Box<Account<'info, TicketRegistry>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitTicketRegistry<'info> {
+    // fn init_ticket_registry()
+    pub fn process(&mut self) -> Result<()> {
+        self.ticket_registry.set_inner(TicketRegistry {
+            state_address: self.state.key(),
+            beneficiary: self.beneficiary.key(),
+            tickets: vec![],
+        });
+
+        emit!(InitTicketRegistryEvent {
+            state: self.state.key(),
+            beneficiary: self.beneficiary.key(),
+            ticket_registry: self.ticket_registry.key(),
+        });
+        Ok(())
+    }
+}
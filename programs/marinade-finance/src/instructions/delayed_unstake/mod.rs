@@ -1,5 +1,23 @@
+pub mod cancel_unstake_ticket;
 pub mod claim;
+pub mod close_registry;
+pub mod close_ticket_eta;
+pub mod gc_ticket;
+pub mod init_ticket_registry;
 pub mod order_unstake;
+pub mod order_unstake_batch;
+pub mod prune_ticket_registry;
+pub mod split_ticket;
+pub mod transfer_ticket;
 
+pub use cancel_unstake_ticket::*;
 pub use claim::*;
+pub use close_registry::*;
+pub use close_ticket_eta::*;
+pub use gc_ticket::*;
+pub use init_ticket_registry::*;
 pub use order_unstake::*;
+pub use order_unstake_batch::*;
+pub use prune_ticket_registry::*;
+pub use split_ticket::*;
+pub use transfer_ticket::*;
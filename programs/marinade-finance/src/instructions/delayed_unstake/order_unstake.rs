@@ -1,9 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
+use anchor_spl::token::{burn, mint_to, Burn, Mint, MintTo, Token, TokenAccount};
 
+use super::claim::WAIT_EPOCHS;
+use crate::state::pause_flags::PauseFlags;
+use crate::state::Fee;
 use crate::{
-    checks::check_token_source_account, error::MarinadeError,
-    events::delayed_unstake::OrderUnstakeEvent, state::delayed_unstake_ticket::TicketAccountData,
+    checks::{
+        check_mint_authority, check_mint_empty, check_token_mint, check_token_source_account,
+    },
+    error::MarinadeError,
+    events::delayed_unstake::{OrderUnstakeEvent, TicketTokenizedEvent},
+    state::{
+        delayed_unstake_ticket::{MaybeTicketExtension, TicketAccountData, TicketExtension},
+        ticket_eta::TicketEta,
+        ticket_registry::TicketRegistry,
+    },
     State,
 };
 
@@ -35,12 +46,71 @@ pub struct OrderUnstake<'info> {
     pub clock: Sysvar<'info, Clock>,
     pub rent: Sysvar<'info, Rent>,
     pub token_program: Program<'info, Token>,
+
+    // opt-in tokenized ticket: a fresh 0 decimals mint created by the user with ticket_mint_authority as mint authority.
+    // One token is minted to ticket_token_account and the ticket is claimable only by burning it
+    #[account(mut)]
+    pub ticket_mint: Option<Box<Account<'info, Mint>>>,
+    #[account(mut)]
+    pub ticket_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            TicketAccountData::MINT_AUTHORITY_SEED
+        ],
+        bump
+    )]
+    pub ticket_mint_authority: Option<UncheckedAccount<'info>>,
+
+    // the new ticket is listed when passed, unless tokenized
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            TicketRegistry::SEED,
+            &burn_msol_from.owner.to_bytes()
+        ],
+        bump
+    )]
+    pub ticket_registry: Option<Box<Account<'info, TicketRegistry>>>,
+
+    // the ticket queue position and ETA are recorded when passed
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<TicketEta>(),
+        seeds = [
+            &new_ticket_account.key().to_bytes(),
+            TicketEta::SEED
+        ],
+        bump
+    )]
+    pub ticket_eta: Option<Box<Account<'info, TicketEta>>>,
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            State::RESERVE_SEED
+        ],
+        bump = state.reserve_bump_seed
+    )]
+    pub reserve_pda: Option<SystemAccount<'info>>,
+    #[account(mut)]
+    pub rent_payer: Option<Signer<'info>>,
+    pub system_program: Option<Program<'info, System>>,
 }
 
 impl<'info> OrderUnstake<'info> {
     // fn order_unstake() // create delayed-unstake Ticket-account
     pub fn process(&mut self, msol_amount: u64) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.process_with_claim_tip(msol_amount, Fee::default())
+    }
+
+    /// max_claim_tip: share of the ticket the beneficiary lets crank_claim pay to its caller,
+    /// at most State::claim_tip. Needs a ticket account of TicketAccountData::SPACE when not 0
+    pub fn process_with_claim_tip(&mut self, msol_amount: u64, max_claim_tip: Fee) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::DELAYED_UNSTAKE)?;
+        max_claim_tip.check()?;
 
         check_token_source_account(
             &self.burn_msol_from,
@@ -50,33 +120,27 @@ impl<'info> OrderUnstake<'info> {
         .map_err(|e| e.with_account_name("burn_msol_from"))?;
         let ticket_beneficiary = self.burn_msol_from.owner;
         let user_msol_balance = self.burn_msol_from.amount;
+        let queue_position = self.state.circulating_ticket_balance;
 
-        // save msol price source
-        let total_virtual_staked_lamports = self.state.total_virtual_staked_lamports();
-        let msol_supply = self.state.msol_supply;
-
-        let sol_value_of_msol_burned = self.state.msol_to_sol(msol_amount)?;
-        // apply delay_unstake_fee to avoid economical attacks
-        // delay_unstake_fee must be >= one epoch staking rewards
-        let delay_unstake_fee_lamports = self
-            .state
-            .delayed_unstake_fee
-            .apply(sol_value_of_msol_burned);
-        // the fee value will be burned but not delivered, thus increasing mSOL value slightly for all mSOL holders
-        let lamports_for_user = sol_value_of_msol_burned - delay_unstake_fee_lamports;
-
-        require_gte!(
-            lamports_for_user,
-            self.state.min_withdraw,
-            MarinadeError::WithdrawAmountIsTooLow
-        );
-
-        // record for event and then update
-        let circulating_ticket_balance = self.state.circulating_ticket_balance;
-        let circulating_ticket_count = self.state.circulating_ticket_count;
-        // circulating_ticket_balance +
-        self.state.circulating_ticket_balance += lamports_for_user;
-        self.state.circulating_ticket_count += 1;
+        book_ticket(
+            &mut self.state,
+            &mut self.new_ticket_account,
+            ticket_beneficiary,
+            self.rent_payer
+                .as_ref()
+                .map_or(self.burn_msol_authority.key(), |rent_payer| {
+                    rent_payer.key()
+                }),
+            msol_amount,
+            user_msol_balance,
+            &self.clock,
+        )?;
+        if max_claim_tip.basis_points > 0 {
+            let Some(extension) = self.new_ticket_account.extension.as_mut() else {
+                return err!(MarinadeError::TicketAccountTooSmall);
+            };
+            extension.max_claim_tip = max_claim_tip;
+        }
 
         // burn mSOL
         burn(
@@ -92,34 +156,164 @@ impl<'info> OrderUnstake<'info> {
         )?;
         self.state.on_msol_burn(msol_amount);
 
-        // initialize new_ticket_account
-        let created_epoch = self.clock.epoch
-            + if self.clock.epoch == self.state.stake_system.last_stake_delta_epoch {
-                1
-            } else {
-                0
-            };
-        self.new_ticket_account.set_inner(TicketAccountData {
-            state_address: self.state.key(),
-            beneficiary: ticket_beneficiary,
-            lamports_amount: lamports_for_user,
-            created_epoch,
+        if self.ticket_eta.is_some() {
+            self.record_eta(queue_position)?;
+        }
+        if self.ticket_mint.is_some() {
+            // claimed by the token holder, not listed under burn_msol_from.owner
+            self.tokenize_ticket()?;
+        } else if let Some(ticket_registry) = &mut self.ticket_registry {
+            ticket_registry.add(self.new_ticket_account.key())?;
+        }
+
+        Ok(())
+    }
+
+    fn record_eta(&mut self, queue_position: u64) -> Result<()> {
+        let (Some(ticket_eta), Some(reserve_pda), Some(rent_payer)) =
+            (&mut self.ticket_eta, &self.reserve_pda, &self.rent_payer)
+        else {
+            return err!(MarinadeError::TicketEtaAccountsMissing);
+        };
+        // the ticket is already booked, a non negative stake delta means the reserve covers it
+        let covered_by_reserve = self.state.stake_delta(reserve_pda.lamports()) >= 0;
+        // otherwise stake is deactivated at the stake delta of created_epoch and cools down
+        // by the next epoch, so both cases resolve to the claim wait
+        let eta_epoch = self.new_ticket_account.created_epoch + WAIT_EPOCHS;
+        ticket_eta.set_inner(TicketEta {
+            ticket: self.new_ticket_account.key(),
+            queue_position,
+            covered_by_reserve,
+            eta_epoch,
+            rent_payer: rent_payer.key(),
         });
-        emit!(OrderUnstakeEvent {
+        Ok(())
+    }
+
+    fn tokenize_ticket(&mut self) -> Result<()> {
+        let (Some(ticket_mint), Some(ticket_token_account), Some(ticket_mint_authority)) = (
+            &self.ticket_mint,
+            &self.ticket_token_account,
+            &self.ticket_mint_authority,
+        ) else {
+            return err!(MarinadeError::TicketTokenAccountsMissing);
+        };
+        check_mint_empty(ticket_mint, "ticket_mint")?;
+        require_eq!(
+            ticket_mint.decimals,
+            0,
+            MarinadeError::InvalidDelayedUnstakeTicket
+        );
+        check_mint_authority(ticket_mint, ticket_mint_authority.key, "ticket_mint")?;
+        check_token_mint(
+            ticket_token_account,
+            &ticket_mint.key(),
+            "ticket_token_account",
+        )?;
+
+        let (_, ticket_mint_authority_bump) =
+            TicketAccountData::find_mint_authority(&self.state.key());
+        mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: ticket_mint.to_account_info(),
+                    to: ticket_token_account.to_account_info(),
+                    authority: ticket_mint_authority.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    TicketAccountData::MINT_AUTHORITY_SEED,
+                    &[ticket_mint_authority_bump],
+                ]],
+            ),
+            1,
+        )?;
+        // only the token holder can claim from now
+        self.new_ticket_account.beneficiary = ticket_mint.key();
+
+        emit!(TicketTokenizedEvent {
             state: self.state.key(),
-            ticket_epoch: created_epoch,
             ticket: self.new_ticket_account.key(),
-            beneficiary: ticket_beneficiary,
-            user_msol_balance,
-            circulating_ticket_count,
-            circulating_ticket_balance,
-            burned_msol_amount: msol_amount,
-            sol_amount: lamports_for_user,
-            fee_bp_cents: self.state.delayed_unstake_fee.bp_cents,
-            total_virtual_staked_lamports,
-            msol_supply,
+            ticket_mint: ticket_mint.key(),
+            ticket_token_account: ticket_token_account.key(),
+            lamports_amount: self.new_ticket_account.lamports_amount,
         });
-
         Ok(())
     }
 }
+
+/// Fills a new delayed-unstake ticket for msol_amount and books it in State.
+/// The caller burns the mSOL (and calls on_msol_burn) right after.
+/// rent_payer funded the ticket account, the signer of the caller when not passed explicitly.
+/// Returns the lamports the ticket is worth
+pub fn book_ticket<'info>(
+    state: &mut Account<'info, State>,
+    ticket: &mut Account<'info, TicketAccountData>,
+    ticket_beneficiary: Pubkey,
+    rent_payer: Pubkey,
+    msol_amount: u64,
+    user_msol_balance: u64,
+    clock: &Clock,
+) -> Result<u64> {
+    // save msol price source
+    let total_virtual_staked_lamports = state.total_virtual_staked_lamports();
+    let msol_supply = state.msol_supply;
+
+    let sol_value_of_msol_burned = state.msol_to_sol(msol_amount)?;
+    // apply delay_unstake_fee to avoid economical attacks
+    // delay_unstake_fee must be >= one epoch staking rewards
+    let delay_unstake_fee_lamports = state.delayed_unstake_fee.apply(sol_value_of_msol_burned);
+    // the fee value will be burned but not delivered, thus increasing mSOL value slightly for all mSOL holders
+    let lamports_for_user = sol_value_of_msol_burned - delay_unstake_fee_lamports;
+
+    require_gte!(
+        lamports_for_user,
+        state.min_withdraw,
+        MarinadeError::WithdrawAmountIsTooLow
+    );
+
+    // record for event and then update
+    let circulating_ticket_balance = state.circulating_ticket_balance;
+    let circulating_ticket_count = state.circulating_ticket_count;
+    // circulating_ticket_balance +
+    state.circulating_ticket_balance += lamports_for_user;
+    state.circulating_ticket_count += 1;
+
+    // initialize new_ticket_account
+    let created_epoch = clock.epoch
+        + if clock.epoch == state.stake_system.last_stake_delta_epoch {
+            1
+        } else {
+            0
+        };
+    // recorded when the account has room for the extension
+    let extension = ticket.extension.map(|_| TicketExtension {
+        msol_burned: msol_amount,
+        max_claim_tip: Fee::default(),
+        rent_payer,
+    });
+    ticket.set_inner(TicketAccountData {
+        state_address: state.key(),
+        beneficiary: ticket_beneficiary,
+        lamports_amount: lamports_for_user,
+        created_epoch,
+        extension: MaybeTicketExtension(extension),
+    });
+    emit!(OrderUnstakeEvent {
+        state: state.key(),
+        ticket_epoch: created_epoch,
+        ticket: ticket.key(),
+        beneficiary: ticket_beneficiary,
+        user_msol_balance,
+        circulating_ticket_count,
+        circulating_ticket_balance,
+        burned_msol_amount: msol_amount,
+        sol_amount: lamports_for_user,
+        fee_bp_cents: state.delayed_unstake_fee.bp_cents,
+        total_virtual_staked_lamports,
+        msol_supply,
+    });
+
+    Ok(lamports_for_user)
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
+
+use crate::{
+    checks::check_token_source_account, error::MarinadeError,
+    state::delayed_unstake_ticket::TicketAccountData, State,
+};
+
+use super::book_ticket;
+use crate::state::pause_flags::PauseFlags;
+
+// order_unstake into several tickets, the new ticket accounts are the remaining accounts,
+// one per amount, each created like order_unstake new_ticket_account
+#[derive(Accounts)]
+pub struct OrderUnstakeBatch<'info> {
+    #[account(
+        mut,
+        has_one = msol_mint
+    )]
+    pub state: Box<Account<'info, State>>,
+    #[account(mut)]
+    pub msol_mint: Box<Account<'info, Mint>>,
+
+    // Note: Ticket beneficiary is burn_msol_from.owner
+    #[account(
+        mut,
+        token::mint = state.msol_mint
+    )]
+    pub burn_msol_from: Box<Account<'info, TokenAccount>>,
+    pub burn_msol_authority: Signer<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> OrderUnstakeBatch<'info> {
+    pub const MAX_TICKETS: usize = 8;
+
+    // same checks as the anchor `zero, rent_exempt = enforce` constraints of order_unstake
+    fn new_ticket_account(
+        &self,
+        info: &AccountInfo<'info>,
+    ) -> Result<Account<'info, TicketAccountData>> {
+        require!(info.is_writable, ErrorCode::ConstraintMut);
+        require!(
+            self.rent.is_exempt(info.lamports(), info.data_len()),
+            ErrorCode::ConstraintRentExempt
+        );
+        let ticket = Account::<TicketAccountData>::try_from_unchecked(info)?;
+        let discriminator = &info.try_borrow_data()?[..8];
+        require!(
+            discriminator.iter().all(|b| *b == 0),
+            ErrorCode::ConstraintZero
+        );
+        Ok(ticket)
+    }
+
+    // fn order_unstake_batch()
+    pub fn process(
+        &mut self,
+        msol_amounts: Vec<u64>,
+        new_ticket_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::DELAYED_UNSTAKE)?;
+        require!(
+            !msol_amounts.is_empty()
+                && msol_amounts.len() <= Self::MAX_TICKETS
+                && msol_amounts.len() == new_ticket_accounts.len(),
+            MarinadeError::InvalidTicketBatch
+        );
+
+        let msol_amount = msol_amounts.iter().sum();
+        check_token_source_account(
+            &self.burn_msol_from,
+            self.burn_msol_authority.key,
+            msol_amount,
+        )
+        .map_err(|e| e.with_account_name("burn_msol_from"))?;
+        let ticket_beneficiary = self.burn_msol_from.owner;
+        let user_msol_balance = self.burn_msol_from.amount;
+
+        for (ticket_msol_amount, info) in msol_amounts.iter().zip(new_ticket_accounts) {
+            let mut ticket = self.new_ticket_account(info)?;
+            book_ticket(
+                &mut self.state,
+                &mut ticket,
+                ticket_beneficiary,
+                self.burn_msol_authority.key(),
+                *ticket_msol_amount,
+                user_msol_balance,
+                &self.clock,
+            )?;
+            // written now, so a repeated account fails the zero check
+            ticket.exit(&crate::ID)?;
+        }
+
+        // one burn for all the tickets
+        burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.msol_mint.to_account_info(),
+                    from: self.burn_msol_from.to_account_info(),
+                    authority: self.burn_msol_authority.to_account_info(),
+                },
+            ),
+            msol_amount,
+        )?;
+        self.state.on_msol_burn(msol_amount);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::delayed_unstake::PruneTicketRegistryEvent, state::ticket_registry::TicketRegistry,
+    State,
+};
+
+#[derive(Accounts)]
+pub struct PruneTicketRegistry<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            TicketRegistry::SEED,
+            &ticket_registry.beneficiary.to_bytes()
+        ],
+        bump
+    )]
+    pub ticket_registry: Box<Account<'info, TicketRegistry>>,
+}
+
+impl<'info> PruneTicketRegistry<'info> {
+    /// permissionless
+    /// remaining_accounts: listed tickets, the closed, emptied or transferred ones are removed
+    /// pub fn prune_ticket_registry()
+    pub fn process(&mut self, tickets: &[AccountInfo<'info>]) -> Result<()> {
+        let pruned = self.ticket_registry.prune(tickets);
+
+        emit!(PruneTicketRegistryEvent {
+            state: self.state.key(),
+            beneficiary: self.ticket_registry.beneficiary,
+            ticket_registry: self.ticket_registry.key(),
+            pruned,
+            tickets_listed: self.ticket_registry.tickets.len() as u32,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    error::MarinadeError,
+    events::delayed_unstake::SplitTicketEvent,
+    state::{
+        delayed_unstake_ticket::{MaybeTicketExtension, TicketAccountData, TicketExtension},
+        ticket_registry::TicketRegistry,
+    },
+    State,
+};
+
+#[derive(Accounts)]
+pub struct SplitTicket<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        constraint = ticket_account.state_address == state.key() @ MarinadeError::InvalidDelayedUnstakeTicket
+    )]
+    pub ticket_account: Box<Account<'info, TicketAccountData>>,
+    #[account(address = ticket_account.beneficiary @ MarinadeError::WrongBeneficiary)]
+    pub beneficiary: Signer<'info>,
+
+    // created by the caller, who pays its rent
+    #[account(
+        zero,
+        rent_exempt = enforce
+    )]
+    pub new_ticket_account: Box<Account<'info, TicketAccountData>>,
+    pub new_ticket_beneficiary: SystemAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    // TicketRegistry of new_ticket_beneficiary, the new ticket is added to the list
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            TicketRegistry::SEED,
+            &new_ticket_beneficiary.key().to_bytes()
+        ],
+        bump
+    )]
+    pub new_ticket_registry: Option<Box<Account<'info, TicketRegistry>>>,
+}
+
+impl<'info> SplitTicket<'info> {
+    // fn split_ticket() // moves lamports of a ticket into a new ticket of the same epoch
+    pub fn process(&mut self, lamports: u64) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::DELAYED_UNSTAKE)?;
+
+        let ticket_balance = self.ticket_account.lamports_amount;
+        require_neq!(
+            ticket_balance,
+            0,
+            MarinadeError::ReusingDelayedUnstakeTicket
+        );
+        require_gt!(ticket_balance, lamports, MarinadeError::InvalidClaimAmount);
+        let ticket_balance_left = ticket_balance - lamports;
+        // no dust tickets
+        require_gte!(
+            lamports.min(ticket_balance_left),
+            self.state.min_withdraw,
+            MarinadeError::WithdrawAmountIsTooLow
+        );
+
+        let msol_burned = self.ticket_account.take_lamports(lamports)?;
+        let max_claim_tip = self.ticket_account.max_claim_tip();
+        let created_epoch = self.ticket_account.created_epoch;
+        // msol_burned and the claim tip opt-in are kept when the new account has room for them
+        let extension = self.new_ticket_account.extension.map(|_| TicketExtension {
+            msol_burned,
+            max_claim_tip,
+            rent_payer: self.beneficiary.key(),
+        });
+        self.new_ticket_account.set_inner(TicketAccountData {
+            state_address: self.state.key(),
+            beneficiary: self.new_ticket_beneficiary.key(),
+            lamports_amount: lamports,
+            created_epoch,
+            extension: MaybeTicketExtension(extension),
+        });
+        // circulating_ticket_balance is unchanged
+        self.state.circulating_ticket_count += 1;
+        if let Some(new_ticket_registry) = &mut self.new_ticket_registry {
+            new_ticket_registry.add(self.new_ticket_account.key())?;
+        }
+
+        emit!(SplitTicketEvent {
+            state: self.state.key(),
+            ticket: self.ticket_account.key(),
+            new_ticket: self.new_ticket_account.key(),
+            beneficiary: self.ticket_account.beneficiary,
+            new_ticket_beneficiary: self.new_ticket_beneficiary.key(),
+            ticket_epoch: created_epoch,
+            ticket_balance_left,
+            new_ticket_amount: lamports,
+            circulating_ticket_count: self.state.circulating_ticket_count,
+        });
+
+        Ok(())
+    }
+}
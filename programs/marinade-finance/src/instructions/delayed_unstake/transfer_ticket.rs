@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    error::MarinadeError,
+    events::delayed_unstake::TransferTicketEvent,
+    state::{delayed_unstake_ticket::TicketAccountData, ticket_registry::TicketRegistry},
+    State,
+};
+
+#[derive(Accounts)]
+#[instruction(new_beneficiary: Pubkey)]
+pub struct TransferTicket<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        constraint = ticket_account.state_address == state.key() @ MarinadeError::InvalidDelayedUnstakeTicket
+    )]
+    pub ticket_account: Box<Account<'info, TicketAccountData>>,
+    #[account(address = ticket_account.beneficiary @ MarinadeError::WrongBeneficiary)]
+    pub beneficiary: Signer<'info>,
+
+    // TicketRegistry of the beneficiary, the ticket is removed from the list
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            TicketRegistry::SEED,
+            &ticket_account.beneficiary.to_bytes()
+        ],
+        bump
+    )]
+    pub ticket_registry: Option<Box<Account<'info, TicketRegistry>>>,
+    // TicketRegistry of new_beneficiary, the ticket is added to the list
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            TicketRegistry::SEED,
+            &new_beneficiary.to_bytes()
+        ],
+        bump
+    )]
+    pub new_ticket_registry: Option<Box<Account<'info, TicketRegistry>>>,
+}
+
+impl<'info> TransferTicket<'info> {
+    // fn transfer_ticket() // the new beneficiary is the only one able to claim the ticket
+    pub fn process(&mut self, new_beneficiary: Pubkey) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::DELAYED_UNSTAKE)?;
+        require_neq!(
+            self.ticket_account.lamports_amount,
+            0,
+            MarinadeError::ReusingDelayedUnstakeTicket
+        );
+
+        let old_beneficiary = self.ticket_account.beneficiary;
+        self.ticket_account.beneficiary = new_beneficiary;
+        if let Some(ticket_registry) = &mut self.ticket_registry {
+            ticket_registry.remove(&self.ticket_account.key());
+        }
+        if let Some(new_ticket_registry) = &mut self.new_ticket_registry {
+            new_ticket_registry.add(self.ticket_account.key())?;
+        }
+
+        emit!(TransferTicketEvent {
+            state: self.state.key(),
+            ticket: self.ticket_account.key(),
+            old_beneficiary,
+            new_beneficiary,
+            lamports_amount: self.ticket_account.lamports_amount,
+            ticket_epoch: self.ticket_account.created_epoch,
+        });
+
+        Ok(())
+    }
+}
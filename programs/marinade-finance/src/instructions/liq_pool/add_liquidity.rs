@@ -1,7 +1,8 @@
-use crate::calc::shares_from_value;
+use crate::calc::{shares_from_value, value_from_shares};
 use crate::error::MarinadeError;
 use crate::events::liq_pool::AddLiquidityEvent;
-use crate::state::liq_pool::LiqPool;
+use crate::state::pause_flags::PauseFlags;
+use crate::state::{liq_pool::LiqPool, lp_cap_bypass::LpCapBypass};
 use crate::{require_lte, State};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
@@ -58,12 +59,24 @@ pub struct AddLiquidity<'info> {
 
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
+
+    // admin exemption of mint_to.owner from the deposit caps
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LpCapBypass::SEED,
+            &mint_to.owner.to_bytes()
+        ],
+        bump
+    )]
+    pub lp_cap_bypass: Option<Box<Account<'info, LpCapBypass>>>,
 }
 
 impl<'info> AddLiquidity<'info> {
     // fn add_liquidity()
     pub fn process(&mut self, lamports: u64) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::LIQUIDITY)?;
+        self.state.check_liq_pool_not_lent()?;
 
         require_gte!(
             lamports,
@@ -76,9 +89,12 @@ impl<'info> AddLiquidity<'info> {
             user_sol_balance,
             MarinadeError::NotEnoughUserFunds
         );
-        self.state
-            .liq_pool
-            .check_liquidity_cap(lamports, self.liq_pool_sol_leg_pda.lamports())?;
+        let capped = self.lp_cap_bypass.is_none();
+        if capped {
+            self.state
+                .liq_pool
+                .check_liquidity_cap(lamports, self.liq_pool_sol_leg_pda.lamports())?;
+        }
 
         // Update virtual lp_supply by real one
 
@@ -115,6 +131,14 @@ impl<'info> AddLiquidity<'info> {
 
         msg!("LP for user {}", shares_for_user);
 
+        let user_lp_balance = self.mint_to.amount;
+        // lp_account_cap bounds the LP balance of mint_to, not of all the accounts of its owner
+        if capped {
+            self.state.check_lp_account_cap(
+                value_from_shares(user_lp_balance, total_liq_pool_value, lp_supply)? + lamports,
+            )?;
+        }
+
         // we start with a transfer instruction so the user can verify the SOL amount they're staking while approving the transaction
         // transfer sol into liq-pool sol leg
         transfer(
@@ -129,7 +153,6 @@ impl<'info> AddLiquidity<'info> {
         )?;
 
         // mint liq-pool shares (mSOL-SOL-LP tokens) for the user
-        let user_lp_balance = self.mint_to.amount;
         mint_to(
             CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
@@ -0,0 +1,178 @@
+use crate::calc::{shares_from_value, value_from_shares};
+use crate::checks::check_token_source_account;
+use crate::error::MarinadeError;
+use crate::events::liq_pool::AddLiquidityMsolEvent;
+use crate::state::pause_flags::PauseFlags;
+use crate::state::{liq_pool::LiqPool, lp_cap_bypass::LpCapBypass};
+use crate::{require_lte, State};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{
+    mint_to, transfer as transfer_token, Mint, MintTo, Token, TokenAccount,
+    Transfer as TransferToken,
+};
+
+#[derive(Accounts)]
+pub struct AddLiquidityMsol<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        address = state.liq_pool.lp_mint
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::LP_MINT_AUTHORITY_SEED
+        ],
+        bump = state.liq_pool.lp_mint_authority_bump_seed
+    )]
+    pub lp_mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = state.liq_pool.msol_leg
+    )]
+    pub liq_pool_msol_leg: Box<Account<'info, TokenAccount>>,
+
+    // liq_pool_sol_leg_pda to be able to compute current liq_pool value
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::SOL_LEG_SEED
+        ],
+        bump = state.liq_pool.sol_leg_bump_seed
+    )]
+    pub liq_pool_sol_leg_pda: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = state.msol_mint
+    )]
+    pub transfer_from: Box<Account<'info, TokenAccount>>,
+    pub transfer_from_authority: Signer<'info>, // transfer_from owner or delegate_authority
+
+    // user SPL-Token account to send the newly minted LP tokens
+    #[account(
+        mut,
+        token::mint = state.liq_pool.lp_mint
+    )]
+    pub mint_to: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    // admin exemption of mint_to.owner from lp_account_cap
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LpCapBypass::SEED,
+            &mint_to.owner.to_bytes()
+        ],
+        bump
+    )]
+    pub lp_cap_bypass: Option<Box<Account<'info, LpCapBypass>>>,
+}
+
+impl<'info> AddLiquidityMsol<'info> {
+    // fn add_liquidity_msol() // add_liquidity paid in mSOL, valued at the current mSOL price
+    pub fn process(&mut self, msol_amount: u64) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::LIQUIDITY)?;
+        self.state.check_liq_pool_not_lent()?;
+
+        let msol_value = self.state.msol_to_sol(msol_amount)?;
+        require_gte!(
+            msol_value,
+            self.state.min_deposit,
+            MarinadeError::DepositAmountIsTooLow
+        );
+        check_token_source_account(
+            &self.transfer_from,
+            self.transfer_from_authority.key,
+            msol_amount,
+        )
+        .map_err(|e| e.with_account_name("transfer_from"))?;
+        let user_msol_balance = self.transfer_from.amount;
+        // no liquidity_sol_cap check: it caps the SOL leg only
+
+        // same as add_liquidity
+        require_lte!(
+            self.lp_mint.supply,
+            self.state.liq_pool.lp_supply,
+            MarinadeError::UnregisteredLPMinted
+        );
+        self.state.liq_pool.lp_supply = self.lp_mint.supply;
+        // save msol price source
+        let total_virtual_staked_lamports = self.state.total_virtual_staked_lamports();
+        let msol_supply = self.state.msol_supply;
+
+        // compute current liq-pool total value BEFORE adding user's deposit
+        let sol_leg_balance = self.liq_pool_sol_leg_pda.lamports();
+        let sol_leg_available_balance = sol_leg_balance - self.state.rent_exempt_for_token_acc;
+        let msol_leg_balance = self.liq_pool_msol_leg.amount;
+        let total_liq_pool_value = LiqPool::total_claimable_lamports(
+            sol_leg_available_balance,
+            self.state.msol_to_sol(msol_leg_balance)?,
+        )?;
+
+        let lp_supply = self.state.liq_pool.lp_supply;
+        let shares_for_user = shares_from_value(msol_value, total_liq_pool_value, lp_supply)?;
+        msg!("LP for user {}", shares_for_user);
+
+        let user_lp_balance = self.mint_to.amount;
+        if self.lp_cap_bypass.is_none() {
+            self.state.check_lp_account_cap(
+                value_from_shares(user_lp_balance, total_liq_pool_value, lp_supply)? + msol_value,
+            )?;
+        }
+
+        transfer_token(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferToken {
+                    from: self.transfer_from.to_account_info(),
+                    to: self.liq_pool_msol_leg.to_account_info(),
+                    authority: self.transfer_from_authority.to_account_info(),
+                },
+            ),
+            msol_amount,
+        )?;
+
+        mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: self.lp_mint.to_account_info(),
+                    to: self.mint_to.to_account_info(),
+                    authority: self.lp_mint_authority.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    LiqPool::LP_MINT_AUTHORITY_SEED,
+                    &[self.state.liq_pool.lp_mint_authority_bump_seed],
+                ]],
+            ),
+            shares_for_user,
+        )?;
+        self.state.liq_pool.on_lp_mint(shares_for_user);
+
+        emit!(AddLiquidityMsolEvent {
+            state: self.state.key(),
+            msol_owner: self.transfer_from.owner,
+            user_msol_balance,
+            user_lp_balance,
+            msol_leg_balance,
+            lp_supply,
+            msol_added_amount: msol_amount,
+            msol_added_value: msol_value,
+            lp_minted: shares_for_user,
+            // msol price components
+            total_virtual_staked_lamports,
+            msol_supply,
+        });
+
+        Ok(())
+    }
+}
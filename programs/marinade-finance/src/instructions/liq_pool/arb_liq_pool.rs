@@ -0,0 +1,133 @@
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    error::MarinadeError, events::liq_pool::ArbLiqPoolEvent, require_lte, state::liq_pool::LiqPool,
+    State,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{
+    transfer as transfer_token, Token, TokenAccount, Transfer as TransferToken,
+};
+
+#[derive(Accounts)]
+pub struct ArbLiqPool<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::SOL_LEG_SEED
+        ],
+        bump = state.liq_pool.sol_leg_bump_seed
+    )]
+    pub liq_pool_sol_leg_pda: SystemAccount<'info>,
+    #[account(
+        mut,
+        address = state.liq_pool.msol_leg
+    )]
+    pub liq_pool_msol_leg: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::MSOL_LEG_AUTHORITY_SEED
+        ],
+        bump = state.liq_pool.msol_leg_authority_bump_seed
+    )]
+    pub liq_pool_msol_leg_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub transfer_from: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = state.msol_mint
+    )]
+    pub mint_to: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ArbLiqPool<'info> {
+    /// permissionless
+    /// Buys mSOL from the liq-pool mSOL leg at the mSOL price, paying SOL into the SOL leg.
+    /// Only allowed while it lowers the liquid_unstake fee, i.e. while the SOL leg is below the liquidity target.
+    /// There is no bounty on top: the mSOL can leave for free (instant_withdraw, or add_liquidity_msol
+    /// then remove_liquidity), so any bounty would be collected risk-free at the LPs' expense
+    /// pub fn arb_liq_pool()
+    pub fn process(&mut self, lamports: u64, min_msol_out: u64) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::LIQUIDITY)?;
+        self.state.check_liq_pool_not_lent()?;
+        require_gte!(
+            lamports,
+            self.state.min_deposit,
+            MarinadeError::DepositAmountIsTooLow
+        );
+
+        let sol_leg_balance = self.liq_pool_sol_leg_pda.lamports();
+        let msol_leg_balance = self.liq_pool_msol_leg.amount;
+        let sol_leg_available =
+            sol_leg_balance.saturating_sub(self.state.rent_exempt_for_token_acc);
+        let fee_before = self.state.liquid_unstake_fee(sol_leg_available, 0);
+        let fee_after = self
+            .state
+            .liquid_unstake_fee(sol_leg_available.saturating_add(lamports), 0);
+        require_gt!(
+            fee_before.basis_points,
+            fee_after.basis_points,
+            MarinadeError::ArbDoesNotImprovePrice
+        );
+
+        let msol_out = self.state.calc_msol_from_lamports(lamports)?;
+        require_lte!(
+            msol_out,
+            msol_leg_balance,
+            MarinadeError::InsufficientLiquidity
+        );
+        require_gte!(msol_out, min_msol_out, MarinadeError::ArbBelowMinOut);
+
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.transfer_from.to_account_info(),
+                    to: self.liq_pool_sol_leg_pda.to_account_info(),
+                },
+            ),
+            lamports,
+        )?;
+
+        transfer_token(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferToken {
+                    from: self.liq_pool_msol_leg.to_account_info(),
+                    to: self.mint_to.to_account_info(),
+                    authority: self.liq_pool_msol_leg_authority.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    LiqPool::MSOL_LEG_AUTHORITY_SEED,
+                    &[self.state.liq_pool.msol_leg_authority_bump_seed],
+                ]],
+            ),
+            msol_out,
+        )?;
+
+        emit!(ArbLiqPoolEvent {
+            state: self.state.key(),
+            arbitrageur: self.transfer_from.key(),
+            sol_leg_balance,
+            msol_leg_balance,
+            lamports,
+            msol_out,
+            fee_before,
+            fee_after,
+            msol_price: self.state.msol_price,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    checks::{check_token_source_account, is_followed_by},
+    error::MarinadeError,
+    events::liq_pool::BeginFlashRemoveLiquidityEvent,
+    instruction::EndFlashRemoveLiquidity as EndFlashRemoveLiquidityInstruction,
+    state::liq_pool::LiqPool,
+    State,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::token::{
+    transfer as transfer_token, Mint, Token, TokenAccount, Transfer as TransferToken,
+};
+
+use super::RemoveLiquidityBreakdown;
+use crate::state::pause_flags::PauseFlags;
+
+#[derive(Accounts)]
+pub struct BeginFlashRemoveLiquidity<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(address = state.liq_pool.lp_mint)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    // LP position the flash removal is sized by. Tokens are not burned
+    #[account(token::mint = state.liq_pool.lp_mint)]
+    pub lp_position: Box<Account<'info, TokenAccount>>,
+    pub lp_position_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub transfer_sol_to: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = state.msol_mint
+    )]
+    pub transfer_msol_to: Box<Account<'info, TokenAccount>>,
+
+    // legs
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::SOL_LEG_SEED
+        ],
+        bump = state.liq_pool.sol_leg_bump_seed
+    )]
+    pub liq_pool_sol_leg_pda: SystemAccount<'info>,
+    #[account(
+        mut,
+        address = state.liq_pool.msol_leg
+    )]
+    pub liq_pool_msol_leg: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::MSOL_LEG_AUTHORITY_SEED
+        ],
+        bump = state.liq_pool.msol_leg_authority_bump_seed
+    )]
+    pub liq_pool_msol_leg_authority: UncheckedAccount<'info>,
+
+    /// CHECK: instructions sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// BeginFlashRemoveLiquidity instruction: lends the legs share of an LP position for the rest of the transaction.
+/// The transaction must contain a later end_flash_remove_liquidity for the same state,
+/// which fails unless both legs got back what was lent plus lp_min_fee
+impl<'info> BeginFlashRemoveLiquidity<'info> {
+    // fn begin_flash_remove_liquidity()
+    pub fn process(&mut self, tokens: u64) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::LIQUIDITY)?;
+        self.state.price_circuit_breaker.check_not_tripped()?;
+        self.state.flash_loan.check_not_in_progress()?;
+        self.state.liq_pool_drain.check_not_drained()?;
+
+        check_token_source_account(&self.lp_position, self.lp_position_authority.key, tokens)
+            .map_err(|e| e.with_account_name("lp_position"))?;
+        require!(
+            is_followed_by(
+                &self.instructions.to_account_info(),
+                &EndFlashRemoveLiquidityInstruction::DISCRIMINATOR,
+                &self.state.key(),
+            )?,
+            MarinadeError::FlashRemoveEndMissing
+        );
+
+        let sol_leg_balance = self.liq_pool_sol_leg_pda.lamports();
+        let msol_leg_balance = self.liq_pool_msol_leg.amount;
+
+        // same lp_supply self-heal as remove_liquidity but without writing it back
+        let lp_supply = self.lp_mint.supply.min(self.state.liq_pool.lp_supply);
+        let RemoveLiquidityBreakdown {
+            sol_out_amount,
+            msol_out_amount,
+            ..
+        } = RemoveLiquidityBreakdown::compute(
+            &self.state,
+            tokens,
+            lp_supply,
+            sol_leg_balance,
+            msol_leg_balance,
+        )?;
+
+        let fee = self.state.liq_pool.lp_min_fee;
+        self.state.flash_remove.begin(
+            sol_leg_balance,
+            msol_leg_balance,
+            sol_out_amount,
+            msol_out_amount,
+            fee,
+        )?;
+
+        if sol_out_amount > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.liq_pool_sol_leg_pda.to_account_info(),
+                        to: self.transfer_sol_to.to_account_info(),
+                    },
+                    &[&[
+                        &self.state.key().to_bytes(),
+                        LiqPool::SOL_LEG_SEED,
+                        &[self.state.liq_pool.sol_leg_bump_seed],
+                    ]],
+                ),
+                sol_out_amount,
+            )?;
+        }
+
+        if msol_out_amount > 0 {
+            transfer_token(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferToken {
+                        from: self.liq_pool_msol_leg.to_account_info(),
+                        to: self.transfer_msol_to.to_account_info(),
+                        authority: self.liq_pool_msol_leg_authority.to_account_info(),
+                    },
+                    &[&[
+                        &self.state.key().to_bytes(),
+                        LiqPool::MSOL_LEG_AUTHORITY_SEED,
+                        &[self.state.liq_pool.msol_leg_authority_bump_seed],
+                    ]],
+                ),
+                msol_out_amount,
+            )?;
+        }
+
+        emit!(BeginFlashRemoveLiquidityEvent {
+            state: self.state.key(),
+            sol_leg_balance,
+            msol_leg_balance,
+            lp_tokens: tokens,
+            sol_out_amount,
+            msol_out_amount,
+            sol_leg_target: self.state.flash_remove.sol_leg_target,
+            msol_leg_target: self.state.flash_remove.msol_leg_target,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{burn, Burn};
+
+use crate::{
+    events::liq_pool::BestEffortUnstakeEvent, instructions::delayed_unstake::book_ticket,
+    state::delayed_unstake_ticket::TicketAccountData, MarinadeError, State,
+};
+
+use super::liquid_unstake::*;
+use crate::state::pause_flags::PauseFlags;
+
+// unstake routed through the cheapest paths: idle reserve at the mSOL price (instant_withdraw),
+// then the liq-pool (liquid_unstake), then a delayed-unstake ticket for the residue
+#[derive(Accounts)]
+pub struct BestEffortUnstake<'info> {
+    pub liquid_unstake: LiquidUnstake<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            &liquid_unstake.state.key().to_bytes(),
+            State::RESERVE_SEED
+        ],
+        bump = liquid_unstake.state.reserve_bump_seed
+    )]
+    pub reserve_pda: SystemAccount<'info>,
+
+    // without it the residue stays in get_msol_from. Beneficiary is get_msol_from.owner
+    #[account(
+        zero,
+        rent_exempt = enforce
+    )]
+    pub new_ticket_account: Option<Box<Account<'info, TicketAccountData>>>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> BestEffortUnstake<'info> {
+    /// min_lamports_out bounds the SOL paid now plus the lamports of the ticket
+    // fn best_effort_unstake()
+    pub fn process(&mut self, msol_amount: u64, min_lamports_out: u64) -> Result<()> {
+        self.liquid_unstake
+            .state
+            .check_not_paused(PauseFlags::LIQUID_UNSTAKE)?;
+
+        // 1. idle reserve, no fee
+        let reserve_balance = self.reserve_pda.lamports();
+        let reserve_available = self
+            .liquid_unstake
+            .state
+            .instant_withdraw_available(reserve_balance, self.clock.epoch);
+        let reserve_msol = self
+            .liquid_unstake
+            .state
+            .calc_msol_from_lamports(reserve_available)?
+            .min(msol_amount);
+        let reserve_lamports = self.liquid_unstake.state.msol_to_sol(reserve_msol)?;
+        if reserve_lamports > 0 {
+            self.withdraw_from_reserve(reserve_msol, reserve_lamports)?;
+        }
+
+        // 2. liq-pool, at the liquid unstake fee. Its value is at most the available SOL leg
+        let mut msol_left = msol_amount - reserve_msol;
+        let sol_leg_available = self
+            .liquid_unstake
+            .liq_pool_sol_leg_pda
+            .lamports()
+            .saturating_sub(self.liquid_unstake.state.rent_exempt_for_token_acc);
+        let pool_msol = self
+            .liquid_unstake
+            .state
+            .calc_msol_from_lamports(sol_leg_available)?
+            .min(msol_left);
+        // same payout as liquid_unstake computes, to skip a leg it would reject as too low
+        let pool_fee = self.liquid_unstake.state.liquid_unstake_fee(
+            sol_leg_available,
+            self.liquid_unstake.state.msol_to_sol(pool_msol)?,
+        );
+        let pool_payout = self
+            .liquid_unstake
+            .state
+            .msol_to_sol(pool_msol - pool_fee.apply(pool_msol))?;
+        let pool_lamports =
+            if pool_msol > 0 && pool_payout >= self.liquid_unstake.state.min_withdraw {
+                let user_balance = self.liquid_unstake.transfer_sol_to.lamports();
+                self.liquid_unstake.process(pool_msol, 0)?;
+                msol_left -= pool_msol;
+                self.liquid_unstake.transfer_sol_to.lamports() - user_balance
+            } else {
+                0
+            };
+
+        // 3. delayed-unstake ticket
+        let ticket_lamports = match &mut self.new_ticket_account {
+            Some(new_ticket_account) if msol_left > 0 => {
+                let LiquidUnstake {
+                    state,
+                    msol_mint,
+                    get_msol_from,
+                    get_msol_from_authority,
+                    token_program,
+                    ..
+                } = &mut self.liquid_unstake;
+                get_msol_from.reload()?;
+                let ticket_lamports = book_ticket(
+                    state,
+                    new_ticket_account,
+                    get_msol_from.owner,
+                    get_msol_from_authority.key(),
+                    msol_left,
+                    get_msol_from.amount,
+                    &self.clock,
+                )?;
+                burn(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        Burn {
+                            mint: msol_mint.to_account_info(),
+                            from: get_msol_from.to_account_info(),
+                            authority: get_msol_from_authority.to_account_info(),
+                        },
+                    ),
+                    msol_left,
+                )?;
+                state.on_msol_burn(msol_left);
+                msol_left = 0;
+                ticket_lamports
+            }
+            _ => 0,
+        };
+
+        require_gte!(
+            reserve_lamports + pool_lamports + ticket_lamports,
+            min_lamports_out,
+            MarinadeError::SlippageExceeded
+        );
+
+        emit!(BestEffortUnstakeEvent {
+            state: self.liquid_unstake.state.key(),
+            msol_owner: self.liquid_unstake.get_msol_from.owner,
+            msol_amount,
+            reserve_msol,
+            reserve_lamports,
+            pool_msol,
+            pool_lamports,
+            ticket: self.new_ticket_account.as_ref().map(|ticket| ticket.key()),
+            ticket_lamports,
+            msol_left,
+        });
+
+        Ok(())
+    }
+
+    fn withdraw_from_reserve(&mut self, msol_amount: u64, lamports: u64) -> Result<()> {
+        let LiquidUnstake {
+            state,
+            msol_mint,
+            get_msol_from,
+            get_msol_from_authority,
+            transfer_sol_to,
+            system_program,
+            token_program,
+            ..
+        } = &mut self.liquid_unstake;
+        state.on_instant_withdraw(lamports, &self.clock)?;
+
+        burn(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Burn {
+                    mint: msol_mint.to_account_info(),
+                    from: get_msol_from.to_account_info(),
+                    authority: get_msol_from_authority.to_account_info(),
+                },
+            ),
+            msol_amount,
+        )?;
+        state.on_msol_burn(msol_amount);
+
+        transfer(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                Transfer {
+                    from: self.reserve_pda.to_account_info(),
+                    to: transfer_sol_to.to_account_info(),
+                },
+                &[&[
+                    &state.key().to_bytes(),
+                    State::RESERVE_SEED,
+                    &[state.reserve_bump_seed],
+                ]],
+            ),
+            lamports,
+        )?;
+        state.on_transfer_from_reserve(lamports);
+        // liquid_unstake checks the balance of get_msol_from
+        get_msol_from.reload()
+    }
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    error::MarinadeError, events::liq_pool::ClaimVestedEvent,
+    state::vesting_schedule::VestingSchedule, State,
+};
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        constraint = vesting_account.state_address == state.key()
+            @ MarinadeError::InvalidVestingSchedule
+    )]
+    pub vesting_account: Box<Account<'info, VestingSchedule>>,
+
+    #[account(
+        mut,
+        address = vesting_account.msol_escrow
+    )]
+    pub msol_escrow: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            VestingSchedule::ESCROW_AUTHORITY_SEED
+        ],
+        bump = vesting_account.escrow_authority_bump_seed
+    )]
+    pub vesting_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = vesting_account.beneficiary @ MarinadeError::WrongBeneficiary
+    )]
+    pub transfer_sol_to: SystemAccount<'info>,
+    #[account(
+        mut,
+        token::mint = state.msol_mint,
+        constraint = transfer_msol_to.owner == vesting_account.beneficiary
+            @ MarinadeError::WrongBeneficiary
+    )]
+    pub transfer_msol_to: Box<Account<'info, TokenAccount>>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// ClaimVested instruction: delivers the vested part of a VestingSchedule created by remove_liquidity_vested
+/// Anyone can call it, funds always go to the beneficiary.
+/// The schedule account is closed once everything is claimed
+impl<'info> ClaimVested<'info> {
+    // fn claim_vested()
+    pub fn process(&mut self) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::LIQUIDITY)?;
+
+        let slot = self.clock.slot;
+        let lamports = self.vesting_account.claimable_lamports(slot)?;
+        let msol_amount = self.vesting_account.claimable_msol(slot)?;
+        if lamports == 0 && msol_amount == 0 {
+            return err!(MarinadeError::NothingVestedYet)
+                .map_err(|e| e.with_values((slot, self.vesting_account.cliff_slot)));
+        }
+
+        if lamports > 0 {
+            // the schedule account is owned by the program, so lamports are moved directly
+            **self
+                .vesting_account
+                .to_account_info()
+                .try_borrow_mut_lamports()? -= lamports;
+            **self.transfer_sol_to.try_borrow_mut_lamports()? += lamports;
+            self.vesting_account.claimed_lamports += lamports;
+        }
+
+        if msol_amount > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: self.msol_escrow.to_account_info(),
+                        to: self.transfer_msol_to.to_account_info(),
+                        authority: self.vesting_authority.to_account_info(),
+                    },
+                    &[&[
+                        &self.state.key().to_bytes(),
+                        VestingSchedule::ESCROW_AUTHORITY_SEED,
+                        &[self.vesting_account.escrow_authority_bump_seed],
+                    ]],
+                ),
+                msol_amount,
+            )?;
+            self.vesting_account.claimed_msol += msol_amount;
+        }
+
+        emit!(ClaimVestedEvent {
+            state: self.state.key(),
+            vesting: self.vesting_account.key(),
+            beneficiary: self.vesting_account.beneficiary,
+            slot,
+            lamports,
+            msol_amount,
+            claimed_lamports: self.vesting_account.claimed_lamports,
+            claimed_msol: self.vesting_account.claimed_msol,
+        });
+
+        if self.vesting_account.is_fully_claimed() {
+            // return the rent to the beneficiary
+            self.vesting_account
+                .close(self.transfer_sol_to.to_account_info())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{events::liq_pool::EndFlashRemoveLiquidityEvent, state::liq_pool::LiqPool, State};
+
+#[derive(Accounts)]
+pub struct EndFlashRemoveLiquidity<'info> {
+    // must stay the first account, begin_flash_remove_liquidity looks for it
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::SOL_LEG_SEED
+        ],
+        bump = state.liq_pool.sol_leg_bump_seed
+    )]
+    pub liq_pool_sol_leg_pda: SystemAccount<'info>,
+    #[account(address = state.liq_pool.msol_leg)]
+    pub liq_pool_msol_leg: Box<Account<'info, TokenAccount>>,
+}
+
+impl<'info> EndFlashRemoveLiquidity<'info> {
+    // fn end_flash_remove_liquidity()
+    pub fn process(&mut self) -> Result<()> {
+        let sol_leg_balance = self.liq_pool_sol_leg_pda.lamports();
+        let msol_leg_balance = self.liq_pool_msol_leg.amount;
+        let flash_remove = self.state.flash_remove;
+
+        self.state
+            .flash_remove
+            .end(sol_leg_balance, msol_leg_balance)?;
+
+        emit!(EndFlashRemoveLiquidityEvent {
+            state: self.state.key(),
+            sol_leg_balance,
+            msol_leg_balance,
+            sol_leg_target: flash_remove.sol_leg_target,
+            msol_leg_target: flash_remove.msol_leg_target,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    error::MarinadeError,
+    events::liq_pool::ExtendLockEvent,
+    state::locked_lp::{LockedLp, LockedLpPosition},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct ExtendLock<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = position.state_address == state.key()
+            @ MarinadeError::InvalidLockedLpPosition
+    )]
+    pub position: Box<Account<'info, LockedLpPosition>>,
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        address = state.locked_lp.boost_vault
+    )]
+    pub boost_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LockedLp::AUTHORITY_SEED
+        ],
+        bump = state.locked_lp.authority_bump_seed
+    )]
+    pub locked_lp_authority: UncheckedAccount<'info>,
+    // receives the boost earned so far
+    #[account(
+        mut,
+        token::mint = state.msol_mint
+    )]
+    pub transfer_msol_to: Box<Account<'info, TokenAccount>>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ExtendLock<'info> {
+    // fn extend_lock() // move unlock_slot later, re-weighting the position on the remaining lock
+    pub fn process(&mut self, unlock_slot: u64) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::LIQUIDITY)?;
+        require_gt!(
+            unlock_slot,
+            self.position.unlock_slot,
+            MarinadeError::InvalidLockDuration
+        );
+
+        let boost = self.position.take_boost(&self.state.locked_lp);
+        pay_locked_lp_boost(
+            &self.state,
+            &self.token_program,
+            &self.boost_vault,
+            &self.locked_lp_authority,
+            &self.transfer_msol_to,
+            boost,
+        )?;
+        self.position
+            .relock(&mut self.state.locked_lp, unlock_slot, self.clock.slot)?;
+
+        emit!(ExtendLockEvent {
+            state: self.state.key(),
+            position: self.position.key(),
+            owner: self.owner.key(),
+            boost_msol: boost,
+            unlock_slot,
+            weight: self.position.weight,
+            total_weight: self.state.locked_lp.total_weight,
+        });
+        Ok(())
+    }
+}
+
+pub fn pay_locked_lp_boost<'info>(
+    state: &Account<'info, State>,
+    token_program: &Program<'info, Token>,
+    boost_vault: &Account<'info, TokenAccount>,
+    locked_lp_authority: &UncheckedAccount<'info>,
+    transfer_msol_to: &Account<'info, TokenAccount>,
+    boost: u64,
+) -> Result<()> {
+    if boost == 0 {
+        return Ok(());
+    }
+    transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: boost_vault.to_account_info(),
+                to: transfer_msol_to.to_account_info(),
+                authority: locked_lp_authority.to_account_info(),
+            },
+            &[&[
+                &state.key().to_bytes(),
+                LockedLp::AUTHORITY_SEED,
+                &[state.locked_lp.authority_bump_seed],
+            ]],
+        ),
+        boost,
+    )
+}
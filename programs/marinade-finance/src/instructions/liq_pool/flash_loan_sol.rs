@@ -0,0 +1,90 @@
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    checks::is_followed_by, error::MarinadeError, events::liq_pool::FlashLoanSolEvent,
+    instruction::FlashRepaySol as FlashRepaySolInstruction, require_lte, state::liq_pool::LiqPool,
+    State,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct FlashLoanSol<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::SOL_LEG_SEED
+        ],
+        bump = state.liq_pool.sol_leg_bump_seed
+    )]
+    pub liq_pool_sol_leg_pda: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub transfer_sol_to: SystemAccount<'info>,
+
+    /// CHECK: instructions sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// FlashLoanSol instruction: lends lamports of the SOL leg for the rest of the transaction.
+/// The transaction must contain a later flash_repay_sol for the same state,
+/// which takes back the lamports plus lp_min_fee, the fee staying with the LPs
+impl<'info> FlashLoanSol<'info> {
+    // fn flash_loan_sol()
+    pub fn process(&mut self, lamports: u64) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::LIQUIDITY)?;
+        self.state.flash_remove.check_not_in_progress()?;
+        self.state.liq_pool_drain.check_not_drained()?;
+        require!(
+            is_followed_by(
+                &self.instructions.to_account_info(),
+                &FlashRepaySolInstruction::DISCRIMINATOR,
+                &self.state.key(),
+            )?,
+            MarinadeError::FlashLoanRepayMissing
+        );
+
+        let sol_leg_balance = self.liq_pool_sol_leg_pda.lamports();
+        require_lte!(
+            lamports,
+            sol_leg_balance.saturating_sub(self.state.rent_exempt_for_token_acc),
+            MarinadeError::InsufficientLiquidity
+        );
+        let fee = self.state.liq_pool.lp_min_fee;
+        self.state
+            .flash_loan
+            .begin(sol_leg_balance, lamports, fee)?;
+
+        transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.liq_pool_sol_leg_pda.to_account_info(),
+                    to: self.transfer_sol_to.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    LiqPool::SOL_LEG_SEED,
+                    &[self.state.liq_pool.sol_leg_bump_seed],
+                ]],
+            ),
+            lamports,
+        )?;
+
+        emit!(FlashLoanSolEvent {
+            state: self.state.key(),
+            sol_leg_balance,
+            lamports,
+            sol_leg_target: self.state.flash_loan.sol_leg_target,
+        });
+
+        Ok(())
+    }
+}
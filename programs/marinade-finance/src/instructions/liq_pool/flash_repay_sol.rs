@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{
+    error::MarinadeError, events::liq_pool::FlashRepaySolEvent, state::liq_pool::LiqPool, State,
+};
+
+#[derive(Accounts)]
+pub struct FlashRepaySol<'info> {
+    // must stay the first account, flash_loan_sol looks for it
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::SOL_LEG_SEED
+        ],
+        bump = state.liq_pool.sol_leg_bump_seed
+    )]
+    pub liq_pool_sol_leg_pda: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub transfer_from: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FlashRepaySol<'info> {
+    // fn flash_repay_sol() // pays the lent lamports + fee from transfer_from
+    pub fn process(&mut self) -> Result<()> {
+        let sol_leg_target = self.state.flash_loan.sol_leg_target;
+        let repaid = self.state.flash_loan.owed()?;
+        require_gte!(
+            self.transfer_from.lamports(),
+            repaid,
+            MarinadeError::NotEnoughUserFunds
+        );
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.transfer_from.to_account_info(),
+                    to: self.liq_pool_sol_leg_pda.to_account_info(),
+                },
+            ),
+            repaid,
+        )?;
+        let sol_leg_balance = self.liq_pool_sol_leg_pda.lamports();
+        self.state.flash_loan.end(sol_leg_balance)?;
+
+        emit!(FlashRepaySolEvent {
+            state: self.state.key(),
+            repaid,
+            sol_leg_balance,
+            sol_leg_target,
+        });
+
+        Ok(())
+    }
+}
@@ -4,9 +4,12 @@ use anchor_spl::token::{
     transfer as transfer_token, Mint, Token, TokenAccount, Transfer as TransferToken,
 };
 
+use crate::state::pause_flags::PauseFlags;
 use crate::{
-    checks::check_token_source_account, events::liq_pool::LiquidUnstakeEvent,
-    state::liq_pool::LiqPool, MarinadeError, State,
+    checks::check_token_source_account,
+    events::liq_pool::{LiquidUnstakeEvent, LpFeeAccruedEvent},
+    state::liq_pool::LiqPool,
+    MarinadeError, State,
 };
 
 #[derive(Accounts)]
@@ -53,12 +56,25 @@ pub struct LiquidUnstake<'info> {
 
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
+
+    // receives the locked LP boost part of the fee, required once the locked LP boost is configured
+    #[account(
+        mut,
+        address = state.locked_lp.boost_vault
+    )]
+    pub locked_lp_boost_vault: Option<Box<Account<'info, TokenAccount>>>,
 }
 
 impl<'info> LiquidUnstake<'info> {
     // fn liquid_unstake()
-    pub fn process(&mut self, msol_amount: u64) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+    // min_lamports_out bounds the fee accepted, it may change between quote and execution
+    pub fn process(&mut self, msol_amount: u64, min_lamports_out: u64) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::LIQUID_UNSTAKE)?;
+        self.state.check_liq_pool_not_lent()?;
+        require!(
+            !self.state.locked_lp.is_configured() || self.locked_lp_boost_vault.is_some(),
+            MarinadeError::MissingLockedLpBoostVault
+        );
 
         check_token_source_account(
             &self.get_msol_from,
@@ -79,13 +95,9 @@ impl<'info> LiquidUnstake<'info> {
 
         // fee is computed based on the liquidity *after* the user takes the sol
         let user_remove_lamports = self.state.msol_to_sol(msol_amount)?;
-        let liquid_unstake_fee = if user_remove_lamports >= liq_pool_available_sol_balance {
-            // user is removing all liquidity
-            self.state.liq_pool.lp_max_fee
-        } else {
-            let after_lamports = liq_pool_available_sol_balance - user_remove_lamports; //how much will be left?
-            self.state.liq_pool.linear_fee(after_lamports)
-        };
+        let liquid_unstake_fee = self
+            .state
+            .liquid_unstake_fee(liq_pool_available_sol_balance, user_remove_lamports);
 
         // compute fee in msol
         let msol_fee = liquid_unstake_fee.apply(msol_amount);
@@ -107,6 +119,11 @@ impl<'info> LiquidUnstake<'info> {
             self.state.min_withdraw,
             MarinadeError::WithdrawAmountIsTooLow
         );
+        require_gte!(
+            working_lamports_value,
+            min_lamports_out,
+            MarinadeError::SlippageExceeded
+        );
 
         //transfer SOL from the liq-pool to the user
         if working_lamports_value > 0 {
@@ -135,6 +152,12 @@ impl<'info> LiquidUnstake<'info> {
         };
         msg!("treasury_msol_cut {}", treasury_msol_cut);
 
+        let locked_lp_boost = if self.locked_lp_boost_vault.is_some() {
+            self.state.locked_lp.boost_cut(msol_fee - treasury_msol_cut)
+        } else {
+            0
+        };
+
         //transfer mSOL to the liq-pool
         transfer_token(
             CpiContext::new(
@@ -145,7 +168,7 @@ impl<'info> LiquidUnstake<'info> {
                     authority: self.get_msol_from_authority.to_account_info(),
                 },
             ),
-            msol_amount - treasury_msol_cut,
+            msol_amount - treasury_msol_cut - locked_lp_boost,
         )?;
 
         //transfer treasury cut to treasury_msol_account
@@ -163,6 +186,38 @@ impl<'info> LiquidUnstake<'info> {
             )?;
         }
 
+        if let Some(locked_lp_boost_vault) = &self.locked_lp_boost_vault {
+            if locked_lp_boost > 0 {
+                transfer_token(
+                    CpiContext::new(
+                        self.token_program.to_account_info(),
+                        TransferToken {
+                            from: self.get_msol_from.to_account_info(),
+                            to: locked_lp_boost_vault.to_account_info(),
+                            authority: self.get_msol_from_authority.to_account_info(),
+                        },
+                    ),
+                    locked_lp_boost,
+                )?;
+                self.state.locked_lp.accrue(locked_lp_boost);
+            }
+        }
+
+        let lp_msol_fee = msol_fee - treasury_msol_cut - locked_lp_boost;
+        let epoch = Clock::get()?.epoch;
+        let fee_lamports = self.state.accrue_lp_fee(lp_msol_fee, epoch)?;
+        emit!(LpFeeAccruedEvent {
+            state: self.state.key(),
+            epoch,
+            msol_fee: lp_msol_fee,
+            fee_lamports,
+            lp_supply: self.state.liq_pool.lp_supply,
+            epoch_fees: self.state.lp_fee_accrual.epoch_fees,
+            previous_epoch_fees: self.state.lp_fee_accrual.previous_epoch_fees,
+            lifetime_fees: self.state.lp_fee_accrual.lifetime_fees,
+            fees_per_lp: self.state.lp_fee_accrual.fees_per_lp,
+        });
+
         emit!(LiquidUnstakeEvent {
             state: self.state.key(),
             msol_owner: self.get_msol_from.owner,
@@ -174,6 +229,7 @@ impl<'info> LiquidUnstake<'info> {
             user_sol_balance,
             msol_fee,
             treasury_msol_cut,
+            locked_lp_boost,
             sol_amount: working_lamports_value,
             lp_liquidity_target: self.state.liq_pool.lp_liquidity_target,
             lp_max_fee: self.state.liq_pool.lp_max_fee,
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    checks::check_token_source_account,
+    error::MarinadeError,
+    events::liq_pool::LockLiquidityEvent,
+    state::locked_lp::{LockedLp, LockedLpPosition},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct LockLiquidity<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + std::mem::size_of::<LockedLpPosition>(),
+        seeds = [
+            &state.key().to_bytes(),
+            LockedLpPosition::SEED,
+            &owner.key().to_bytes()
+        ],
+        bump
+    )]
+    pub position: Box<Account<'info, LockedLpPosition>>,
+    #[account(mut)]
+    pub owner: Signer<'info>, // lp_from owner or delegate_authority
+
+    #[account(
+        mut,
+        token::mint = state.liq_pool.lp_mint
+    )]
+    pub lp_from: Box<Account<'info, TokenAccount>>,
+    // holds the locked LP
+    #[account(
+        mut,
+        token::mint = state.liq_pool.lp_mint,
+        token::authority = locked_lp_authority
+    )]
+    pub lp_escrow: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LockedLp::AUTHORITY_SEED
+        ],
+        bump = state.locked_lp.authority_bump_seed
+    )]
+    pub locked_lp_authority: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> LockLiquidity<'info> {
+    // fn lock_liquidity() // escrow LP tokens for lock_slots, earning the locked LP boost
+    pub fn process(&mut self, lp_amount: u64, lock_slots: u64) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::LIQUIDITY)?;
+        require!(
+            self.state.locked_lp.is_configured(),
+            MarinadeError::LockedLpBoostDisabled
+        );
+        LockedLp::check_lock_slots(lock_slots)?;
+        check_token_source_account(&self.lp_from, self.owner.key, lp_amount)
+            .map_err(|e| e.with_account_name("lp_from"))?;
+
+        transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.lp_from.to_account_info(),
+                    to: self.lp_escrow.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                },
+            ),
+            lp_amount,
+        )?;
+
+        let mut position = LockedLpPosition {
+            state_address: self.state.key(),
+            owner: self.owner.key(),
+            lp_escrow: self.lp_escrow.key(),
+            lp_amount,
+            unlock_slot: 0,
+            weight: 0,
+            boost_debt: 0,
+        };
+        position.relock(
+            &mut self.state.locked_lp,
+            self.clock.slot + lock_slots,
+            self.clock.slot,
+        )?;
+        self.position.set_inner(position);
+
+        emit!(LockLiquidityEvent {
+            state: self.state.key(),
+            position: self.position.key(),
+            owner: self.owner.key(),
+            lp_amount,
+            unlock_slot: self.position.unlock_slot,
+            weight: self.position.weight,
+            total_weight: self.state.locked_lp.total_weight,
+        });
+        Ok(())
+    }
+}
@@ -1,7 +1,45 @@
 pub mod add_liquidity;
+pub mod add_liquidity_msol;
+pub mod arb_liq_pool;
+pub mod begin_flash_remove_liquidity;
+pub mod best_effort_unstake;
+pub mod claim_vested;
+pub mod end_flash_remove_liquidity;
+pub mod extend_lock;
+pub mod flash_loan_sol;
+pub mod flash_repay_sol;
 pub mod liquid_unstake;
+pub mod lock_liquidity;
 pub mod remove_liquidity;
+pub mod remove_liquidity_order_unstake;
+pub mod remove_liquidity_rebalance;
+pub mod remove_liquidity_sol_only;
+pub mod remove_liquidity_vested;
+pub mod remove_liquidity_with_keeper_tip;
+pub mod remove_liquidity_with_receipt;
+pub mod simulate_remove_breakdown;
+pub mod unlock_liquidity;
+pub mod zap_add_liquidity;
 
 pub use add_liquidity::*;
+pub use add_liquidity_msol::*;
+pub use arb_liq_pool::*;
+pub use begin_flash_remove_liquidity::*;
+pub use best_effort_unstake::*;
+pub use claim_vested::*;
+pub use end_flash_remove_liquidity::*;
+pub use extend_lock::*;
+pub use flash_loan_sol::*;
+pub use flash_repay_sol::*;
 pub use liquid_unstake::*;
+pub use lock_liquidity::*;
 pub use remove_liquidity::*;
+pub use remove_liquidity_order_unstake::*;
+pub use remove_liquidity_rebalance::*;
+pub use remove_liquidity_sol_only::*;
+pub use remove_liquidity_vested::*;
+pub use remove_liquidity_with_keeper_tip::*;
+pub use remove_liquidity_with_receipt::*;
+pub use simulate_remove_breakdown::*;
+pub use unlock_liquidity::*;
+pub use zap_add_liquidity::*;
@@ -1,6 +1,11 @@
+use crate::state::pause_flags::PauseFlags;
 use crate::{
-    calc::proportional, checks::check_token_source_account, error::MarinadeError,
-    events::liq_pool::RemoveLiquidityEvent, state::liq_pool::LiqPool, State,
+    calc::{proportional, value_from_shares},
+    checks::check_token_source_account,
+    error::MarinadeError,
+    events::liq_pool::{RemovalPriceImpact, RemoveLiquidityEvent},
+    state::{liq_pool::LiqPool, Fee},
+    State,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
@@ -8,6 +13,214 @@ use anchor_spl::token::{
     burn, transfer as transfer_token, Burn, Mint, Token, TokenAccount, Transfer as TransferToken,
 };
 
+/// Outcome of each check remove_liquidity makes on the pool and the outputs,
+/// false where remove_liquidity fails. Fields in the order remove_liquidity checks them
+#[derive(Clone, Copy, Debug, Default, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveLiquidityChecks {
+    pub not_paused: bool,        // neither the program nor PauseFlags::LIQUIDITY
+    pub liq_pool_not_lent: bool, // no flash removal, flash loan or drain in progress
+    pub circuit_breaker_not_tripped: bool,
+    pub lp_supply_registered: bool, // no LP minted outside of the program
+    pub min_withdraw_passed: bool,
+    pub min_sol_out_passed: bool,
+    pub min_msol_out_passed: bool,
+}
+
+/// Full picture of what burning some LP tokens yields against the current legs.
+/// Used by remove_liquidity itself and returned by simulate_remove_breakdown
+#[derive(Clone, Copy, Debug, Default, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveLiquidityBreakdown {
+    pub lp_burned: u64,
+    pub lp_mint_supply: u64,
+    pub lp_supply: u64, // virtual lp supply used for the proportion
+    pub sol_leg_available_balance: u64,
+    pub msol_leg_balance: u64,
+    pub sol_out_amount: u64,
+    pub msol_out_amount: u64,
+    pub msol_out_value: u64, // lamports value of msol_out_amount at the current mSOL price
+    pub spot_value: u64, // lamports value of lp_burned at the pool value per LP, without per-leg rounding
+    pub min_withdraw: u64,
+    pub min_sol_out: u64,
+    pub min_msol_out: u64,
+    pub checks: RemoveLiquidityChecks, // filled by evaluate
+}
+
+impl RemoveLiquidityBreakdown {
+    /// The removal as remove_liquidity makes it, with the result of every check.
+    /// Shared by remove_liquidity and simulate_remove_breakdown, fails only on math errors
+    pub fn evaluate(
+        state: &State,
+        tokens: u64,
+        lp_mint_supply: u64,
+        sol_leg_balance: u64,
+        msol_leg_balance: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+    ) -> Result<Self> {
+        // lp_supply after the self-heal of remove_liquidity. Above the virtual supply
+        // the self-heal fails (lp_supply_registered) and the virtual one is shown
+        let lp_supply = lp_mint_supply.min(state.liq_pool.lp_supply);
+        let mut breakdown = Self {
+            lp_mint_supply,
+            min_sol_out,
+            min_msol_out,
+            ..Self::compute(state, tokens, lp_supply, sol_leg_balance, msol_leg_balance)?
+        };
+        let passed = breakdown.check_results(state).map(|result| result.is_ok());
+        breakdown.checks = RemoveLiquidityChecks {
+            not_paused: passed[0],
+            liq_pool_not_lent: passed[1],
+            circuit_breaker_not_tripped: passed[2],
+            lp_supply_registered: passed[3],
+            min_withdraw_passed: passed[4],
+            min_sol_out_passed: passed[5],
+            min_msol_out_passed: passed[6],
+        };
+        Ok(breakdown)
+    }
+
+    /// fails with the error of the first failed check, like remove_liquidity
+    pub fn check(&self, state: &State) -> Result<()> {
+        self.check_results(state).into_iter().collect()
+    }
+
+    // every check of RemoveLiquidityChecks with the error remove_liquidity fails with
+    fn check_results(&self, state: &State) -> [Result<()>; 7] {
+        let at_least = |value: u64, min: u64, error: MarinadeError| -> Result<()> {
+            require_gte!(value, min, error);
+            Ok(())
+        };
+        [
+            state.check_not_paused(PauseFlags::LIQUIDITY),
+            state.check_liq_pool_not_lent(),
+            state.price_circuit_breaker.check_not_tripped(),
+            // Someone minted lp tokens without our permission or bug found
+            at_least(
+                state.liq_pool.lp_supply,
+                self.lp_mint_supply,
+                MarinadeError::UnregisteredLPMinted,
+            ),
+            at_least(
+                self.total_out_value(),
+                state.min_withdraw,
+                MarinadeError::WithdrawAmountIsTooLow,
+            ),
+            at_least(
+                self.sol_out_amount,
+                self.min_sol_out,
+                MarinadeError::RemoveLiquidityBelowMinOut,
+            ),
+            at_least(
+                self.msol_out_amount,
+                self.min_msol_out,
+                MarinadeError::RemoveLiquidityBelowMinOut,
+            ),
+        ]
+    }
+
+    pub fn compute(
+        state: &State,
+        tokens: u64,
+        lp_supply: u64,
+        sol_leg_balance: u64,
+        msol_leg_balance: u64,
+    ) -> Result<Self> {
+        Self::from_legs(
+            tokens,
+            lp_supply,
+            sol_leg_balance - state.rent_exempt_for_token_acc,
+            msol_leg_balance,
+            state.min_withdraw,
+            |msol_amount| state.msol_to_sol(msol_amount),
+        )
+    }
+
+    fn from_legs(
+        tokens: u64,
+        lp_supply: u64,
+        sol_leg_available_balance: u64,
+        msol_leg_balance: u64,
+        min_withdraw: u64,
+        msol_to_sol: impl Fn(u64) -> Result<u64>,
+    ) -> Result<Self> {
+        let sol_out_amount = proportional(tokens, sol_leg_available_balance, lp_supply)?;
+        let msol_out_amount = proportional(tokens, msol_leg_balance, lp_supply)?;
+        let msol_out_value = msol_to_sol(msol_out_amount)?;
+        let spot_value = value_from_shares(
+            tokens,
+            LiqPool::total_claimable_lamports(
+                sol_leg_available_balance,
+                msol_to_sol(msol_leg_balance)?,
+            )?,
+            lp_supply,
+        )?;
+        Ok(Self {
+            lp_burned: tokens,
+            lp_mint_supply: lp_supply,
+            lp_supply,
+            sol_leg_available_balance,
+            msol_leg_balance,
+            sol_out_amount,
+            msol_out_amount,
+            msol_out_value,
+            spot_value,
+            min_withdraw,
+            ..Default::default()
+        })
+    }
+
+    pub fn total_out_value(&self) -> u64 {
+        self.sol_out_amount + self.msol_out_value
+    }
+
+    /// rounding dust left in the legs: value of the LP burned minus the value delivered
+    pub fn dust_value(&self) -> u64 {
+        self.spot_value.saturating_sub(self.total_out_value())
+    }
+
+    /// mSOL taken from what stays in the msol leg to pay dust_value, rounded down
+    pub fn dust_msol(&self, lamports_to_msol: impl Fn(u64) -> Result<u64>) -> Result<u64> {
+        Ok(lamports_to_msol(self.dust_value())?.min(self.msol_leg_balance - self.msol_out_amount))
+    }
+
+    pub fn price_impact(
+        &self,
+        msol_to_sol: impl Fn(u64) -> Result<u64>,
+    ) -> Result<RemovalPriceImpact> {
+        Ok(RemovalPriceImpact {
+            sol_leg_ratio_before_bps: LiqPool::leg_ratio_bps(
+                self.sol_leg_available_balance,
+                msol_to_sol(self.msol_leg_balance)?,
+            ),
+            sol_leg_ratio_after_bps: LiqPool::leg_ratio_bps(
+                self.sol_leg_available_balance - self.sol_out_amount,
+                msol_to_sol(self.msol_leg_balance - self.msol_out_amount)?,
+            ),
+            effective_price: proportional(
+                self.total_out_value(),
+                State::PRICE_DENOMINATOR,
+                self.lp_burned,
+            )?,
+        })
+    }
+
+    /// shortfall of the delivered value against spot_value in basis points.
+    /// Removal is pro-rata on both legs so there is no price impact,
+    /// the shortfall is the per-leg rounding and weighs most on small removals
+    pub fn slippage_bps(&self) -> Result<u16> {
+        let shortfall = self.dust_value();
+        if shortfall == 0 {
+            return Ok(0);
+        }
+        u16::try_from(proportional(
+            shortfall,
+            Fee::MAX_BASIS_POINTS.into(),
+            self.spot_value,
+        )?)
+        .map_err(|_| error!(MarinadeError::CalculationFailure))
+    }
+}
+
 #[derive(Accounts)]
 pub struct RemoveLiquidity<'info> {
     #[account(mut)]
@@ -62,11 +275,52 @@ pub struct RemoveLiquidity<'info> {
 
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
+
+    /// CHECK: deserialized in code. Receives the rounding dust, required while removal_dust_to_treasury
+    #[account(
+        mut,
+        address = state.treasury_msol_account
+    )]
+    pub treasury_msol_account: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> RemoveLiquidity<'info> {
-    pub fn process(&mut self, tokens: u64) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+    pub fn process(&mut self, tokens: u64) -> Result<RemoveLiquidityBreakdown> {
+        self.remove(tokens, 0, 0, None)
+    }
+
+    /// remove_liquidity failing when either leg pays out less than the user expects,
+    /// the legs can move between building and executing the transaction
+    pub fn process_with_min_out(
+        &mut self,
+        tokens: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+    ) -> Result<RemoveLiquidityBreakdown> {
+        self.remove(tokens, min_sol_out, min_msol_out, None)
+    }
+
+    /// remove_liquidity paying `tip` lamports of the SOL output to the keeper that submitted it
+    pub fn process_with_keeper_tip(
+        &mut self,
+        tokens: u64,
+        keeper: &AccountInfo<'info>,
+        tip: u64,
+    ) -> Result<RemoveLiquidityBreakdown> {
+        self.remove(tokens, 0, 0, Some((keeper, tip)))
+    }
+
+    fn remove(
+        &mut self,
+        tokens: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+        keeper_tip: Option<(&AccountInfo<'info>, u64)>,
+    ) -> Result<RemoveLiquidityBreakdown> {
+        require!(
+            !self.state.removal_dust_to_treasury || self.treasury_msol_account.is_some(),
+            MarinadeError::MissingTreasuryMsolAccount
+        );
 
         check_token_source_account(&self.burn_from, self.burn_from_authority.key, tokens)
             .map_err(|e| e.with_account_name("burn_from"))?;
@@ -79,75 +333,75 @@ impl<'info> RemoveLiquidity<'info> {
         let msol_leg_balance = self.liq_pool_msol_leg.amount;
 
         // Update virtual lp_supply by real one
+        // reload first so the self-heal never works on a stale copy of the mint data
+        self.lp_mint.reload()?;
         let lp_mint_supply = self.lp_mint.supply;
-        if lp_mint_supply > self.state.liq_pool.lp_supply {
-            // impossible to happen unless bug
-            msg!("Someone minted lp tokens without our permission or bug found");
-        } else {
-            // maybe burn
-            self.state.liq_pool.lp_supply = lp_mint_supply;
-        }
         msg!("mSOL-SOL-LP total supply:{}", lp_mint_supply);
 
-        let sol_out_amount = proportional(
-            tokens,
-            sol_leg_balance - self.state.rent_exempt_for_token_acc,
-            self.state.liq_pool.lp_supply, // Use virtual amount
-        )?;
-        let msol_out_amount = proportional(
+        // same evaluation as simulate_remove_breakdown
+        let breakdown = RemoveLiquidityBreakdown::evaluate(
+            &self.state,
             tokens,
+            lp_mint_supply,
+            sol_leg_balance,
             msol_leg_balance,
-            self.state.liq_pool.lp_supply, // Use virtual amount
+            min_sol_out,
+            min_msol_out,
         )?;
-
-        require_gte!(
-            sol_out_amount + self.state.msol_to_sol(msol_out_amount)?,
-            self.state.min_withdraw,
-            MarinadeError::WithdrawAmountIsTooLow,
-        );
+        breakdown.check(&self.state)?;
+        self.state.liq_pool.sync_lp_supply(lp_mint_supply)?;
+        let RemoveLiquidityBreakdown {
+            sol_out_amount,
+            msol_out_amount,
+            ..
+        } = breakdown;
         msg!(
             "SOL out amount:{}, mSOL out amount:{}",
             sol_out_amount,
             msol_out_amount
         );
 
-        if sol_out_amount > 0 {
+        let (user_sol_amount, tip) = match keeper_tip {
+            Some((_, tip)) => LiqPool::split_keeper_tip(sol_out_amount, tip)?,
+            None => (sol_out_amount, 0),
+        };
+        if user_sol_amount > 0 {
             msg!("transfer SOL");
-            transfer(
-                CpiContext::new_with_signer(
-                    self.system_program.to_account_info(),
-                    Transfer {
-                        from: self.liq_pool_sol_leg_pda.to_account_info(),
-                        to: self.transfer_sol_to.to_account_info(),
-                    },
-                    &[&[
-                        &self.state.key().to_bytes(),
-                        LiqPool::SOL_LEG_SEED,
-                        &[self.state.liq_pool.sol_leg_bump_seed],
-                    ]],
-                ),
-                sol_out_amount,
-            )?;
+            self.transfer_sol_from_leg(self.transfer_sol_to.to_account_info(), user_sol_amount)?;
+        }
+        if let Some((keeper, _)) = keeper_tip {
+            if tip > 0 {
+                msg!("transfer keeper tip {}", tip);
+                self.transfer_sol_from_leg(keeper.clone(), tip)?;
+            }
         }
 
         if msol_out_amount > 0 {
             msg!("transfer mSOL");
-            transfer_token(
-                CpiContext::new_with_signer(
-                    self.token_program.to_account_info(),
-                    TransferToken {
-                        from: self.liq_pool_msol_leg.to_account_info(),
-                        to: self.transfer_msol_to.to_account_info(),
-                        authority: self.liq_pool_msol_leg_authority.to_account_info(),
-                    },
-                    &[&[
-                        &self.state.key().to_bytes(),
-                        LiqPool::MSOL_LEG_AUTHORITY_SEED,
-                        &[self.state.liq_pool.msol_leg_authority_bump_seed],
-                    ]],
-                ),
-                msol_out_amount,
-            )?;
+            self.transfer_msol_from_leg(self.transfer_msol_to.to_account_info(), msol_out_amount)?;
+        }
+
+        // the user outputs are already final, the dust only comes from what stays in the pool
+        let treasury_msol_dust = match &self.treasury_msol_account {
+            Some(treasury_msol_account)
+                if self.state.removal_dust_to_treasury
+                    && self
+                        .state
+                        .get_treasury_msol_balance(treasury_msol_account)
+                        .is_some() =>
+            {
+                breakdown.dust_msol(|lamports| self.state.calc_msol_from_lamports(lamports))?
+            }
+            _ => 0,
+        };
+        if let Some(treasury_msol_account) = &self.treasury_msol_account {
+            if treasury_msol_dust > 0 {
+                msg!("transfer mSOL dust {} to treasury", treasury_msol_dust);
+                self.transfer_msol_from_leg(
+                    treasury_msol_account.to_account_info(),
+                    treasury_msol_dust,
+                )?;
+            }
         }
 
         burn(
@@ -163,6 +417,12 @@ impl<'info> RemoveLiquidity<'info> {
         )?;
         self.state.liq_pool.on_lp_burn(tokens);
 
+        let price_impact = if self.state.removal_event_price_impact {
+            Some(breakdown.price_impact(|msol_amount| self.state.msol_to_sol(msol_amount))?)
+        } else {
+            None
+        };
+
         emit!(RemoveLiquidityEvent {
             state: self.state.key(),
             sol_leg_balance,
@@ -174,8 +434,824 @@ impl<'info> RemoveLiquidity<'info> {
             lp_burned: tokens,
             sol_out_amount,
             msol_out_amount,
+            treasury_msol_dust,
+            price_impact,
         });
 
+        Ok(breakdown)
+    }
+
+    pub(crate) fn transfer_msol_from_leg(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        transfer_token(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferToken {
+                    from: self.liq_pool_msol_leg.to_account_info(),
+                    to,
+                    authority: self.liq_pool_msol_leg_authority.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    LiqPool::MSOL_LEG_AUTHORITY_SEED,
+                    &[self.state.liq_pool.msol_leg_authority_bump_seed],
+                ]],
+            ),
+            amount,
+        )
+    }
+
+    pub(crate) fn transfer_sol_from_leg(
+        &self,
+        to: AccountInfo<'info>,
+        lamports: u64,
+    ) -> Result<()> {
+        transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.liq_pool_sol_leg_pda.to_account_info(),
+                    to,
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    LiqPool::SOL_LEG_SEED,
+                    &[self.state.liq_pool.sol_leg_bump_seed],
+                ]],
+            ),
+            lamports,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use anchor_lang::{Discriminator, Event};
+
+    use super::RemoveLiquidityBreakdown;
+    use crate::{
+        calc::{proportional, shares_from_value},
+        error::MarinadeError,
+        events::liq_pool::RemoveLiquidityEvent,
+        state::liq_pool::LiqPool,
+        State,
+    };
+
+    // mSOL at 1.5 SOL
+    fn breakdown(
+        tokens: u64,
+        lp_supply: u64,
+        sol_leg_available_balance: u64,
+        msol_leg_balance: u64,
+    ) -> Result<RemoveLiquidityBreakdown> {
+        RemoveLiquidityBreakdown::from_legs(
+            tokens,
+            lp_supply,
+            sol_leg_available_balance,
+            msol_leg_balance,
+            1,
+            |msol_amount| Ok(msol_amount * 3 / 2),
+        )
+    }
+
+    #[test]
+    fn test_slippage_bps() -> Result<()> {
+        // exact shares have no slippage
+        let exact = breakdown(500, 1_000, 2_000, 1_000)?;
+        assert_eq!(exact.spot_value, 1_750);
+        assert_eq!(exact.total_out_value(), 1_750);
+        assert_eq!(exact.slippage_bps()?, 0);
+
+        // on a small pool the rounding of each leg is a visible part of a small removal
+        let small_pool = breakdown(1, 3, 10, 10)?;
+        assert_eq!(small_pool.spot_value, 8);
+        assert_eq!(small_pool.total_out_value(), 7);
+        assert_eq!(small_pool.slippage_bps()?, 1_250);
+        let larger_removal = breakdown(2, 3, 10, 10)?;
+        assert!(larger_removal.slippage_bps()? < small_pool.slippage_bps()?);
+
+        // empty pool
+        assert_eq!(breakdown(0, 0, 0, 0)?.slippage_bps()?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_u64_max_tokens() {
+        // only reachable with a burn_from holding u64::MAX LP (check_token_source_account),
+        // even then the u128 math fails cleanly instead of overflowing
+        assert_eq!(
+            breakdown(u64::MAX, 1_000, 2_000, 1_000),
+            Err(MarinadeError::CalculationFailure.into())
+        );
+        assert!(breakdown(u64::MAX, u64::MAX, 2_000, 1_000).is_ok());
+    }
+
+    #[test]
+    fn test_price_impact_event() -> Result<()> {
+        // pool: 3_000 SOL + 1_000 mSOL worth 1_500 => 66.66% SOL
+        let removal = breakdown(250, 1_000, 3_000, 1_000)?;
+        let price_impact = removal.price_impact(|msol_amount| Ok(msol_amount * 3 / 2))?;
+        let event = RemoveLiquidityEvent {
+            state: Pubkey::new_unique(),
+            sol_leg_balance: 3_000,
+            msol_leg_balance: 1_000,
+            user_lp_balance: 250,
+            user_sol_balance: 0,
+            user_msol_balance: 0,
+            lp_mint_supply: 1_000,
+            lp_burned: 250,
+            sol_out_amount: removal.sol_out_amount,
+            msol_out_amount: removal.msol_out_amount,
+            treasury_msol_dust: 0,
+            price_impact: Some(price_impact),
+        };
+        let mut data = event.data();
+        assert_eq!(data[..8], RemoveLiquidityEvent::DISCRIMINATOR);
+        let decoded = RemoveLiquidityEvent::try_from_slice(&data.split_off(8))?;
+        let decoded_impact = decoded.price_impact.unwrap();
+        assert_eq!(decoded_impact, price_impact);
+
+        // pro-rata removal keeps the ratio
+        assert_eq!(decoded_impact.sol_leg_ratio_before_bps, 6_666);
+        assert_eq!(decoded_impact.sol_leg_ratio_after_bps, 6_666);
+        // (750 + 250 * 1.5) / 250 = 4.5 lamports per LP
+        assert_eq!(
+            decoded_impact.effective_price,
+            State::PRICE_DENOMINATOR * 9 / 2
+        );
+
+        // rounding moves the ratio on a tiny pool
+        let tiny = breakdown(1, 3, 10, 10)?.price_impact(|msol_amount| Ok(msol_amount * 3 / 2))?;
+        assert_eq!(tiny.sol_leg_ratio_before_bps, 4_000);
+        assert_eq!(tiny.sol_leg_ratio_after_bps, 4_117); // 7 SOL / (7 + 10)
+        Ok(())
+    }
+
+    #[test]
+    fn test_dust() -> Result<()> {
+        let msol_to_lamports = |msol_amount: u64| -> Result<u64> { Ok(msol_amount * 3 / 2) };
+        let lamports_to_msol = |lamports: u64| -> Result<u64> { Ok(lamports * 2 / 3) };
+
+        assert_eq!(
+            breakdown(500, 1_000, 2_000, 1_000)?.dust_msol(lamports_to_msol)?,
+            0
+        );
+
+        let small_pool = breakdown(1, 3, 10, 10)?;
+        assert_eq!(small_pool.dust_value(), 1);
+        // rounded down, 1 lamport is not worth a whole mSOL unit
+        assert_eq!(small_pool.dust_msol(lamports_to_msol)?, 0);
+
+        for (tokens, lp_supply, sol, msol) in [
+            (1, 7, 1_000, 1_000),
+            (5, 7, 1_003, 999),
+            (1, 1_000_000, 999_999, 1_999_999),
+            (333, 1_000, 0, 1_000),
+        ] {
+            let removal = breakdown(tokens, lp_supply, sol, msol)?;
+            let dust_msol = removal.dust_msol(lamports_to_msol)?;
+            // routed dust is worth at most the dust and only comes from what stays in the msol leg,
+            // the user still gets exactly the pro-rata outputs
+            assert!(msol_to_lamports(dust_msol)? <= removal.dust_value());
+            assert!(dust_msol + removal.msol_out_amount <= msol);
+            assert_eq!(removal, breakdown(tokens, lp_supply, sol, msol)?);
+        }
+        let removal = breakdown(1, 7, 1_000, 1_000)?;
+        assert_eq!(removal.dust_value(), 2);
+        assert_eq!(removal.dust_msol(lamports_to_msol)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_total_claimable_is_sum_of_holders() -> Result<()> {
+        let (sol, msol) = (7_777_777_777, 3_333_333_333);
+        let total = LiqPool::total_claimable_lamports(sol, msol * 3 / 2)?;
+        let holders = [1, 10_000, 333_333_333, 1_234_567_890, 3_000_000_000];
+        let lp_supply: u64 = holders.iter().sum::<u64>() + 5_000_000_000;
+        let mut claimable_sum = 0;
+        let mut lp_sum = 0;
+        for tokens in holders
+            .into_iter()
+            .chain([lp_supply - holders.iter().sum::<u64>()])
+        {
+            claimable_sum += breakdown(tokens, lp_supply, sol, msol)?.total_out_value();
+            lp_sum += tokens;
+        }
+        assert_eq!(lp_sum, lp_supply);
+        // each holder loses at most 1 lamport per leg plus the mSOL value rounding
+        assert!(claimable_sum <= total);
+        assert!(total - claimable_sum <= 3 * (holders.len() as u64 + 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_then_remove_is_value_neutral() -> Result<()> {
+        // (sol_leg_available_balance, msol_leg_balance, lp_supply, mSOL price as total_staked/msol_supply)
+        let pools: [(u64, u64, u64, (u64, u64)); 6] = [
+            (0, 0, 0, (1, 1)),                                         // first deposit
+            (1_000_000_000, 0, 1_000_000_000, (1, 1)),                 // SOL only
+            (10_000_000_000, 5_000_000_000, 12_000_000_000, (11, 10)), // LP worth more than 1 SOL
+            (
+                3_000_000_000,
+                7_000_000_000,
+                9_000_000_000,
+                (123_456, 100_000),
+            ),
+            (1_000, 1_000, 7, (3, 2)), // tiny pool, expensive LP
+            (50_000_000_000_000, 1, 49_999_000_000_000, (1, 1)), // large pool
+        ];
+        let amounts = [1, 999, 1_000_000, 1_000_000_000, 123_456_789_012];
+        for (sol, msol, lp_supply, (staked, msol_supply)) in pools {
+            let msol_to_sol = |msol_amount| proportional(msol_amount, staked, msol_supply);
+            let pool_value = sol + msol_to_sol(msol)?;
+            for amount in amounts {
+                // add_liquidity
+                let shares = shares_from_value(amount, pool_value, lp_supply)?;
+                // immediate remove_liquidity of everything received
+                let breakdown = RemoveLiquidityBreakdown::from_legs(
+                    shares,
+                    lp_supply + shares,
+                    sol + amount,
+                    msol,
+                    1,
+                    msol_to_sol,
+                )?;
+                let returned = breakdown.total_out_value();
+                // there is no add/remove fee, only rounding: never more than deposited,
+                // at most the value of one LP unit per rounding step below it
+                let lp_unit_value = pool_value.checked_div(lp_supply).map_or(1, |v| v + 1);
+                assert!(
+                    returned <= amount,
+                    "returned {} > added {} on pool {:?}",
+                    returned,
+                    amount,
+                    (sol, msol, lp_supply)
+                );
+                assert!(
+                    amount - returned <= lp_unit_value + 2,
+                    "lost {} adding {} on pool {:?}",
+                    amount - returned,
+                    amount,
+                    (sol, msol, lp_supply)
+                );
+            }
+        }
         Ok(())
     }
 }
+
+// every guard of remove_liquidity with the smallest fixture change that trips it
+#[cfg(test)]
+mod error_paths {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use anchor_lang::{
+        error::ErrorCode,
+        prelude::*,
+        solana_program::{program_option::COption, program_pack::Pack},
+        system_program,
+    };
+    use anchor_spl::token::spl_token;
+
+    use super::{RemoveLiquidity, RemoveLiquidityBreakdown, RemoveLiquidityChecks};
+    use crate::{
+        error::MarinadeError,
+        instructions::SimulateRemoveBreakdown,
+        state::{liq_pool::LiqPool, pause_flags::PauseFlags},
+        State, ID,
+    };
+
+    const STATE: usize = 0;
+    const LP_MINT: usize = 1;
+    const BURN_FROM: usize = 2;
+    const BURN_FROM_AUTHORITY: usize = 3;
+    const TRANSFER_MSOL_TO: usize = 5;
+    const SOL_LEG: usize = 6;
+    const MSOL_LEG: usize = 7;
+    const MSOL_LEG_AUTHORITY: usize = 8;
+    const TOKEN_PROGRAM: usize = 10;
+    const TREASURY: usize = 11;
+
+    const RENT: u64 = 2_039_280;
+    const LP_SUPPLY: u64 = 1_000_000_000;
+    const USER_LP: u64 = 100_000_000;
+    // USER_LP is 10% of the legs
+    const SOL_OUT: u64 = 1_000_000_000;
+    const MSOL_OUT: u64 = 500_000_000;
+
+    struct TestAccount {
+        key: Pubkey,
+        is_signer: bool,
+        lamports: u64,
+        data: Vec<u8>,
+        owner: Pubkey,
+        executable: bool,
+    }
+
+    impl TestAccount {
+        fn new(key: Pubkey, lamports: u64, data: Vec<u8>, owner: Pubkey) -> Self {
+            Self {
+                key,
+                is_signer: false,
+                lamports,
+                data,
+                owner,
+                executable: false,
+            }
+        }
+
+        fn program(key: Pubkey) -> Self {
+            Self {
+                executable: true,
+                ..Self::new(key, 1, vec![], Pubkey::default())
+            }
+        }
+
+        fn token(key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) -> Self {
+            let mut data = vec![0; spl_token::state::Account::LEN];
+            spl_token::state::Account {
+                mint,
+                owner,
+                amount,
+                state: spl_token::state::AccountState::Initialized,
+                ..Default::default()
+            }
+            .pack_into_slice(&mut data);
+            Self::new(key, RENT, data, spl_token::ID)
+        }
+
+        fn mint(key: Pubkey, supply: u64) -> Self {
+            let mut data = vec![0; spl_token::state::Mint::LEN];
+            spl_token::state::Mint {
+                mint_authority: COption::Some(Pubkey::new_unique()),
+                supply,
+                decimals: 9,
+                is_initialized: true,
+                freeze_authority: COption::None,
+            }
+            .pack_into_slice(&mut data);
+            Self::new(key, RENT, data, spl_token::ID)
+        }
+    }
+
+    struct Fixture {
+        accounts: Vec<TestAccount>, // in RemoveLiquidity order
+        tokens: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let state_address = Pubkey::new_unique();
+            let user = Pubkey::new_unique();
+            let msol_mint = Pubkey::new_unique();
+            let (sol_leg, sol_leg_bump_seed) = LiqPool::find_sol_leg_address(&state_address);
+            let (msol_leg_authority, msol_leg_authority_bump_seed) =
+                LiqPool::find_msol_leg_authority(&state_address);
+
+            let mut state =
+                State::try_deserialize_unchecked(&mut &vec![0; State::serialized_len()][..])
+                    .unwrap();
+            state.msol_mint = msol_mint;
+            state.treasury_msol_account = Pubkey::new_unique();
+            state.rent_exempt_for_token_acc = RENT;
+            state.min_withdraw = 1;
+            state.liq_pool.lp_mint = Pubkey::new_unique();
+            state.liq_pool.msol_leg = Pubkey::new_unique();
+            state.liq_pool.sol_leg_bump_seed = sol_leg_bump_seed;
+            state.liq_pool.msol_leg_authority_bump_seed = msol_leg_authority_bump_seed;
+            state.liq_pool.lp_supply = LP_SUPPLY;
+
+            let mut fixture = Self {
+                accounts: vec![
+                    TestAccount::new(state_address, 1, vec![], ID),
+                    TestAccount::mint(state.liq_pool.lp_mint, LP_SUPPLY),
+                    TestAccount::token(Pubkey::new_unique(), state.liq_pool.lp_mint, user, USER_LP),
+                    TestAccount {
+                        is_signer: true,
+                        ..TestAccount::new(user, 1, vec![], system_program::ID)
+                    },
+                    TestAccount::new(user, 1, vec![], system_program::ID),
+                    TestAccount::token(Pubkey::new_unique(), msol_mint, user, 0),
+                    TestAccount::new(sol_leg, RENT + 10_000_000_000, vec![], system_program::ID),
+                    TestAccount::token(
+                        state.liq_pool.msol_leg,
+                        msol_mint,
+                        msol_leg_authority,
+                        5_000_000_000,
+                    ),
+                    TestAccount::new(msol_leg_authority, 0, vec![], system_program::ID),
+                    TestAccount::program(system_program::ID),
+                    TestAccount::program(spl_token::ID),
+                    TestAccount::token(state.treasury_msol_account, msol_mint, user, 0),
+                ],
+                tokens: USER_LP,
+                min_sol_out: 0,
+                min_msol_out: 0,
+            };
+            fixture.set_state(|s| *s = state);
+            fixture
+        }
+
+        fn state(&self) -> State {
+            State::try_deserialize(&mut &self.accounts[STATE].data[..]).unwrap()
+        }
+
+        fn set_state(&mut self, update: impl FnOnce(&mut State)) {
+            let mut state = if self.accounts[STATE].data.is_empty() {
+                State::try_deserialize_unchecked(&mut &vec![0; State::serialized_len()][..])
+                    .unwrap()
+            } else {
+                self.state()
+            };
+            update(&mut state);
+            let mut data = vec![];
+            state.try_serialize(&mut data).unwrap();
+            self.accounts[STATE].data = data;
+        }
+
+        fn snapshot(&self) -> Vec<(u64, Vec<u8>)> {
+            self.accounts
+                .iter()
+                .map(|a| (a.lamports, a.data.clone()))
+                .collect()
+        }
+
+        fn run(&mut self) -> Result<RemoveLiquidityBreakdown> {
+            self.run_with(|_| {})
+        }
+
+        // `before_process` sees the accounts after anchor deserialized them,
+        // like an earlier instruction of the same transaction
+        fn run_with(
+            &mut self,
+            before_process: impl FnOnce(&[AccountInfo]),
+        ) -> Result<RemoveLiquidityBreakdown> {
+            let (tokens, min_sol_out, min_msol_out) =
+                (self.tokens, self.min_sol_out, self.min_msol_out);
+            let infos: Vec<AccountInfo> = self
+                .accounts
+                .iter_mut()
+                .map(|a| {
+                    AccountInfo::new(
+                        &a.key,
+                        a.is_signer,
+                        true,
+                        &mut a.lamports,
+                        &mut a.data,
+                        &a.owner,
+                        a.executable,
+                        0,
+                    )
+                })
+                .collect();
+            let mut accounts = RemoveLiquidity::try_accounts(
+                &ID,
+                &mut &infos[..],
+                &[],
+                &mut BTreeMap::new(),
+                &mut BTreeSet::new(),
+            )?;
+            before_process(&infos);
+            let breakdown = accounts.process_with_min_out(tokens, min_sol_out, min_msol_out)?;
+            accounts.exit(&ID)?;
+            Ok(breakdown)
+        }
+
+        fn simulate(&mut self) -> Result<RemoveLiquidityBreakdown> {
+            let (tokens, min_sol_out, min_msol_out) =
+                (self.tokens, self.min_sol_out, self.min_msol_out);
+            let infos: Vec<AccountInfo> = self
+                .accounts
+                .iter_mut()
+                .enumerate()
+                .filter(|(index, _)| [STATE, LP_MINT, SOL_LEG, MSOL_LEG].contains(index))
+                .map(|(_, a)| {
+                    AccountInfo::new(
+                        &a.key,
+                        a.is_signer,
+                        false,
+                        &mut a.lamports,
+                        &mut a.data,
+                        &a.owner,
+                        a.executable,
+                        0,
+                    )
+                })
+                .collect();
+            SimulateRemoveBreakdown::try_accounts(
+                &ID,
+                &mut &infos[..],
+                &[],
+                &mut BTreeMap::new(),
+                &mut BTreeSet::new(),
+            )?
+            .process(tokens, min_sol_out, min_msol_out)
+        }
+    }
+
+    // (name, fixture change, expected error code)
+    type Case = (&'static str, fn(&mut Fixture), u32);
+    // (name, fixture change, checks it fails)
+    type ChecksCase = (
+        &'static str,
+        fn(&mut Fixture),
+        fn(&mut RemoveLiquidityChecks),
+    );
+
+    fn error_code<T: std::fmt::Debug>(result: Result<T>) -> u32 {
+        match result {
+            Err(Error::AnchorError(error)) => error.error_code_number,
+            other => panic!("expected an anchor error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_fixture_passes() {
+        let mut fixture = Fixture::new();
+        fixture.min_sol_out = SOL_OUT;
+        fixture.min_msol_out = MSOL_OUT;
+        fixture.run().unwrap();
+        // CPIs are stubbed out in unit tests, only the State bookkeeping is observable
+        assert_eq!(fixture.state().liq_pool.lp_supply, LP_SUPPLY - USER_LP);
+
+        // existing clients without the treasury account, the dust policy is off
+        let mut fixture = Fixture::new();
+        fixture.accounts.truncate(TREASURY);
+        fixture.run().unwrap();
+    }
+
+    #[test]
+    fn test_self_heal_after_prior_burn() {
+        const PRIOR_BURN: u64 = 300_000_000;
+        let mut fixture = Fixture::new();
+        let lp_mint = fixture.accounts[LP_MINT].key;
+        fixture
+            .run_with(|infos| {
+                // LP burned by a previous instruction, after anchor loaded lp_mint
+                let burnt = TestAccount::mint(lp_mint, LP_SUPPLY - PRIOR_BURN);
+                infos[LP_MINT]
+                    .data
+                    .borrow_mut()
+                    .copy_from_slice(&burnt.data);
+            })
+            .unwrap();
+        // the self-heal used the reloaded supply, not the stale LP_SUPPLY
+        assert_eq!(
+            fixture.state().liq_pool.lp_supply,
+            LP_SUPPLY - PRIOR_BURN - USER_LP
+        );
+    }
+
+    #[test]
+    fn test_simulated_breakdown_matches_removal() {
+        let mut fixture = Fixture::new();
+        fixture.min_sol_out = SOL_OUT;
+        fixture.min_msol_out = MSOL_OUT;
+        let simulated = fixture.simulate().unwrap();
+        assert_eq!(simulated.sol_out_amount, SOL_OUT);
+        assert_eq!(simulated.msol_out_amount, MSOL_OUT);
+        assert!(simulated.check(&fixture.state()).is_ok());
+
+        // the transfers are bounded from below by the min outs, so exactly the simulated amounts
+        assert_eq!(fixture.run().unwrap(), simulated);
+        for (min_sol_out, min_msol_out) in [
+            (simulated.sol_out_amount + 1, 0),
+            (0, simulated.msol_out_amount + 1),
+        ] {
+            let mut fixture = Fixture::new();
+            fixture.min_sol_out = min_sol_out;
+            fixture.min_msol_out = min_msol_out;
+            assert_eq!(
+                error_code(fixture.run()),
+                u32::from(MarinadeError::RemoveLiquidityBelowMinOut)
+            );
+        }
+    }
+
+    #[test]
+    fn test_simulation_reports_failed_checks() {
+        let cases: Vec<ChecksCase> = vec![
+            (
+                "paused",
+                |f| f.set_state(|s| s.paused = true),
+                |c| c.not_paused = false,
+            ),
+            (
+                "liquidity paused",
+                |f| f.set_state(|s| s.pause_flags = PauseFlags::LIQUIDITY),
+                |c| c.not_paused = false,
+            ),
+            (
+                "flash removal open",
+                |f| f.set_state(|s| s.flash_remove.in_progress = true),
+                |c| c.liq_pool_not_lent = false,
+            ),
+            (
+                "circuit breaker tripped",
+                |f| f.set_state(|s| s.price_circuit_breaker.tripped = true),
+                |c| c.circuit_breaker_not_tripped = false,
+            ),
+            (
+                "LP minted outside of the program",
+                |f| f.accounts[LP_MINT] = TestAccount::mint(f.accounts[LP_MINT].key, LP_SUPPLY + 1),
+                |c| c.lp_supply_registered = false,
+            ),
+            (
+                "below min_withdraw",
+                |f| f.set_state(|s| s.min_withdraw = u64::MAX),
+                |c| c.min_withdraw_passed = false,
+            ),
+            (
+                "slippage on both legs",
+                |f| {
+                    f.min_sol_out = SOL_OUT + 1;
+                    f.min_msol_out = MSOL_OUT + 1;
+                },
+                |c| {
+                    c.min_sol_out_passed = false;
+                    c.min_msol_out_passed = false;
+                },
+            ),
+        ];
+        let all_passed = RemoveLiquidityChecks {
+            not_paused: true,
+            liq_pool_not_lent: true,
+            circuit_breaker_not_tripped: true,
+            lp_supply_registered: true,
+            min_withdraw_passed: true,
+            min_sol_out_passed: true,
+            min_msol_out_passed: true,
+        };
+        assert_eq!(Fixture::new().simulate().unwrap().checks, all_passed);
+        for (name, break_fixture, failed) in cases {
+            let mut fixture = Fixture::new();
+            break_fixture(&mut fixture);
+            let simulated = fixture.simulate().unwrap();
+            let mut expected = all_passed;
+            failed(&mut expected);
+            assert_eq!(simulated.checks, expected, "{}", name);
+            // the simulation fails with the error of the removal
+            assert_eq!(
+                error_code(simulated.check(&fixture.state())),
+                error_code(fixture.run()),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_error_paths() {
+        let cases: Vec<Case> = vec![
+            (
+                "paused",
+                |f| f.set_state(|s| s.paused = true),
+                MarinadeError::ProgramIsPaused.into(),
+            ),
+            (
+                "liquidity paused",
+                |f| f.set_state(|s| s.pause_flags = PauseFlags::LIQUIDITY),
+                MarinadeError::GroupIsPaused.into(),
+            ),
+            (
+                "flash removal open",
+                |f| f.set_state(|s| s.flash_remove.in_progress = true),
+                MarinadeError::FlashRemoveInProgress.into(),
+            ),
+            (
+                "circuit breaker tripped",
+                |f| f.set_state(|s| s.price_circuit_breaker.tripped = true),
+                MarinadeError::CircuitBreakerTripped.into(),
+            ),
+            (
+                "state of another program",
+                |f| f.accounts[STATE].owner = Pubkey::new_unique(),
+                ErrorCode::AccountOwnedByWrongProgram.into(),
+            ),
+            (
+                "lp mint mismatch",
+                |f| f.accounts[LP_MINT].key = Pubkey::new_unique(),
+                ErrorCode::ConstraintAddress.into(),
+            ),
+            (
+                "burn_from of another mint",
+                |f| {
+                    let burn_from = &f.accounts[BURN_FROM];
+                    f.accounts[BURN_FROM] = TestAccount::token(
+                        burn_from.key,
+                        Pubkey::new_unique(),
+                        f.accounts[BURN_FROM_AUTHORITY].key,
+                        USER_LP,
+                    )
+                },
+                ErrorCode::ConstraintTokenMint.into(),
+            ),
+            (
+                "burn_from short of tokens",
+                |f| f.tokens = USER_LP + 1,
+                MarinadeError::NotEnoughUserFunds.into(),
+            ),
+            (
+                "burn_from authority is neither owner nor delegate",
+                |f| f.accounts[BURN_FROM_AUTHORITY].key = Pubkey::new_unique(),
+                MarinadeError::WrongTokenOwnerOrDelegate.into(),
+            ),
+            (
+                "burn_from authority did not sign",
+                |f| f.accounts[BURN_FROM_AUTHORITY].is_signer = false,
+                ErrorCode::AccountNotSigner.into(),
+            ),
+            (
+                "transfer_msol_to of another mint",
+                |f| {
+                    let transfer_msol_to = &f.accounts[TRANSFER_MSOL_TO];
+                    f.accounts[TRANSFER_MSOL_TO] = TestAccount::token(
+                        transfer_msol_to.key,
+                        Pubkey::new_unique(),
+                        f.accounts[BURN_FROM_AUTHORITY].key,
+                        0,
+                    )
+                },
+                ErrorCode::ConstraintTokenMint.into(),
+            ),
+            (
+                "sol leg is not the PDA",
+                |f| f.accounts[SOL_LEG].key = Pubkey::new_unique(),
+                ErrorCode::ConstraintSeeds.into(),
+            ),
+            (
+                "msol leg mismatch",
+                |f| f.accounts[MSOL_LEG].key = Pubkey::new_unique(),
+                ErrorCode::ConstraintAddress.into(),
+            ),
+            (
+                "msol leg authority is not the PDA",
+                |f| f.accounts[MSOL_LEG_AUTHORITY].key = Pubkey::new_unique(),
+                ErrorCode::ConstraintSeeds.into(),
+            ),
+            (
+                "treasury mismatch",
+                |f| f.accounts[TREASURY].key = Pubkey::new_unique(),
+                ErrorCode::ConstraintAddress.into(),
+            ),
+            (
+                "dust to treasury without the treasury account",
+                |f| {
+                    f.set_state(|s| s.removal_dust_to_treasury = true);
+                    f.accounts.truncate(TREASURY);
+                },
+                MarinadeError::MissingTreasuryMsolAccount.into(),
+            ),
+            (
+                "token program mismatch",
+                |f| f.accounts[TOKEN_PROGRAM].key = Pubkey::new_unique(),
+                ErrorCode::InvalidProgramId.into(),
+            ),
+            (
+                "LP minted outside of the program",
+                |f| f.accounts[LP_MINT] = TestAccount::mint(f.accounts[LP_MINT].key, LP_SUPPLY + 1),
+                MarinadeError::UnregisteredLPMinted.into(),
+            ),
+            (
+                "below min_withdraw",
+                |f| f.set_state(|s| s.min_withdraw = u64::MAX),
+                MarinadeError::WithdrawAmountIsTooLow.into(),
+            ),
+            (
+                "SOL slippage",
+                |f| f.min_sol_out = SOL_OUT + 1,
+                MarinadeError::RemoveLiquidityBelowMinOut.into(),
+            ),
+            (
+                "mSOL slippage",
+                |f| f.min_msol_out = MSOL_OUT + 1,
+                MarinadeError::RemoveLiquidityBelowMinOut.into(),
+            ),
+            (
+                "overflow",
+                |f| {
+                    let burn_from = &f.accounts[BURN_FROM];
+                    f.accounts[BURN_FROM] = TestAccount::token(
+                        burn_from.key,
+                        f.accounts[LP_MINT].key,
+                        f.accounts[BURN_FROM_AUTHORITY].key,
+                        u64::MAX,
+                    );
+                    f.tokens = u64::MAX;
+                },
+                MarinadeError::CalculationFailure.into(),
+            ),
+        ];
+
+        for (name, break_fixture, expected) in cases {
+            let mut fixture = Fixture::new();
+            break_fixture(&mut fixture);
+            let before = fixture.snapshot();
+            assert_eq!(error_code(fixture.run()), expected, "{}", name);
+            assert!(fixture.snapshot() == before, "{} mutated accounts", name);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{burn, Burn, Mint};
+
+use crate::{
+    checks::check_token_source_account, instructions::delayed_unstake::book_ticket,
+    state::delayed_unstake_ticket::TicketAccountData,
+};
+
+use super::remove_liquidity::*;
+use crate::state::pause_flags::PauseFlags;
+
+// remove_liquidity delivering the SOL part and turning the mSOL part into a delayed-unstake ticket
+#[derive(Accounts)]
+pub struct RemoveLiquidityOrderUnstake<'info> {
+    // Note: the mSOL lands in remove_liquidity.transfer_msol_to and is burned from there,
+    // burn_from_authority must be able to burn it. Ticket beneficiary is transfer_msol_to.owner
+    pub remove_liquidity: RemoveLiquidity<'info>,
+
+    #[account(
+        mut,
+        address = remove_liquidity.state.msol_mint
+    )]
+    pub msol_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        zero,
+        rent_exempt = enforce
+    )]
+    pub new_ticket_account: Box<Account<'info, TicketAccountData>>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> RemoveLiquidityOrderUnstake<'info> {
+    /// min_msol_out bounds the mSOL the ticket is ordered for
+    pub fn process(&mut self, tokens: u64, min_sol_out: u64, min_msol_out: u64) -> Result<()> {
+        // the ticket part must not go through while delayed unstakes are paused
+        self.remove_liquidity
+            .state
+            .check_not_paused(PauseFlags::DELAYED_UNSTAKE)?;
+
+        let RemoveLiquidityBreakdown {
+            msol_out_amount, ..
+        } = self
+            .remove_liquidity
+            .process_with_min_out(tokens, min_sol_out, min_msol_out)?;
+
+        let RemoveLiquidity {
+            state,
+            burn_from_authority,
+            transfer_msol_to,
+            token_program,
+            ..
+        } = &mut self.remove_liquidity;
+        transfer_msol_to.reload()?;
+        check_token_source_account(transfer_msol_to, burn_from_authority.key, msol_out_amount)
+            .map_err(|e| e.with_account_name("transfer_msol_to"))?;
+
+        book_ticket(
+            state,
+            &mut self.new_ticket_account,
+            transfer_msol_to.owner,
+            burn_from_authority.key(),
+            msol_out_amount,
+            transfer_msol_to.amount,
+            &self.clock,
+        )?;
+
+        burn(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Burn {
+                    mint: self.msol_mint.to_account_info(),
+                    from: transfer_msol_to.to_account_info(),
+                    authority: burn_from_authority.to_account_info(),
+                },
+            ),
+            msol_out_amount,
+        )?;
+        state.on_msol_burn(msol_out_amount);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{mint_to, Mint, MintTo};
+
+use crate::{
+    error::MarinadeError, events::liq_pool::RemoveLiquidityRebalanceEvent, require_lte,
+    state::liq_pool::LiqPool, State,
+};
+
+use super::remove_liquidity::*;
+use crate::state::pause_flags::PauseFlags;
+
+// remove_liquidity where the SOL part is staked into the mSOL leg instead of delivered.
+// The user gets LP back for it at the pool value, as add_liquidity would credit
+#[derive(Accounts)]
+pub struct RemoveLiquidityRebalance<'info> {
+    // Note: remove_liquidity.transfer_sol_to must sign, the SOL output goes through it into the reserve.
+    // The LP credit goes back to remove_liquidity.burn_from
+    pub remove_liquidity: RemoveLiquidity<'info>,
+
+    #[account(
+        mut,
+        address = remove_liquidity.state.msol_mint
+    )]
+    pub msol_mint: Box<Account<'info, Mint>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &remove_liquidity.state.key().to_bytes(),
+            State::MSOL_MINT_AUTHORITY_SEED
+        ],
+        bump = remove_liquidity.state.msol_mint_authority_bump_seed
+    )]
+    pub msol_mint_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            &remove_liquidity.state.key().to_bytes(),
+            State::RESERVE_SEED
+        ],
+        bump = remove_liquidity.state.reserve_bump_seed
+    )]
+    pub reserve_pda: SystemAccount<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &remove_liquidity.state.key().to_bytes(),
+            LiqPool::LP_MINT_AUTHORITY_SEED
+        ],
+        bump = remove_liquidity.state.liq_pool.lp_mint_authority_bump_seed
+    )]
+    pub lp_mint_authority: UncheckedAccount<'info>,
+}
+
+impl<'info> RemoveLiquidityRebalance<'info> {
+    pub fn process(&mut self, tokens: u64) -> Result<()> {
+        // the SOL part is deposited, it must not go through while deposits are paused
+        self.remove_liquidity
+            .state
+            .check_not_paused(PauseFlags::DEPOSITS)?;
+        require!(
+            self.remove_liquidity.transfer_sol_to.is_signer,
+            ErrorCode::AccountNotSigner
+        );
+        let RemoveLiquidityBreakdown { sol_out_amount, .. } =
+            self.remove_liquidity.process(tokens)?;
+        if sol_out_amount == 0 {
+            return Ok(());
+        }
+
+        let RemoveLiquidity {
+            state,
+            lp_mint,
+            burn_from,
+            transfer_sol_to,
+            liq_pool_sol_leg_pda,
+            liq_pool_msol_leg,
+            system_program,
+            token_program,
+            ..
+        } = &mut self.remove_liquidity;
+
+        // the pool after the removal, as add_liquidity sees it before a deposit
+        liq_pool_msol_leg.reload()?;
+        let sol_leg_balance = liq_pool_sol_leg_pda.lamports();
+        let msol_leg_balance = liq_pool_msol_leg.amount;
+        let pool_value =
+            state.liq_pool_total_claimable_lamports(sol_leg_balance, msol_leg_balance)?;
+        let sol_leg_ratio_bps = state.liq_pool_leg_ratio_bps(sol_leg_balance, msol_leg_balance)?;
+
+        // stake the SOL output into the mSOL leg, as deposit does
+        // impossible to happen check outside bug (msol mint auth is a PDA)
+        require_lte!(
+            self.msol_mint.supply,
+            state.msol_supply,
+            MarinadeError::UnregisteredMsolMinted
+        );
+        state.check_staking_cap(sol_out_amount)?;
+        let msol_minted = state.calc_msol_from_lamports(sol_out_amount)?;
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: transfer_sol_to.to_account_info(),
+                    to: self.reserve_pda.to_account_info(),
+                },
+            ),
+            sol_out_amount,
+        )?;
+        state.on_transfer_to_reserve(sol_out_amount);
+        mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                MintTo {
+                    mint: self.msol_mint.to_account_info(),
+                    to: liq_pool_msol_leg.to_account_info(),
+                    authority: self.msol_mint_authority.to_account_info(),
+                },
+                &[&[
+                    &state.key().to_bytes(),
+                    State::MSOL_MINT_AUTHORITY_SEED,
+                    &[state.msol_mint_authority_bump_seed],
+                ]],
+            ),
+            msol_minted,
+        )?;
+        state.on_msol_mint(msol_minted);
+
+        // credit LP for the value left in the pool, on top of the already burned virtual lp_supply
+        let lp_credited = LiqPool::rebalance_credit_lp(
+            state.msol_to_sol(msol_minted)?,
+            pool_value,
+            state.liq_pool.lp_supply,
+        )?;
+        mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                MintTo {
+                    mint: lp_mint.to_account_info(),
+                    to: burn_from.to_account_info(),
+                    authority: self.lp_mint_authority.to_account_info(),
+                },
+                &[&[
+                    &state.key().to_bytes(),
+                    LiqPool::LP_MINT_AUTHORITY_SEED,
+                    &[state.liq_pool.lp_mint_authority_bump_seed],
+                ]],
+            ),
+            lp_credited,
+        )?;
+        state.liq_pool.on_lp_mint(lp_credited);
+
+        emit!(RemoveLiquidityRebalanceEvent {
+            state: state.key(),
+            lp_burned: tokens,
+            sol_converted: sol_out_amount,
+            msol_minted,
+            sol_leg_ratio_bps,
+            lp_credited,
+        });
+        Ok(())
+    }
+}
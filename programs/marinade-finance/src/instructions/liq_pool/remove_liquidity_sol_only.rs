@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer as transfer_token, Transfer as TransferToken};
+
+use crate::{
+    checks::check_token_source_account,
+    error::MarinadeError,
+    events::liq_pool::{LpFeeAccruedEvent, RemoveLiquiditySolOnlyEvent},
+};
+
+use super::remove_liquidity::*;
+use crate::state::pause_flags::PauseFlags;
+
+// remove_liquidity paying everything in SOL: the mSOL part is liquid-unstaked against the
+// SOL leg left after the removal, at the liquid_unstake fee
+#[derive(Accounts)]
+pub struct RemoveLiquiditySolOnly<'info> {
+    // Note: the mSOL passes through remove_liquidity.transfer_msol_to and is taken back from there,
+    // burn_from_authority must be able to transfer it
+    pub remove_liquidity: RemoveLiquidity<'info>,
+}
+
+impl<'info> RemoveLiquiditySolOnly<'info> {
+    pub fn process(&mut self, tokens: u64) -> Result<()> {
+        // the mSOL part is liquid-unstaked, it must not go through while liquid unstakes are paused
+        self.remove_liquidity
+            .state
+            .check_not_paused(PauseFlags::LIQUID_UNSTAKE)?;
+
+        let RemoveLiquidityBreakdown {
+            sol_out_amount,
+            msol_out_amount,
+            ..
+        } = self.remove_liquidity.process(tokens)?;
+        if msol_out_amount == 0 {
+            return Ok(());
+        }
+
+        let remove_liquidity = &mut self.remove_liquidity;
+        remove_liquidity.transfer_msol_to.reload()?;
+        check_token_source_account(
+            &remove_liquidity.transfer_msol_to,
+            remove_liquidity.burn_from_authority.key,
+            msol_out_amount,
+        )
+        .map_err(|e| e.with_account_name("transfer_msol_to"))?;
+
+        let state = &remove_liquidity.state;
+        // same pricing as liquid_unstake, on the legs the removal left
+        let sol_leg_balance = remove_liquidity.liq_pool_sol_leg_pda.lamports();
+        let sol_leg_available = sol_leg_balance.saturating_sub(state.rent_exempt_for_token_acc);
+        let msol_fee = state
+            .liquid_unstake_fee(sol_leg_available, state.msol_to_sol(msol_out_amount)?)
+            .apply(msol_out_amount);
+        let swap_lamports = state.msol_to_sol(msol_out_amount - msol_fee)?;
+        require_gte!(
+            sol_leg_available,
+            swap_lamports,
+            MarinadeError::InsufficientLiquidity
+        );
+
+        // without the treasury account all the fee stays with the LPs, like an invalid treasury
+        let treasury_msol_account = remove_liquidity.treasury_msol_account.clone();
+        let treasury_msol_cut = match &treasury_msol_account {
+            Some(treasury_msol_account)
+                if state
+                    .get_treasury_msol_balance(treasury_msol_account)
+                    .is_some() =>
+            {
+                state.liq_pool.treasury_cut.apply(msol_fee)
+            }
+            _ => 0,
+        };
+
+        if swap_lamports > 0 {
+            remove_liquidity.transfer_sol_from_leg(
+                remove_liquidity.transfer_sol_to.to_account_info(),
+                swap_lamports,
+            )?;
+        }
+        remove_liquidity.transfer_msol_back(
+            remove_liquidity.liq_pool_msol_leg.to_account_info(),
+            msol_out_amount - treasury_msol_cut,
+        )?;
+        if let Some(treasury_msol_account) = treasury_msol_account {
+            if treasury_msol_cut > 0 {
+                remove_liquidity.transfer_msol_back(
+                    treasury_msol_account.to_account_info(),
+                    treasury_msol_cut,
+                )?;
+            }
+        }
+
+        let lp_msol_fee = msol_fee - treasury_msol_cut;
+        let epoch = Clock::get()?.epoch;
+        let state = &mut remove_liquidity.state;
+        let fee_lamports = state.accrue_lp_fee(lp_msol_fee, epoch)?;
+        emit!(LpFeeAccruedEvent {
+            state: state.key(),
+            epoch,
+            msol_fee: lp_msol_fee,
+            fee_lamports,
+            lp_supply: state.liq_pool.lp_supply,
+            epoch_fees: state.lp_fee_accrual.epoch_fees,
+            previous_epoch_fees: state.lp_fee_accrual.previous_epoch_fees,
+            lifetime_fees: state.lp_fee_accrual.lifetime_fees,
+            fees_per_lp: state.lp_fee_accrual.fees_per_lp,
+        });
+
+        emit!(RemoveLiquiditySolOnlyEvent {
+            state: remove_liquidity.state.key(),
+            lp_burned: tokens,
+            sol_out_amount,
+            msol_swapped: msol_out_amount,
+            msol_fee,
+            treasury_msol_cut,
+            swap_lamports,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> RemoveLiquidity<'info> {
+    // mSOL delivered to transfer_msol_to, moved on with the burn_from_authority signature
+    fn transfer_msol_back(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        transfer_token(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferToken {
+                    from: self.transfer_msol_to.to_account_info(),
+                    to,
+                    authority: self.burn_from_authority.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    error::MarinadeError, events::liq_pool::RemoveLiquidityVestedEvent,
+    state::vesting_schedule::VestingSchedule,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use super::remove_liquidity::*;
+
+// remove_liquidity locking the outputs into a linear vesting schedule
+#[derive(Accounts)]
+pub struct RemoveLiquidityVested<'info> {
+    // Note: remove_liquidity.transfer_sol_to must sign, the SOL output goes through it into new_vesting_account.
+    // remove_liquidity.transfer_msol_to holds the vested mSOL, owned by vesting_authority.
+    // Vesting beneficiary is remove_liquidity.burn_from.owner
+    pub remove_liquidity: RemoveLiquidity<'info>,
+
+    // holds the vested SOL
+    #[account(
+        zero,
+        rent_exempt = enforce
+    )]
+    pub new_vesting_account: Box<Account<'info, VestingSchedule>>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &remove_liquidity.state.key().to_bytes(),
+            VestingSchedule::ESCROW_AUTHORITY_SEED
+        ],
+        bump,
+        constraint = remove_liquidity.transfer_msol_to.owner == vesting_authority.key()
+            @ MarinadeError::InvalidVestingSchedule
+    )]
+    pub vesting_authority: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> RemoveLiquidityVested<'info> {
+    // fn remove_liquidity_vested() // burn LP and lock the outputs into a vesting schedule
+    #[allow(clippy::too_many_arguments)]
+    pub fn process(
+        &mut self,
+        tokens: u64,
+        start_slot: u64,
+        cliff_slot: u64,
+        end_slot: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+        vesting_authority_bump: u8,
+    ) -> Result<()> {
+        VestingSchedule::check_slots(start_slot, cliff_slot, end_slot)?;
+        require!(
+            self.remove_liquidity.transfer_sol_to.is_signer,
+            ErrorCode::AccountNotSigner
+        );
+
+        let beneficiary = self.remove_liquidity.burn_from.owner;
+        let RemoveLiquidityBreakdown {
+            sol_out_amount,
+            msol_out_amount,
+            ..
+        } = self
+            .remove_liquidity
+            .process_with_min_out(tokens, min_sol_out, min_msol_out)?;
+
+        if sol_out_amount > 0 {
+            transfer(
+                CpiContext::new(
+                    self.remove_liquidity.system_program.to_account_info(),
+                    Transfer {
+                        from: self.remove_liquidity.transfer_sol_to.to_account_info(),
+                        to: self.new_vesting_account.to_account_info(),
+                    },
+                ),
+                sol_out_amount,
+            )?;
+        }
+
+        let state = self.remove_liquidity.state.key();
+        self.new_vesting_account.set_inner(VestingSchedule {
+            state_address: state,
+            beneficiary,
+            msol_escrow: self.remove_liquidity.transfer_msol_to.key(),
+            escrow_authority_bump_seed: vesting_authority_bump,
+            start_slot,
+            cliff_slot,
+            end_slot,
+            total_lamports: sol_out_amount,
+            total_msol: msol_out_amount,
+            claimed_lamports: 0,
+            claimed_msol: 0,
+        });
+
+        emit!(RemoveLiquidityVestedEvent {
+            state,
+            vesting: self.new_vesting_account.key(),
+            beneficiary,
+            lp_burned: tokens,
+            sol_out_amount,
+            msol_out_amount,
+            start_slot,
+            cliff_slot,
+            end_slot,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::events::liq_pool::KeeperTipEvent;
+
+use super::remove_liquidity::*;
+
+// remove_liquidity submitted by a keeper, paid from the SOL output (capped by LiqPool::MAX_KEEPER_TIP)
+#[derive(Accounts)]
+pub struct RemoveLiquidityWithKeeperTip<'info> {
+    pub remove_liquidity: RemoveLiquidity<'info>,
+
+    #[account(mut)]
+    pub keeper: SystemAccount<'info>,
+}
+
+impl<'info> RemoveLiquidityWithKeeperTip<'info> {
+    pub fn process(&mut self, tokens: u64, tip: u64) -> Result<()> {
+        let RemoveLiquidityBreakdown { sol_out_amount, .. } = self
+            .remove_liquidity
+            .process_with_keeper_tip(tokens, &self.keeper.to_account_info(), tip)?;
+
+        emit!(KeeperTipEvent {
+            state: self.remove_liquidity.state.key(),
+            keeper: self.keeper.key(),
+            sol_out_amount,
+            tip,
+        });
+        Ok(())
+    }
+}
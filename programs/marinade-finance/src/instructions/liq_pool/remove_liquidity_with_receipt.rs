@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{create_metadata_accounts_v3, CreateMetadataAccountsV3, Metadata};
+use anchor_spl::token::{freeze_account, mint_to, FreezeAccount, Mint, MintTo, TokenAccount};
+use mpl_token_metadata::state::DataV2;
+
+use crate::{
+    checks::check_mint_empty, events::liq_pool::RemoveLiquidityReceiptEvent,
+    state::liq_pool::LiqPool,
+};
+
+use super::remove_liquidity::*;
+
+// remove_liquidity + a soulbound receipt NFT, opt-in because the receipt costs rent
+#[derive(Accounts)]
+pub struct RemoveLiquidityWithReceipt<'info> {
+    pub remove_liquidity: RemoveLiquidity<'info>,
+
+    // fresh mint created by the user, authorities given to receipt_authority
+    #[account(
+        mut,
+        mint::decimals = 0,
+        mint::authority = receipt_authority,
+        mint::freeze_authority = receipt_authority,
+    )]
+    pub receipt_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = receipt_mint,
+        token::authority = remove_liquidity.burn_from.owner,
+    )]
+    pub receipt_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: created and checked by the metadata program
+    #[account(mut)]
+    pub receipt_metadata: UncheckedAccount<'info>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &remove_liquidity.state.key().to_bytes(),
+            LiqPool::RECEIPT_AUTHORITY_SEED
+        ],
+        bump
+    )]
+    pub receipt_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> RemoveLiquidityWithReceipt<'info> {
+    pub const RECEIPT_NAME: &'static str = "mSOL-SOL-LP removal receipt";
+    pub const RECEIPT_SYMBOL: &'static str = "MLPR";
+
+    /// metadata of the receipt, the removal parameters are recorded in the uri
+    /// so any metadata reader can show them
+    pub fn receipt_data(
+        state: &Pubkey,
+        slot: u64,
+        lp_burned: u64,
+        sol_out_amount: u64,
+        msol_out_amount: u64,
+    ) -> DataV2 {
+        DataV2 {
+            name: Self::RECEIPT_NAME.to_string(),
+            symbol: Self::RECEIPT_SYMBOL.to_string(),
+            uri: format!(
+                "marinade:remove_liquidity?state={}&slot={}&lp_burned={}&sol_out={}&msol_out={}",
+                state, slot, lp_burned, sol_out_amount, msol_out_amount
+            ),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        }
+    }
+
+    pub fn process(
+        &mut self,
+        tokens: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+        receipt_authority_bump: u8,
+    ) -> Result<()> {
+        check_mint_empty(&self.receipt_mint, "receipt_mint")?;
+
+        let RemoveLiquidityBreakdown {
+            sol_out_amount,
+            msol_out_amount,
+            ..
+        } = self
+            .remove_liquidity
+            .process_with_min_out(tokens, min_sol_out, min_msol_out)?;
+
+        let state = self.remove_liquidity.state.key();
+        let receipt_authority_seeds: &[&[u8]] = &[
+            &state.to_bytes(),
+            LiqPool::RECEIPT_AUTHORITY_SEED,
+            &[receipt_authority_bump],
+        ];
+        let token_program = self.remove_liquidity.token_program.to_account_info();
+
+        mint_to(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                MintTo {
+                    mint: self.receipt_mint.to_account_info(),
+                    to: self.receipt_token_account.to_account_info(),
+                    authority: self.receipt_authority.to_account_info(),
+                },
+                &[receipt_authority_seeds],
+            ),
+            1,
+        )?;
+        // soulbound: the receipt can not leave the owner's account
+        freeze_account(CpiContext::new_with_signer(
+            token_program,
+            FreezeAccount {
+                account: self.receipt_token_account.to_account_info(),
+                mint: self.receipt_mint.to_account_info(),
+                authority: self.receipt_authority.to_account_info(),
+            },
+            &[receipt_authority_seeds],
+        ))?;
+
+        create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                self.metadata_program.to_account_info(),
+                CreateMetadataAccountsV3 {
+                    metadata: self.receipt_metadata.to_account_info(),
+                    mint: self.receipt_mint.to_account_info(),
+                    mint_authority: self.receipt_authority.to_account_info(),
+                    payer: self.rent_payer.to_account_info(),
+                    update_authority: self.receipt_authority.to_account_info(),
+                    system_program: self.remove_liquidity.system_program.to_account_info(),
+                    rent: self.rent.to_account_info(),
+                },
+                &[receipt_authority_seeds],
+            ),
+            Self::receipt_data(
+                &state,
+                self.clock.slot,
+                tokens,
+                sol_out_amount,
+                msol_out_amount,
+            ),
+            false, // immutable receipt
+            true,
+            None,
+        )?;
+
+        emit!(RemoveLiquidityReceiptEvent {
+            state,
+            receipt_mint: self.receipt_mint.key(),
+            owner: self.receipt_token_account.owner,
+            slot: self.clock.slot,
+            lp_burned: tokens,
+            sol_out_amount,
+            msol_out_amount,
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+    use mpl_token_metadata::state::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
+
+    use super::RemoveLiquidityWithReceipt;
+
+    #[test]
+    fn test_receipt_data() {
+        let state = Pubkey::new_unique();
+        let data = RemoveLiquidityWithReceipt::receipt_data(&state, 123, 1_000, 2_000_000, 500_000);
+        assert_eq!(data.name, RemoveLiquidityWithReceipt::RECEIPT_NAME);
+        assert_eq!(data.symbol, RemoveLiquidityWithReceipt::RECEIPT_SYMBOL);
+        assert_eq!(data.seller_fee_basis_points, 0);
+        assert!(data.creators.is_none());
+
+        // the removal parameters can be read back from the uri
+        let (scheme, query) = data.uri.split_once('?').unwrap();
+        assert_eq!(scheme, "marinade:remove_liquidity");
+        let params: Vec<(&str, &str)> = query
+            .split('&')
+            .map(|param| param.split_once('=').unwrap())
+            .collect();
+        assert_eq!(
+            params,
+            vec![
+                ("state", state.to_string().as_str()),
+                ("slot", "123"),
+                ("lp_burned", "1000"),
+                ("sol_out", "2000000"),
+                ("msol_out", "500000"),
+            ]
+        );
+
+        // within the metadata program limits, whatever the amounts
+        let largest = RemoveLiquidityWithReceipt::receipt_data(
+            &state,
+            u64::MAX,
+            u64::MAX,
+            u64::MAX,
+            u64::MAX,
+        );
+        assert!(largest.name.len() <= MAX_NAME_LENGTH);
+        assert!(largest.symbol.len() <= MAX_SYMBOL_LENGTH);
+        assert!(largest.uri.len() <= MAX_URI_LENGTH);
+    }
+}
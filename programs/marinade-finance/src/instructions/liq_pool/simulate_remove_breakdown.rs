@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::{state::liq_pool::LiqPool, State};
+
+use super::RemoveLiquidityBreakdown;
+
+// read-only view, the result is delivered as return data
+#[derive(Accounts)]
+pub struct SimulateRemoveBreakdown<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(address = state.liq_pool.lp_mint)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::SOL_LEG_SEED
+        ],
+        bump = state.liq_pool.sol_leg_bump_seed
+    )]
+    pub liq_pool_sol_leg_pda: SystemAccount<'info>,
+    #[account(address = state.liq_pool.msol_leg)]
+    pub liq_pool_msol_leg: Box<Account<'info, TokenAccount>>,
+}
+
+impl<'info> SimulateRemoveBreakdown<'info> {
+    /// the removal remove_liquidity would make with these min outs,
+    /// breakdown.checks tells which of its checks would fail
+    pub fn process(
+        &self,
+        tokens: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+    ) -> Result<RemoveLiquidityBreakdown> {
+        self.breakdown(tokens, min_sol_out, min_msol_out)
+    }
+
+    pub fn estimate_slippage_bps(&self, tokens: u64) -> Result<u16> {
+        let slippage_bps = self.breakdown(tokens, 0, 0)?.slippage_bps()?;
+        msg!("removal slippage bps:{}", slippage_bps);
+        Ok(slippage_bps)
+    }
+
+    pub fn total_claimable_lamports(&self) -> Result<u64> {
+        let total = self.state.liq_pool_total_claimable_lamports(
+            self.liq_pool_sol_leg_pda.lamports(),
+            self.liq_pool_msol_leg.amount,
+        )?;
+        msg!("liq pool total claimable lamports:{}", total);
+        Ok(total)
+    }
+
+    fn breakdown(
+        &self,
+        tokens: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+    ) -> Result<RemoveLiquidityBreakdown> {
+        // same evaluation as remove_liquidity, without writing the lp_supply self-heal back
+        RemoveLiquidityBreakdown::evaluate(
+            &self.state,
+            tokens,
+            self.lp_mint.supply,
+            self.liq_pool_sol_leg_pda.lamports(),
+            self.liq_pool_msol_leg.amount,
+            min_sol_out,
+            min_msol_out,
+        )
+    }
+}
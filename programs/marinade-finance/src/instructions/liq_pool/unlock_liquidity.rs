@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+use crate::{
+    error::MarinadeError,
+    events::liq_pool::UnlockLiquidityEvent,
+    state::locked_lp::{LockedLp, LockedLpPosition},
+    State,
+};
+
+use super::pay_locked_lp_boost;
+use crate::state::pause_flags::PauseFlags;
+
+#[derive(Accounts)]
+pub struct UnlockLiquidity<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = lp_escrow,
+        constraint = position.state_address == state.key()
+            @ MarinadeError::InvalidLockedLpPosition,
+        close = owner
+    )]
+    pub position: Box<Account<'info, LockedLpPosition>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub lp_escrow: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = state.liq_pool.lp_mint
+    )]
+    pub transfer_lp_to: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = state.locked_lp.boost_vault
+    )]
+    pub boost_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LockedLp::AUTHORITY_SEED
+        ],
+        bump = state.locked_lp.authority_bump_seed
+    )]
+    pub locked_lp_authority: UncheckedAccount<'info>,
+    // receives the boost earned since the last payout
+    #[account(
+        mut,
+        token::mint = state.msol_mint
+    )]
+    pub transfer_msol_to: Box<Account<'info, TokenAccount>>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> UnlockLiquidity<'info> {
+    // fn unlock_liquidity() // return the LP tokens and the last boost once unlock_slot is reached
+    pub fn process(&mut self) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::LIQUIDITY)?;
+        require_gte!(
+            self.clock.slot,
+            self.position.unlock_slot,
+            MarinadeError::LockNotExpired
+        );
+
+        let boost = self.position.take_boost(&self.state.locked_lp);
+        pay_locked_lp_boost(
+            &self.state,
+            &self.token_program,
+            &self.boost_vault,
+            &self.locked_lp_authority,
+            &self.transfer_msol_to,
+            boost,
+        )?;
+
+        let lp_amount = self.position.lp_amount;
+        transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.lp_escrow.to_account_info(),
+                    to: self.transfer_lp_to.to_account_info(),
+                    authority: self.locked_lp_authority.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    LockedLp::AUTHORITY_SEED,
+                    &[self.state.locked_lp.authority_bump_seed],
+                ]],
+            ),
+            lp_amount,
+        )?;
+        self.state.locked_lp.total_weight -= self.position.weight;
+
+        emit!(UnlockLiquidityEvent {
+            state: self.state.key(),
+            position: self.position.key(),
+            owner: self.owner.key(),
+            lp_amount,
+            boost_msol: boost,
+            total_weight: self.state.locked_lp.total_weight,
+        });
+        Ok(())
+    }
+}
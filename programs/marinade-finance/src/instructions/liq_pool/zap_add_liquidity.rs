@@ -0,0 +1,251 @@
+use crate::calc::{proportional, shares_from_value, value_from_shares};
+use crate::error::MarinadeError;
+use crate::events::liq_pool::ZapAddLiquidityEvent;
+use crate::state::pause_flags::PauseFlags;
+use crate::state::{liq_pool::LiqPool, lp_cap_bypass::LpCapBypass};
+use crate::{require_lte, State};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ZapAddLiquidity<'info> {
+    #[account(
+        mut,
+        has_one = msol_mint
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    #[account(mut)]
+    pub msol_mint: Box<Account<'info, Mint>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            State::MSOL_MINT_AUTHORITY_SEED
+        ],
+        bump = state.msol_mint_authority_bump_seed
+    )]
+    pub msol_mint_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            State::RESERVE_SEED
+        ],
+        bump = state.reserve_bump_seed
+    )]
+    pub reserve_pda: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        address = state.liq_pool.lp_mint
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::LP_MINT_AUTHORITY_SEED
+        ],
+        bump = state.liq_pool.lp_mint_authority_bump_seed
+    )]
+    pub lp_mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = state.liq_pool.msol_leg
+    )]
+    pub liq_pool_msol_leg: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            LiqPool::SOL_LEG_SEED
+        ],
+        bump = state.liq_pool.sol_leg_bump_seed
+    )]
+    pub liq_pool_sol_leg_pda: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    pub transfer_from: Signer<'info>,
+
+    // user SPL-Token account to send the newly minted LP tokens
+    #[account(
+        mut,
+        token::mint = state.liq_pool.lp_mint
+    )]
+    pub mint_to: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+
+    // admin exemption of mint_to.owner from the liq-pool deposit caps
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            LpCapBypass::SEED,
+            &mint_to.owner.to_bytes()
+        ],
+        bump
+    )]
+    pub lp_cap_bypass: Option<Box<Account<'info, LpCapBypass>>>,
+}
+
+impl<'info> ZapAddLiquidity<'info> {
+    /// add_liquidity at the current leg ratio: the mSOL part of the lamports is staked
+    /// and its mSOL minted straight into the mSOL leg, the rest goes to the SOL leg
+    // fn zap_add_liquidity()
+    pub fn process(&mut self, lamports: u64) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::LIQUIDITY)?;
+        self.state.check_liq_pool_not_lent()?;
+
+        require_gte!(
+            lamports,
+            self.state.min_deposit,
+            MarinadeError::DepositAmountIsTooLow
+        );
+        let user_sol_balance = self.transfer_from.lamports();
+        require_gte!(
+            user_sol_balance,
+            lamports,
+            MarinadeError::NotEnoughUserFunds
+        );
+
+        // same as add_liquidity
+        require_lte!(
+            self.lp_mint.supply,
+            self.state.liq_pool.lp_supply,
+            MarinadeError::UnregisteredLPMinted
+        );
+        self.state.liq_pool.lp_supply = self.lp_mint.supply;
+        // save msol price source
+        let total_virtual_staked_lamports = self.state.total_virtual_staked_lamports();
+        let msol_supply = self.state.msol_supply;
+
+        // compute current liq-pool total value BEFORE adding user's deposit
+        let sol_leg_balance = self.liq_pool_sol_leg_pda.lamports();
+        let sol_leg_available_balance = sol_leg_balance - self.state.rent_exempt_for_token_acc;
+        let msol_leg_balance = self.liq_pool_msol_leg.amount;
+        let msol_leg_value = self.state.msol_to_sol(msol_leg_balance)?;
+        let total_liq_pool_value =
+            LiqPool::total_claimable_lamports(sol_leg_available_balance, msol_leg_value)?;
+
+        let lamports_to_stake = if total_liq_pool_value > 0 {
+            proportional(lamports, msol_leg_value, total_liq_pool_value)?
+        } else {
+            0
+        };
+        let lamports_to_sol_leg = lamports - lamports_to_stake;
+        let msol_to_mint = self.state.calc_msol_from_lamports(lamports_to_stake)?;
+        // what the user adds to the pool, rounded in the pool favor
+        let value_added = lamports_to_sol_leg + self.state.msol_to_sol(msol_to_mint)?;
+
+        self.state.check_staking_cap(lamports_to_stake)?;
+        let capped = self.lp_cap_bypass.is_none();
+        if capped {
+            self.state
+                .liq_pool
+                .check_liquidity_cap(lamports_to_sol_leg, sol_leg_balance)?;
+        }
+
+        let lp_supply = self.state.liq_pool.lp_supply;
+        let shares_for_user = shares_from_value(value_added, total_liq_pool_value, lp_supply)?;
+        msg!("LP for user {}", shares_for_user);
+
+        let user_lp_balance = self.mint_to.amount;
+        if capped {
+            self.state.check_lp_account_cap(
+                value_from_shares(user_lp_balance, total_liq_pool_value, lp_supply)? + value_added,
+            )?;
+        }
+
+        if lamports_to_sol_leg > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.transfer_from.to_account_info(),
+                        to: self.liq_pool_sol_leg_pda.to_account_info(),
+                    },
+                ),
+                lamports_to_sol_leg,
+            )?;
+        }
+
+        if lamports_to_stake > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.transfer_from.to_account_info(),
+                        to: self.reserve_pda.to_account_info(),
+                    },
+                ),
+                lamports_to_stake,
+            )?;
+            self.state.on_transfer_to_reserve(lamports_to_stake);
+        }
+
+        if msol_to_mint > 0 {
+            mint_to(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    MintTo {
+                        mint: self.msol_mint.to_account_info(),
+                        to: self.liq_pool_msol_leg.to_account_info(),
+                        authority: self.msol_mint_authority.to_account_info(),
+                    },
+                    &[&[
+                        &self.state.key().to_bytes(),
+                        State::MSOL_MINT_AUTHORITY_SEED,
+                        &[self.state.msol_mint_authority_bump_seed],
+                    ]],
+                ),
+                msol_to_mint,
+            )?;
+            self.state.on_msol_mint(msol_to_mint);
+        }
+
+        mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: self.lp_mint.to_account_info(),
+                    to: self.mint_to.to_account_info(),
+                    authority: self.lp_mint_authority.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    LiqPool::LP_MINT_AUTHORITY_SEED,
+                    &[self.state.liq_pool.lp_mint_authority_bump_seed],
+                ]],
+            ),
+            shares_for_user,
+        )?;
+        self.state.liq_pool.on_lp_mint(shares_for_user);
+
+        emit!(ZapAddLiquidityEvent {
+            state: self.state.key(),
+            sol_owner: self.transfer_from.key(),
+            user_sol_balance,
+            user_lp_balance,
+            sol_leg_balance,
+            msol_leg_balance,
+            lp_supply,
+            sol_added_amount: lamports_to_sol_leg,
+            sol_staked_amount: lamports_to_stake,
+            msol_minted: msol_to_mint,
+            lp_minted: shares_for_user,
+            // msol price components
+            total_virtual_staked_lamports,
+            msol_supply,
+        });
+
+        Ok(())
+    }
+}
@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
 
+use crate::checks::check_feature_account;
 use crate::events::management::AddValidatorEvent;
-use crate::state::validator_system::{ValidatorList, ValidatorRecord};
-use crate::{error::MarinadeError, State};
+use crate::state::pause_flags::PauseFlags;
+use crate::state::{
+    validator_blacklist::BlacklistedValidator,
+    validator_system::{ValidatorList, ValidatorRecord},
+};
+use crate::State;
 
 #[derive(Accounts)]
 pub struct AddValidator<'info> {
@@ -44,11 +49,30 @@ pub struct AddValidator<'info> {
     pub rent: Sysvar<'info, Rent>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: must not be initialized, see BlacklistedValidator
+    /// Required while any validator is blacklisted
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            BlacklistedValidator::SEED,
+            &validator_vote.key().to_bytes(),
+        ],
+        bump,
+    )]
+    pub blacklisted_validator: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> AddValidator<'info> {
     pub fn process(&mut self, score: u32) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
+
+        check_feature_account(
+            &self.blacklisted_validator,
+            self.state.has_blacklisted_validators(),
+            "blacklisted_validator",
+        )?;
+        BlacklistedValidator::check_not_blacklisted(self.blacklisted_validator.as_deref())?;
 
         msg!("Add validator {}", self.validator_vote.key);
 
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{system_program, vote};
+
+use crate::checks::check_feature_account;
+use crate::events::management::AddValidatorEvent;
+use crate::state::pause_flags::PauseFlags;
+use crate::state::{
+    validator_blacklist::BlacklistedValidator,
+    validator_criteria::VoteAccountSummary,
+    validator_system::{ValidatorList, ValidatorRecord},
+};
+use crate::{error::MarinadeError, State};
+
+// add_validator without the manager, for vote accounts meeting state.validator_criteria
+#[derive(Accounts)]
+pub struct AddValidatorPermissionless<'info> {
+    #[account(mut)]
+    pub state: Account<'info, State>,
+    #[account(
+        mut,
+        address = state.validator_system.validator_list.account,
+    )]
+    pub validator_list: Account<'info, ValidatorList>,
+
+    /// CHECK: parsed by VoteAccountSummary
+    #[account(owner = vote::program::ID @ MarinadeError::InvalidVoteAccount)]
+    pub validator_vote: UncheckedAccount<'info>,
+
+    /// CHECK: no discriminator used
+    /// by initializing this account we mark the validator as added
+    #[account(
+        init, // will ensure it is system account
+        payer = rent_payer,
+        space = 0,
+        seeds = [
+            &state.key().to_bytes(),
+            ValidatorRecord::DUPLICATE_FLAG_SEED,
+            &validator_vote.key().to_bytes(),
+        ],
+        bump,
+    )]
+    pub duplication_flag: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: must not be initialized, see BlacklistedValidator
+    /// Required while any validator is blacklisted
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            BlacklistedValidator::SEED,
+            &validator_vote.key().to_bytes(),
+        ],
+        bump,
+    )]
+    pub blacklisted_validator: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> AddValidatorPermissionless<'info> {
+    // fn add_validator_permissionless()
+    pub fn process(&mut self) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
+        let criteria = self.state.validator_criteria;
+        require_gt!(
+            criteria.permissionless_score,
+            0,
+            MarinadeError::PermissionlessAddDisabled
+        );
+
+        let vote = VoteAccountSummary::load(&self.validator_vote)?;
+        criteria.check(&vote, self.clock.slot)?;
+
+        check_feature_account(
+            &self.blacklisted_validator,
+            self.state.has_blacklisted_validators(),
+            "blacklisted_validator",
+        )?;
+        BlacklistedValidator::check_not_blacklisted(self.blacklisted_validator.as_deref())?;
+
+        msg!("Add validator {}", self.validator_vote.key);
+
+        let state_address = self.state.key();
+        self.state.validator_system.add(
+            &mut self.validator_list.to_account_info().data.borrow_mut(),
+            self.validator_vote.key(),
+            criteria.permissionless_score,
+            &state_address,
+            self.duplication_flag.key,
+        )?;
+
+        emit!(AddValidatorEvent {
+            state: self.state.key(),
+            validator: self.validator_vote.key(),
+            index: self.state.validator_system.validator_count() - 1,
+            score: criteria.permissionless_score,
+        });
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     checks::check_stake_amount_and_validator,
     error::MarinadeError,
+    events::management::EmergencyUnstakeEvent,
     state::{
         stake_system::{StakeList, StakeSystem},
         validator_system::ValidatorList,
@@ -8,6 +9,7 @@ use crate::{
     State,
 };
 
+use crate::state::pause_flags::PauseFlags;
 use anchor_lang::prelude::*;
 use anchor_spl::stake::{deactivate_stake, DeactivateStake, Stake, StakeAccount};
 
@@ -49,7 +51,7 @@ pub struct EmergencyUnstake<'info> {
 
 impl<'info> EmergencyUnstake<'info> {
     pub fn process(&mut self, stake_index: u32, validator_index: u32) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
 
         let mut stake = self.state.stake_system.get_checked(
             &self.stake_list.to_account_info().data.as_ref().borrow(),
@@ -77,6 +79,8 @@ impl<'info> EmergencyUnstake<'info> {
         )?;
 
         let unstake_amount = stake.last_update_delegated_lamports;
+        let stake_account = stake.stake_account;
+        let validator_vote = validator.validator_account;
         self.state.on_stake_moved(unstake_amount, &self.clock)?;
         msg!("Deactivate whole stake {}", stake.stake_account);
         deactivate_stake(CpiContext::new_with_signer(
@@ -126,6 +130,15 @@ impl<'info> EmergencyUnstake<'info> {
             validator,
         )?;
 
+        emit!(EmergencyUnstakeEvent {
+            state: self.state.key(),
+            epoch: self.clock.epoch,
+            stake_index,
+            stake_account,
+            validator_index,
+            validator_vote,
+            unstake_amount,
+        });
         Ok(())
     }
 }
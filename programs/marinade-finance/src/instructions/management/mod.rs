@@ -1,11 +1,23 @@
 pub mod add_validator;
+pub mod add_validator_permissionless;
 pub mod emergency_unstake;
 pub mod partial_unstake;
+pub mod protect_validator;
 pub mod remove_validator;
+pub mod reset_directed_stake;
 pub mod set_validator_score;
+pub mod set_validator_scores_bulk;
+pub mod unprotect_validator;
+pub mod update_oracle_scores;
 
 pub use add_validator::*;
+pub use add_validator_permissionless::*;
 pub use emergency_unstake::*;
 pub use partial_unstake::*;
+pub use protect_validator::*;
 pub use remove_validator::*;
+pub use reset_directed_stake::*;
 pub use set_validator_score::*;
+pub use set_validator_scores_bulk::*;
+pub use unprotect_validator::*;
+pub use update_oracle_scores::*;
@@ -1,6 +1,7 @@
 use crate::{
     checks::check_stake_amount_and_validator,
     error::MarinadeError,
+    events::management::PartialUnstakeEvent,
     state::{
         stake_system::{StakeList, StakeSystem},
         validator_system::ValidatorList,
@@ -9,6 +10,7 @@ use crate::{
 };
 use std::convert::TryFrom;
 
+use crate::state::pause_flags::PauseFlags;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{
     program::invoke_signed, stake, stake::state::StakeState, system_program,
@@ -86,7 +88,7 @@ impl<'info> PartialUnstake<'info> {
         validator_index: u32,
         desired_unstake_amount: u64,
     ) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
 
         assert!(
             desired_unstake_amount >= self.state.stake_system.min_stake,
@@ -155,7 +157,10 @@ impl<'info> PartialUnstake<'info> {
             .last_update_delegated_lamports
             .saturating_sub(unstake_amount);
 
-        let unstaked_from_account = if stake_account_after < self.state.stake_system.min_stake {
+        let stake_account = stake.stake_account;
+        let validator_vote = validator.validator_account;
+        let whole_stake = stake_account_after < self.state.stake_system.min_stake;
+        let unstaked_from_account = if whole_stake {
             // unstake all if what will remain in the account is < min_stake
             msg!("Deactivate whole stake {}", stake.stake_account);
 
@@ -271,6 +276,22 @@ impl<'info> PartialUnstake<'info> {
             validator,
         )?;
 
+        emit!(PartialUnstakeEvent {
+            state: self.state.key(),
+            epoch: self.clock.epoch,
+            stake_index,
+            stake_account,
+            validator_index,
+            validator_vote,
+            desired_unstake_amount,
+            validator_stake_target,
+            unstake_amount: unstaked_from_account,
+            split_stake_account: if whole_stake {
+                None
+            } else {
+                Some(self.split_stake_account.key())
+            },
+        });
         Ok(())
     }
 
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError, events::management::ProtectValidatorEvent,
+    state::protected_validator::ProtectedValidator, State,
+};
+
+#[derive(Accounts)]
+#[instruction(validator_vote: Pubkey)]
+pub struct ProtectValidator<'info> {
+    #[account(mut)]
+    pub state: Account<'info, State>,
+    #[account(
+        address = state.validator_system.manager_authority
+            @ MarinadeError::InvalidValidatorManager
+    )]
+    pub manager_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<ProtectedValidator>(),
+        seeds = [
+            &state.key().to_bytes(),
+            ProtectedValidator::SEED,
+            &validator_vote.to_bytes()
+        ],
+        bump
+    )]
+    pub protected_validator: Account<'info, ProtectedValidator>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProtectValidator<'info> {
+    // fn protect_validator()
+    pub fn process(&mut self, validator_vote: Pubkey) -> Result<()> {
+        self.state.protected_validator_count += 1;
+        self.protected_validator.set_inner(ProtectedValidator {
+            state_address: self.state.key(),
+            validator_account: validator_vote,
+        });
+
+        emit!(ProtectValidatorEvent {
+            state: self.state.key(),
+            validator: validator_vote,
+        });
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use anchor_lang::{prelude::*, system_program};
 
+use crate::state::pause_flags::PauseFlags;
 use crate::{
     error::MarinadeError,
     events::management::RemoveValidatorEvent,
@@ -45,7 +46,7 @@ pub struct RemoveValidator<'info> {
 
 impl<'info> RemoveValidator<'info> {
     pub fn process(&mut self, index: u32, validator_vote: Pubkey) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
 
         let validator = self.state.validator_system.get_checked(
             &self.validator_list.to_account_info().data.borrow(),
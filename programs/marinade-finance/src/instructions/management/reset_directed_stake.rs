@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError, events::management::ResetDirectedStakeEvent,
+    state::directed_stake::DirectedStakeRecord, State,
+};
+
+#[derive(Accounts)]
+pub struct ResetDirectedStake<'info> {
+    #[account(mut)]
+    pub state: Box<Account<'info, State>>,
+    #[account(
+        address = state.validator_system.manager_authority
+            @ MarinadeError::InvalidValidatorManager
+    )]
+    pub manager_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_collector,
+        constraint = directed_stake.state_address == state.key()
+            @ MarinadeError::WrongDirectedStakeRecord
+    )]
+    pub directed_stake: Box<Account<'info, DirectedStakeRecord>>,
+    /// CHECK: any account receiving the record rent
+    #[account(mut)]
+    pub rent_collector: UncheckedAccount<'info>,
+}
+
+impl<'info> ResetDirectedStake<'info> {
+    // fn reset_directed_stake() // drops the direction, e.g. of a removed validator. The stake stays
+    pub fn process(&mut self) -> Result<()> {
+        let directed_lamports = self.directed_stake.directed_lamports;
+        self.state.total_directed_stake -= directed_lamports;
+
+        emit!(ResetDirectedStakeEvent {
+            state: self.state.key(),
+            validator: self.directed_stake.validator_account,
+            directed_lamports,
+            total_directed_stake: self.state.total_directed_stake,
+        });
+        Ok(())
+    }
+}
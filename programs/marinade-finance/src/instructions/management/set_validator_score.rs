@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::state::pause_flags::PauseFlags;
 use crate::{
     error::MarinadeError,
     events::{management::SetValidatorScoreEvent, U32ValueChange},
@@ -25,7 +26,7 @@ pub struct SetValidatorScore<'info> {
 
 impl<'info> SetValidatorScore<'info> {
     pub fn process(&mut self, index: u32, validator_vote: Pubkey, score: u32) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
 
         let mut validator = self.state.validator_system.get_checked(
             &self.validator_list.to_account_info().data.borrow(),
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    error::MarinadeError, events::management::SetValidatorScoresBulkEvent,
+    state::validator_system::ValidatorList, State,
+};
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq)]
+pub struct ValidatorScoreUpdate {
+    pub index: u32,
+    pub score: u32,
+}
+
+#[derive(Accounts)]
+pub struct SetValidatorScoresBulk<'info> {
+    #[account(mut)]
+    pub state: Account<'info, State>,
+    #[account(
+        address = state.validator_system.manager_authority
+            @ MarinadeError::InvalidValidatorManager
+    )]
+    pub manager_authority: Signer<'info>,
+    #[account(
+        mut,
+        address = state.validator_system.validator_list.account,
+    )]
+    pub validator_list: Account<'info, ValidatorList>,
+}
+
+impl<'info> SetValidatorScoresBulk<'info> {
+    /// set_validator_score for many validators. validator_list_hash is
+    /// ValidatorSystem::validator_list_hash of the list the indexes were taken from,
+    /// so a list changed since then (validator added or removed) fails the whole batch
+    // fn set_validator_scores_bulk()
+    pub fn process(
+        &mut self,
+        validator_list_hash: [u8; 32],
+        scores: Vec<ValidatorScoreUpdate>,
+    ) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::CRANKS)?;
+
+        let validator_list = self.validator_list.to_account_info();
+        let mut validator_list_data = validator_list.data.as_ref().borrow_mut();
+        require!(
+            self.state
+                .validator_system
+                .validator_list_hash(&validator_list_data)?
+                .to_bytes()
+                == validator_list_hash,
+            MarinadeError::InvalidValidatorListHash
+        );
+
+        for ValidatorScoreUpdate { index, score } in scores.iter().copied() {
+            let mut validator = self
+                .state
+                .validator_system
+                .get(&validator_list_data, index)?;
+            self.state.validator_system.total_validator_score -= validator.score;
+            validator.score = score;
+            self.state.validator_system.total_validator_score += score;
+            self.state
+                .validator_system
+                .set(&mut validator_list_data, index, validator)?;
+        }
+
+        emit!(SetValidatorScoresBulkEvent {
+            state: self.state.key(),
+            validator_list_hash,
+            updated: scores.len() as u32,
+            total_validator_score: self.state.validator_system.total_validator_score,
+        });
+        Ok(())
+    }
+}
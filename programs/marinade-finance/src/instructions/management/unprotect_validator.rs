@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError, events::management::UnprotectValidatorEvent,
+    state::protected_validator::ProtectedValidator, State,
+};
+
+#[derive(Accounts)]
+pub struct UnprotectValidator<'info> {
+    #[account(mut)]
+    pub state: Account<'info, State>,
+    #[account(
+        address = state.validator_system.manager_authority
+            @ MarinadeError::InvalidValidatorManager
+    )]
+    pub manager_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_receiver,
+        constraint = protected_validator.state_address == state.key()
+            @ MarinadeError::InvalidProtectedValidator
+    )]
+    pub protected_validator: Account<'info, ProtectedValidator>,
+
+    #[account(mut)]
+    pub rent_receiver: SystemAccount<'info>,
+}
+
+impl<'info> UnprotectValidator<'info> {
+    // fn unprotect_validator()
+    pub fn process(&mut self) -> Result<()> {
+        self.state.protected_validator_count -= 1;
+        emit!(UnprotectValidatorEvent {
+            state: self.state.key(),
+            validator: self.protected_validator.validator_account,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError,
+    events::management::UpdateOracleScoresEvent,
+    state::score_oracle::{ScoreOracle, ScoreUpdate},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct UpdateOracleScores<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        has_one = scorer @ MarinadeError::InvalidScorer,
+        seeds = [
+            &state.key().to_bytes(),
+            ScoreOracle::SEED
+        ],
+        bump
+    )]
+    pub score_oracle: Box<Account<'info, ScoreOracle>>,
+    pub scorer: Signer<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> UpdateOracleScores<'info> {
+    // fn update_oracle_scores() // scores of the validators from start_index on in the validator list
+    pub fn process(&mut self, start_index: u32, scores: Vec<ScoreUpdate>) -> Result<()> {
+        self.score_oracle
+            .update(start_index, &scores, self.clock.epoch)?;
+
+        emit!(UpdateOracleScoresEvent {
+            state: self.state.key(),
+            epoch: self.clock.epoch,
+            start_index,
+            count: scores.len() as u32,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    error::MarinadeError, events::user::ClaimReferralRewardsEvent, state::referral::ReferralState,
+    State,
+};
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    pub partner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            ReferralState::SEED,
+            &partner.key().to_bytes()
+        ],
+        bump
+    )]
+    pub referral: Box<Account<'info, ReferralState>>,
+
+    // funded with protocol fees by the treasury
+    #[account(
+        mut,
+        token::mint = state.msol_mint,
+        token::authority = vault_authority
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            ReferralState::VAULT_AUTHORITY_SEED
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = state.msol_mint
+    )]
+    pub transfer_msol_to: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimReferralRewards<'info> {
+    /// settles the rewards of the referred mSOL and pays the accrued mSOL
+    /// as far as the vault holds it, the rest stays accrued
+    // fn claim_referral_rewards()
+    pub fn process(&mut self, bumps: &BTreeMap<String, u8>) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::DEPOSITS)?;
+        self.referral.settle(
+            self.state.msol_price,
+            self.state.msol_supply,
+            self.state.reward_fee,
+        )?;
+
+        let msol_amount = self.referral.accrued_msol.min(self.vault.amount);
+        require_gt!(msol_amount, 0, MarinadeError::NoReferralRewards);
+
+        transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.vault.to_account_info(),
+                    to: self.transfer_msol_to.to_account_info(),
+                    authority: self.vault_authority.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    ReferralState::VAULT_AUTHORITY_SEED,
+                    &[bumps["vault_authority"]],
+                ]],
+            ),
+            msol_amount,
+        )?;
+        self.referral.on_claim(msol_amount);
+
+        emit!(ClaimReferralRewardsEvent {
+            state: self.state.key(),
+            partner: self.partner.key(),
+            msol_amount,
+            accrued_msol: self.referral.accrued_msol,
+            claimed_msol: self.referral.claimed_msol,
+        });
+        Ok(())
+    }
+}
@@ -8,7 +8,8 @@ use anchor_spl::token::{
 
 use crate::error::MarinadeError;
 use crate::events::user::DepositEvent;
-use crate::state::liq_pool::LiqPool;
+use crate::state::pause_flags::PauseFlags;
+use crate::state::{liq_pool::LiqPool, staking_cap_bypass::StakingCapBypass};
 use crate::{require_lte, State};
 
 #[derive(Accounts)]
@@ -82,12 +83,25 @@ pub struct Deposit<'info> {
 
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
+
+    // admin exemption of mint_to.owner from staking_sol_cap
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            StakingCapBypass::SEED,
+            &mint_to.owner.to_bytes()
+        ],
+        bump
+    )]
+    pub staking_cap_bypass: Option<Box<Account<'info, StakingCapBypass>>>,
 }
 
 impl<'info> Deposit<'info> {
     // fn deposit_sol()
     pub fn process(&mut self, lamports: u64) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::DEPOSITS)?;
+        // lent liq pool lamports must not come back as a deposit
+        self.state.check_liq_pool_not_lent()?;
 
         require_gte!(
             lamports,
@@ -181,7 +195,9 @@ impl<'info> Deposit<'info> {
         // check if we have more lamports from the user besides the amount we swapped
         let sol_deposited = lamports - sol_swapped;
         if sol_deposited > 0 {
-            self.state.check_staking_cap(sol_deposited)?;
+            if self.staking_cap_bypass.is_none() {
+                self.state.check_staking_cap(sol_deposited)?;
+            }
 
             // transfer sol_deposited to reserve
             transfer(
@@ -239,3 +255,208 @@ impl<'info> Deposit<'info> {
         Ok(())
     }
 }
+
+// flash_loan_sol -> deposit -> flash_repay_sol must not turn the lent lamports into mSOL
+#[cfg(test)]
+mod flash_loan {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use anchor_lang::{
+        prelude::*,
+        solana_program::{program_option::COption, program_pack::Pack},
+        system_program,
+    };
+    use anchor_spl::token::spl_token;
+
+    use super::Deposit;
+    use crate::{
+        error::MarinadeError,
+        instructions::FlashRepaySol,
+        state::{liq_pool::LiqPool, Fee},
+        State, ID,
+    };
+
+    const RENT: u64 = 2_039_280;
+    const SOL_LEG_BALANCE: u64 = 10_000_000_000;
+    const LENT: u64 = 1_000_000_000;
+
+    struct TestAccount {
+        key: Pubkey,
+        is_signer: bool,
+        lamports: u64,
+        data: Vec<u8>,
+        owner: Pubkey,
+        executable: bool,
+    }
+
+    impl TestAccount {
+        fn new(key: Pubkey, lamports: u64, data: Vec<u8>, owner: Pubkey) -> Self {
+            Self {
+                key,
+                is_signer: false,
+                lamports,
+                data,
+                owner,
+                executable: false,
+            }
+        }
+
+        fn program(key: Pubkey) -> Self {
+            Self {
+                executable: true,
+                ..Self::new(key, 1, vec![], Pubkey::default())
+            }
+        }
+
+        fn token(key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) -> Self {
+            let mut data = vec![0; spl_token::state::Account::LEN];
+            spl_token::state::Account {
+                mint,
+                owner,
+                amount,
+                state: spl_token::state::AccountState::Initialized,
+                ..Default::default()
+            }
+            .pack_into_slice(&mut data);
+            Self::new(key, RENT, data, spl_token::ID)
+        }
+
+        fn mint(key: Pubkey, authority: Pubkey) -> Self {
+            let mut data = vec![0; spl_token::state::Mint::LEN];
+            spl_token::state::Mint {
+                mint_authority: COption::Some(authority),
+                supply: 0,
+                decimals: 9,
+                is_initialized: true,
+                freeze_authority: COption::None,
+            }
+            .pack_into_slice(&mut data);
+            Self::new(key, RENT, data, spl_token::ID)
+        }
+
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                self.is_signer,
+                true,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                self.executable,
+                0,
+            )
+        }
+    }
+
+    fn state_data(state: &State) -> Vec<u8> {
+        let mut data = vec![];
+        state.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    fn error_code(result: Result<()>) -> u32 {
+        match result {
+            Err(Error::AnchorError(error)) => error.error_code_number,
+            other => panic!("expected an anchor error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_loan_deposit_repay_fails() {
+        let state_address = Pubkey::new_unique();
+        let borrower = Pubkey::new_unique();
+        let (sol_leg, sol_leg_bump_seed) = LiqPool::find_sol_leg_address(&state_address);
+        let (msol_leg_authority, msol_leg_authority_bump_seed) =
+            LiqPool::find_msol_leg_authority(&state_address);
+        let (reserve, reserve_bump_seed) = State::find_reserve_address(&state_address);
+        let (msol_mint_authority, msol_mint_authority_bump_seed) =
+            State::find_msol_mint_authority(&state_address);
+
+        let mut state =
+            State::try_deserialize_unchecked(&mut &vec![0; State::serialized_len()][..]).unwrap();
+        state.msol_mint = Pubkey::new_unique();
+        state.min_deposit = 1;
+        state.rent_exempt_for_token_acc = RENT;
+        state.reserve_bump_seed = reserve_bump_seed;
+        state.msol_mint_authority_bump_seed = msol_mint_authority_bump_seed;
+        state.liq_pool.msol_leg = Pubkey::new_unique();
+        state.liq_pool.sol_leg_bump_seed = sol_leg_bump_seed;
+        state.liq_pool.msol_leg_authority_bump_seed = msol_leg_authority_bump_seed;
+        state.liq_pool.lp_min_fee = Fee::from_basis_points(30);
+
+        // flash_loan_sol of LENT to the borrower
+        state
+            .flash_loan
+            .begin(SOL_LEG_BALANCE, LENT, state.liq_pool.lp_min_fee)
+            .unwrap();
+        let owed = state.flash_loan.owed().unwrap();
+        let mut deposit_accounts = vec![
+            TestAccount::new(state_address, 1, state_data(&state), ID),
+            TestAccount::mint(state.msol_mint, msol_mint_authority),
+            TestAccount::new(sol_leg, SOL_LEG_BALANCE - LENT, vec![], system_program::ID),
+            TestAccount::token(
+                state.liq_pool.msol_leg,
+                state.msol_mint,
+                msol_leg_authority,
+                5_000_000_000,
+            ),
+            TestAccount::new(msol_leg_authority, 0, vec![], system_program::ID),
+            TestAccount::new(reserve, RENT, vec![], system_program::ID),
+            TestAccount {
+                is_signer: true,
+                ..TestAccount::new(borrower, LENT, vec![], system_program::ID)
+            },
+            TestAccount::token(Pubkey::new_unique(), state.msol_mint, borrower, 0),
+            TestAccount::new(msol_mint_authority, 0, vec![], system_program::ID),
+            TestAccount::program(system_program::ID),
+            TestAccount::program(spl_token::ID),
+            TestAccount::program(ID), // no staking_cap_bypass
+        ];
+
+        // deposit of the lent lamports, which would buy the mSOL leg with them
+        let infos: Vec<AccountInfo> = deposit_accounts.iter_mut().map(TestAccount::info).collect();
+        let mut deposit = Deposit::try_accounts(
+            &ID,
+            &mut &infos[..],
+            &[],
+            &mut BTreeMap::new(),
+            &mut BTreeSet::new(),
+        )
+        .unwrap();
+        assert_eq!(
+            error_code(deposit.process(LENT)),
+            u32::from(MarinadeError::FlashLoanInProgress)
+        );
+
+        // even with the sol leg back at its target, the repay still takes lamports + fee
+        // from the borrower, who only holds the lent lamports
+        let mut repay_accounts = [
+            TestAccount::new(state_address, 1, state_data(&state), ID),
+            TestAccount::new(
+                sol_leg,
+                state.flash_loan.sol_leg_target,
+                vec![],
+                system_program::ID,
+            ),
+            TestAccount {
+                is_signer: true,
+                ..TestAccount::new(borrower, LENT, vec![], system_program::ID)
+            },
+            TestAccount::program(system_program::ID),
+        ];
+        assert!(owed > LENT);
+        let infos: Vec<AccountInfo> = repay_accounts.iter_mut().map(TestAccount::info).collect();
+        let mut repay = FlashRepaySol::try_accounts(
+            &ID,
+            &mut &infos[..],
+            &[],
+            &mut BTreeMap::new(),
+            &mut BTreeSet::new(),
+        )
+        .unwrap();
+        assert_eq!(
+            error_code(repay.process()),
+            u32::from(MarinadeError::NotEnoughUserFunds)
+        );
+    }
+}
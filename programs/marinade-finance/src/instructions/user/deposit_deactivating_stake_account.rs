@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    program::invoke,
+    stake::{self, config as stake_config},
+    sysvar::stake_history,
+};
+
+use crate::{error::MarinadeError, events::user::ReactivateStakeAccountEvent};
+
+use super::deposit_stake_account::*;
+
+// deposit of a stake account deactivated in the current epoch.
+// Delegating it again to the same validator rescinds the deactivation before the deposit
+#[derive(Accounts)]
+pub struct DepositDeactivatingStakeAccount<'info> {
+    pub deposit_stake_account: DepositStakeAccount<'info>,
+
+    /// CHECK: CPI, must be the current delegation
+    pub validator_vote: UncheckedAccount<'info>,
+    /// CHECK: have no CPU budget to parse
+    #[account(address = stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+    /// CHECK: CPI
+    #[account(address = stake_config::ID)]
+    pub stake_config: UncheckedAccount<'info>,
+}
+
+impl<'info> DepositDeactivatingStakeAccount<'info> {
+    // fn deposit_deactivating_stake_account()
+    pub fn process(&mut self, validator_index: u32) -> Result<()> {
+        let stake_account = &self.deposit_stake_account.stake_account;
+        let delegation = stake_account.delegation().ok_or_else(|| {
+            error!(MarinadeError::RequiredDelegatedStake).with_account_name("stake_account")
+        })?;
+        // the stake program rescinds only a deactivation of the current epoch
+        require_eq!(
+            delegation.deactivation_epoch,
+            self.deposit_stake_account.clock.epoch,
+            MarinadeError::StakeDeactivationNotRescindable
+        );
+        require_keys_eq!(
+            delegation.voter_pubkey,
+            self.validator_vote.key(),
+            MarinadeError::WrongValidatorAccountOrIndex
+        );
+
+        invoke(
+            &stake::instruction::delegate_stake(
+                stake_account.to_account_info().key,
+                self.deposit_stake_account.stake_authority.key,
+                self.validator_vote.key,
+            ),
+            &[
+                self.deposit_stake_account.stake_program.to_account_info(),
+                stake_account.to_account_info(),
+                self.validator_vote.to_account_info(),
+                self.deposit_stake_account.clock.to_account_info(),
+                self.stake_history.to_account_info(),
+                self.stake_config.to_account_info(),
+                self.deposit_stake_account.stake_authority.to_account_info(),
+            ],
+        )?;
+
+        emit!(ReactivateStakeAccountEvent {
+            state: self.deposit_stake_account.state.key(),
+            stake: self.deposit_stake_account.stake_account.key(),
+            validator: delegation.voter_pubkey,
+            delegated: delegation.stake,
+        });
+
+        self.deposit_stake_account.stake_account.reload()?;
+        self.deposit_stake_account.process(validator_index)
+    }
+}
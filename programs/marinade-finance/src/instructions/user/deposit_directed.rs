@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::user::DepositDirectedEvent,
+    state::{directed_stake::DirectedStakeRecord, validator_system::ValidatorList},
+};
+
+use super::deposit::*;
+
+// deposit whose lamports are counted as directed to a validator of the list
+#[derive(Accounts)]
+pub struct DepositDirected<'info> {
+    pub deposit: Deposit<'info>,
+
+    #[account(address = deposit.state.validator_system.validator_list.account)]
+    pub validator_list: Account<'info, ValidatorList>,
+    /// CHECK: must be in the validator list
+    pub validator_vote: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            &deposit.state.key().to_bytes(),
+            DirectedStakeRecord::SEED,
+            &validator_vote.key().to_bytes()
+        ],
+        bump
+    )]
+    pub directed_stake: Box<Account<'info, DirectedStakeRecord>>,
+}
+
+impl<'info> DepositDirected<'info> {
+    /// the directed lamports stay counted for the validator until the manager resets them
+    // fn deposit_directed()
+    pub fn process(&mut self, lamports: u64, validator_index: u32) -> Result<()> {
+        // the validator could be removed and added again since the record was created
+        self.deposit
+            .state
+            .validator_system
+            .get_checked(
+                &self.validator_list.to_account_info().data.as_ref().borrow(),
+                validator_index,
+                self.validator_vote.key,
+            )
+            .map_err(|e| e.with_account_name("validator_vote"))?;
+
+        self.deposit.process(lamports)?;
+
+        self.directed_stake.directed_lamports += lamports;
+        self.deposit.state.total_directed_stake += lamports;
+
+        emit!(DepositDirectedEvent {
+            state: self.deposit.state.key(),
+            validator: self.validator_vote.key(),
+            lamports,
+            directed_lamports: self.directed_stake.directed_lamports,
+            total_directed_stake: self.deposit.state.total_directed_stake,
+        });
+        Ok(())
+    }
+}
@@ -6,9 +6,14 @@ use anchor_lang::solana_program::{
 use anchor_spl::stake::{Stake, StakeAccount};
 use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
 
+use crate::checks::check_feature_account;
 use crate::events::user::DepositStakeAccountEvent;
-use crate::state::stake_system::StakeList;
+use crate::state::pause_flags::PauseFlags;
 use crate::state::validator_system::ValidatorList;
+use crate::state::{
+    stake_system::StakeList, staking_cap_bypass::StakingCapBypass,
+    validator_blacklist::BlacklistedValidator,
+};
 use crate::{error::MarinadeError, require_lte, state::stake_system::StakeSystem, State, ID};
 
 #[derive(Accounts)]
@@ -67,12 +72,47 @@ pub struct DepositStakeAccount<'info> {
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub stake_program: Program<'info, Stake>,
+
+    /// CHECK: must not be initialized for the stake voter, see BlacklistedValidator
+    /// Required while any validator is blacklisted
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            BlacklistedValidator::SEED,
+            &stake_account
+                .delegation()
+                .map(|delegation| delegation.voter_pubkey)
+                .unwrap_or_default().to_bytes(),
+        ],
+        bump,
+    )]
+    pub blacklisted_validator: Option<UncheckedAccount<'info>>,
+
+    // lockup custodian, co-signs to clear a lockup still in force
+    pub custodian: Option<Signer<'info>>,
+
+    // admin exemption of mint_to.owner from staking_sol_cap
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            StakingCapBypass::SEED,
+            &mint_to.owner.to_bytes()
+        ],
+        bump
+    )]
+    pub staking_cap_bypass: Option<Box<Account<'info, StakingCapBypass>>>,
 }
 
 impl<'info> DepositStakeAccount<'info> {
     pub const WAIT_EPOCHS: u64 = 0; // Accepting fresh/redelegated accounts also because those are mergeable anyways
     pub fn process(&mut self, validator_index: u32) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::DEPOSITS)?;
+        check_feature_account(
+            &self.blacklisted_validator,
+            self.state.has_blacklisted_validators(),
+            "blacklisted_validator",
+        )?;
+        BlacklistedValidator::check_not_blacklisted(self.blacklisted_validator.as_deref())?;
 
         // impossible to happen check outside bug (msol mint auth is a PDA)
         require_lte!(
@@ -110,6 +150,11 @@ impl<'info> DepositStakeAccount<'info> {
             self.state.stake_system.min_stake,
             MarinadeError::TooLowDelegationInDepositingStake
         );
+        require_gte!(
+            delegation.stake,
+            self.state.min_deposit_stake_account,
+            MarinadeError::DepositAmountIsTooLow
+        );
 
         // Check that stake account has the right amount of lamports.
         // if there's extra the user should withdraw the extra and try again
@@ -120,11 +165,14 @@ impl<'info> DepositStakeAccount<'info> {
             MarinadeError::WrongStakeBalance,
         );
 
-        self.state.check_staking_cap(delegation.stake)?;
+        if self.staking_cap_bypass.is_none() {
+            self.state.check_staking_cap(delegation.stake)?;
+        }
 
         let lockup = self.stake_account.lockup().unwrap();
-        // Check Lockup
-        if lockup.is_in_force(&self.clock, None) {
+        // Check Lockup. Expired lockups are accepted and the custodian can override one in force
+        let lockup_in_force = lockup.is_in_force(&self.clock, None);
+        if lockup.is_in_force(&self.clock, self.custodian.as_ref().map(|c| c.key)) {
             msg!("Can not deposit stake account with lockup");
             return err!(MarinadeError::StakeAccountWithLockup)
                 .map_err(|e| e.with_account_name("stake_account"));
@@ -170,6 +218,12 @@ impl<'info> DepositStakeAccount<'info> {
 
             // Clean old lockup
             if lockup.custodian != Pubkey::default() {
+                // signed by the withdrawer once expired, by the custodian while in force
+                let lockup_authority = if lockup_in_force {
+                    self.custodian.as_ref().unwrap().to_account_info()
+                } else {
+                    self.stake_authority.to_account_info()
+                };
                 invoke(
                     &stake::instruction::set_lockup(
                         &self.stake_account.key(),
@@ -178,12 +232,12 @@ impl<'info> DepositStakeAccount<'info> {
                             epoch: Some(0),
                             custodian: Some(Pubkey::default()),
                         },
-                        self.stake_authority.key,
+                        lockup_authority.key,
                     ),
                     &[
                         self.stake_program.to_account_info(),
                         self.stake_account.to_account_info(),
-                        self.stake_authority.to_account_info(),
+                        lockup_authority,
                     ],
                 )?;
             }
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    program::invoke,
+    stake::{self, state::StakeState},
+    system_program,
+};
+use anchor_spl::stake::StakeAccount;
+
+use crate::{error::MarinadeError, events::user::DepositStakeAccountSplitEvent};
+
+use super::deposit_stake_account::*;
+
+// deposit of a part of a stake account. The rest is split off into remainder_stake_account
+// which stays delegated to the same validator under the user authorities
+#[derive(Accounts)]
+pub struct DepositStakeAccountSplit<'info> {
+    pub deposit_stake_account: DepositStakeAccount<'info>,
+
+    #[account(
+        init,
+        payer = remainder_rent_payer,
+        space = std::mem::size_of::<StakeState>(),
+        owner = stake::program::ID,
+    )]
+    pub remainder_stake_account: Account<'info, StakeAccount>,
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    pub remainder_rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositStakeAccountSplit<'info> {
+    // fn deposit_stake_account_split()
+    pub fn process(&mut self, lamports: u64, validator_index: u32) -> Result<()> {
+        let stake_account = &self.deposit_stake_account.stake_account;
+        let delegation = stake_account.delegation().ok_or_else(|| {
+            error!(MarinadeError::RequiredDelegatedStake).with_account_name("stake_account")
+        })?;
+        require_gt!(
+            delegation.stake,
+            lamports,
+            MarinadeError::InvalidDepositSplit
+        );
+        let remainder_lamports = delegation.stake - lamports;
+
+        let split_instruction = stake::instruction::split(
+            stake_account.to_account_info().key,
+            self.deposit_stake_account.stake_authority.key,
+            remainder_lamports,
+            &self.remainder_stake_account.key(),
+        )
+        .last()
+        .unwrap()
+        .clone();
+        invoke(
+            &split_instruction,
+            &[
+                self.deposit_stake_account.stake_program.to_account_info(),
+                stake_account.to_account_info(),
+                self.remainder_stake_account.to_account_info(),
+                self.deposit_stake_account.stake_authority.to_account_info(),
+            ],
+        )?;
+
+        // what is left in the account is exactly the deposited part
+        self.deposit_stake_account.stake_account.reload()?;
+        self.deposit_stake_account.process(validator_index)?;
+
+        emit!(DepositStakeAccountSplitEvent {
+            state: self.deposit_stake_account.state.key(),
+            stake: self.deposit_stake_account.stake_account.key(),
+            remainder_stake: self.remainder_stake_account.key(),
+            deposited: lamports,
+            remainder: remainder_lamports,
+        });
+        Ok(())
+    }
+}
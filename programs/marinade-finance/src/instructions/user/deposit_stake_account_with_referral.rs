@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError, events::user::ReferredDepositEvent, state::referral::ReferralState,
+};
+
+use super::deposit_stake_account::*;
+
+#[derive(Accounts)]
+pub struct DepositStakeAccountWithReferral<'info> {
+    pub deposit_stake_account: DepositStakeAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = referral.state_address == deposit_stake_account.state.key()
+            @ MarinadeError::InvalidReferral
+    )]
+    pub referral: Box<Account<'info, ReferralState>>,
+}
+
+impl<'info> DepositStakeAccountWithReferral<'info> {
+    // fn deposit_stake_account_with_referral()
+    pub fn process(&mut self, validator_index: u32) -> Result<()> {
+        let user_msol_balance = self.deposit_stake_account.mint_to.amount;
+        self.deposit_stake_account.process(validator_index)?;
+        self.deposit_stake_account.mint_to.reload()?;
+        let msol_received = self.deposit_stake_account.mint_to.amount - user_msol_balance;
+        let credited_msol = self
+            .referral
+            .on_referred_deposit(msol_received, &self.deposit_stake_account.state)?;
+
+        emit!(ReferredDepositEvent {
+            state: self.deposit_stake_account.state.key(),
+            partner: self.referral.partner,
+            msol_received,
+            credited_msol,
+            accrued_msol: self.referral.accrued_msol,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke, stake, sysvar::stake_history};
+
+use crate::{error::MarinadeError, events::user::DepositStakeAccountsEvent};
+
+use super::deposit_stake_account::*;
+
+// deposit of several stake accounts of the same user and validator, passed as remaining accounts.
+// They are merged into deposit_stake_account.stake_account first, so the deposit adds a single
+// entry to the stake list. stake_authority must also be the withdrawer
+#[derive(Accounts)]
+pub struct DepositStakeAccounts<'info> {
+    pub deposit_stake_account: DepositStakeAccount<'info>,
+
+    /// CHECK: have no CPU budget to parse
+    #[account(address = stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+}
+
+impl<'info> DepositStakeAccounts<'info> {
+    pub const MAX_STAKE_ACCOUNTS: usize = 8; // merged into the first one
+
+    // fn deposit_stake_accounts()
+    pub fn process(
+        &mut self,
+        validator_index: u32,
+        stake_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            !stake_accounts.is_empty() && stake_accounts.len() < Self::MAX_STAKE_ACCOUNTS,
+            MarinadeError::InvalidStakeAccountBatch
+        );
+
+        let destination = self.deposit_stake_account.stake_account.to_account_info();
+        for source in stake_accounts {
+            // the stake program checks the rest: owner, authorities, lockup, voter and activation
+            require_keys_neq!(
+                *source.key,
+                *destination.key,
+                MarinadeError::InvalidStakeAccountBatch
+            );
+            let merge_instruction = stake::instruction::merge(
+                destination.key,
+                source.key,
+                self.deposit_stake_account.stake_authority.key,
+            )
+            .last()
+            .unwrap()
+            .clone();
+            invoke(
+                &merge_instruction,
+                &[
+                    self.deposit_stake_account.stake_program.to_account_info(),
+                    destination.clone(),
+                    source.clone(),
+                    self.deposit_stake_account.clock.to_account_info(),
+                    self.stake_history.to_account_info(),
+                    self.deposit_stake_account.stake_authority.to_account_info(),
+                ],
+            )?;
+        }
+
+        // merging active stakes leaves the rent of the sources undelegated,
+        // returned to rent_payer for the deposit to find delegated stake + rent only
+        self.deposit_stake_account.stake_account.reload()?;
+        let stake_account = &self.deposit_stake_account.stake_account;
+        let undelegated = stake_account.to_account_info().lamports()
+            - stake_account.delegation().unwrap().stake
+            - stake_account.meta().unwrap().rent_exempt_reserve;
+        if undelegated > 0 {
+            invoke(
+                &stake::instruction::withdraw(
+                    destination.key,
+                    self.deposit_stake_account.stake_authority.key,
+                    self.deposit_stake_account.rent_payer.key,
+                    undelegated,
+                    None,
+                ),
+                &[
+                    self.deposit_stake_account.stake_program.to_account_info(),
+                    destination.clone(),
+                    self.deposit_stake_account.rent_payer.to_account_info(),
+                    self.deposit_stake_account.clock.to_account_info(),
+                    self.stake_history.to_account_info(),
+                    self.deposit_stake_account.stake_authority.to_account_info(),
+                ],
+            )?;
+            self.deposit_stake_account.stake_account.reload()?;
+        }
+        self.deposit_stake_account.process(validator_index)?;
+
+        emit!(DepositStakeAccountsEvent {
+            state: self.deposit_stake_account.state.key(),
+            stake: destination.key(),
+            merged: stake_accounts.iter().map(|a| a.key()).collect(),
+        });
+        Ok(())
+    }
+}
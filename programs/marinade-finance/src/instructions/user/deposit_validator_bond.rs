@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    error::MarinadeError, events::user::DepositValidatorBondEvent,
+    state::validator_bond::ValidatorBond, State,
+};
+
+#[derive(Accounts)]
+pub struct DepositValidatorBond<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        constraint = validator_bond.state_address == state.key()
+            @ MarinadeError::WrongValidatorBond
+    )]
+    pub validator_bond: Box<Account<'info, ValidatorBond>>,
+
+    #[account(mut)]
+    pub transfer_from: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositValidatorBond<'info> {
+    // fn deposit_validator_bond()
+    pub fn process(&mut self, lamports: u64) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::VALIDATOR_BONDS)?;
+        require_gt!(lamports, 0, MarinadeError::DepositAmountIsTooLow);
+
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.transfer_from.to_account_info(),
+                    to: self.validator_bond.to_account_info(),
+                },
+            ),
+            lamports,
+        )?;
+        self.validator_bond.bonded_lamports += lamports;
+
+        emit!(DepositValidatorBondEvent {
+            state: self.state.key(),
+            validator: self.validator_bond.validator_account,
+            depositor: self.transfer_from.key(),
+            lamports,
+            bonded_lamports: self.validator_bond.bonded_lamports,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MarinadeError, events::user::ReferredDepositEvent, state::referral::ReferralState,
+};
+
+use super::deposit::*;
+
+#[derive(Accounts)]
+pub struct DepositWithReferral<'info> {
+    pub deposit: Deposit<'info>,
+
+    #[account(
+        mut,
+        constraint = referral.state_address == deposit.state.key()
+            @ MarinadeError::InvalidReferral
+    )]
+    pub referral: Box<Account<'info, ReferralState>>,
+}
+
+impl<'info> DepositWithReferral<'info> {
+    // fn deposit_with_referral()
+    pub fn process(&mut self, lamports: u64) -> Result<()> {
+        let user_msol_balance = self.deposit.mint_to.amount;
+        self.deposit.process(lamports)?;
+        self.deposit.mint_to.reload()?;
+        // minted or swapped from the liq-pool
+        let msol_received = self.deposit.mint_to.amount - user_msol_balance;
+        let credited_msol = self
+            .referral
+            .on_referred_deposit(msol_received, &self.deposit.state)?;
+
+        emit!(ReferredDepositEvent {
+            state: self.deposit.state.key(),
+            partner: self.referral.partner,
+            msol_received,
+            credited_msol,
+            accrued_msol: self.referral.accrued_msol,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::events::user::GiftDepositEvent;
+
+use super::deposit::*;
+
+// deposit already mints to any mSOL account; this adds the attribution of a gift
+#[derive(Accounts)]
+pub struct GiftDeposit<'info> {
+    pub deposit: Deposit<'info>,
+}
+
+pub const GIFT_MEMO_LEN: usize = 64;
+
+impl<'info> GiftDeposit<'info> {
+    // fn gift_deposit()
+    pub fn process(&mut self, lamports: u64, memo: Option<[u8; GIFT_MEMO_LEN]>) -> Result<()> {
+        let beneficiary_msol_balance = self.deposit.mint_to.amount;
+        self.deposit.process(lamports)?;
+        self.deposit.mint_to.reload()?;
+
+        emit!(GiftDepositEvent {
+            state: self.deposit.state.key(),
+            payer: self.deposit.transfer_from.key(),
+            beneficiary: self.deposit.mint_to.owner,
+            lamports,
+            msol_received: self.deposit.mint_to.amount - beneficiary_msol_balance,
+            memo,
+        });
+        Ok(())
+    }
+}
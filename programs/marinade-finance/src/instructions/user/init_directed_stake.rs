@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::user::InitDirectedStakeEvent,
+    state::{directed_stake::DirectedStakeRecord, validator_system::ValidatorList},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct InitDirectedStake<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(address = state.validator_system.validator_list.account)]
+    pub validator_list: Account<'info, ValidatorList>,
+    /// CHECK: must be in the validator list
+    pub validator_vote: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<DirectedStakeRecord>(),
+        seeds = [
+            &state.key().to_bytes(),
+            DirectedStakeRecord::SEED,
+            &validator_vote.key().to_bytes()
+        ],
+        bump
+    )]
+    pub directed_stake: Box<Account<'info, DirectedStakeRecord>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitDirectedStake<'info> {
+    // fn init_directed_stake()
+    pub fn process(&mut self, validator_index: u32) -> Result<()> {
+        self.state
+            .validator_system
+            .get_checked(
+                &self.validator_list.to_account_info().data.as_ref().borrow(),
+                validator_index,
+                self.validator_vote.key,
+            )
+            .map_err(|e| e.with_account_name("validator_vote"))?;
+
+        self.directed_stake.set_inner(DirectedStakeRecord {
+            state_address: self.state.key(),
+            validator_account: self.validator_vote.key(),
+            directed_lamports: 0,
+        });
+
+        emit!(InitDirectedStakeEvent {
+            state: self.state.key(),
+            validator: self.validator_vote.key(),
+            directed_stake: self.directed_stake.key(),
+        });
+        Ok(())
+    }
+}
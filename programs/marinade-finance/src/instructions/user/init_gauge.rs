@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::user::InitGaugeEvent,
+    state::{gauge::Gauge, validator_system::ValidatorList},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct InitGauge<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(address = state.validator_system.validator_list.account)]
+    pub validator_list: Account<'info, ValidatorList>,
+    /// CHECK: must be in the validator list
+    pub validator_vote: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<Gauge>(),
+        seeds = [
+            &state.key().to_bytes(),
+            Gauge::SEED,
+            &validator_vote.key().to_bytes()
+        ],
+        bump
+    )]
+    pub gauge: Box<Account<'info, Gauge>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitGauge<'info> {
+    // fn init_gauge()
+    pub fn process(&mut self, validator_index: u32) -> Result<()> {
+        self.state
+            .validator_system
+            .get_checked(
+                &self.validator_list.to_account_info().data.as_ref().borrow(),
+                validator_index,
+                self.validator_vote.key,
+            )
+            .map_err(|e| e.with_account_name("validator_vote"))?;
+
+        self.gauge.set_inner(Gauge {
+            state_address: self.state.key(),
+            validator_account: self.validator_vote.key(),
+            locked_msol: 0,
+            weight: 0,
+            tally_epoch: 0,
+        });
+
+        emit!(InitGaugeEvent {
+            state: self.state.key(),
+            validator: self.validator_vote.key(),
+            gauge: self.gauge.key(),
+        });
+        Ok(())
+    }
+}
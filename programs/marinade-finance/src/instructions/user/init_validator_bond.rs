@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote;
+
+use crate::{
+    error::MarinadeError,
+    events::user::InitValidatorBondEvent,
+    state::{validator_bond::ValidatorBond, validator_criteria::VoteAccountSummary},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct InitValidatorBond<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    /// CHECK: authorized_withdrawer read by VoteAccountSummary
+    #[account(owner = vote::program::ID @ MarinadeError::InvalidVoteAccount)]
+    pub validator_vote: UncheckedAccount<'info>,
+    // authorized withdrawer of validator_vote
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<ValidatorBond>(),
+        seeds = [
+            &state.key().to_bytes(),
+            ValidatorBond::SEED,
+            &validator_vote.key().to_bytes()
+        ],
+        bump
+    )]
+    pub validator_bond: Box<Account<'info, ValidatorBond>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitValidatorBond<'info> {
+    // fn init_validator_bond()
+    pub fn process(&mut self) -> Result<()> {
+        require_keys_eq!(
+            VoteAccountSummary::authorized_withdrawer(&self.validator_vote)?,
+            self.authority.key(),
+            MarinadeError::InvalidBondAuthority
+        );
+
+        self.validator_bond.set_inner(ValidatorBond {
+            state_address: self.state.key(),
+            validator_account: self.validator_vote.key(),
+            authority: self.authority.key(),
+            bonded_lamports: 0,
+        });
+
+        emit!(InitValidatorBondEvent {
+            state: self.state.key(),
+            validator: self.validator_vote.key(),
+            validator_bond: self.validator_bond.key(),
+            authority: self.authority.key(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote;
+
+use crate::{
+    error::MarinadeError,
+    events::user::ValidatorProfileEvent,
+    state::{
+        validator_criteria::VoteAccountSummary,
+        validator_profile::{ValidatorProfile, ValidatorProfileData},
+    },
+    State,
+};
+
+#[derive(Accounts)]
+pub struct InitValidatorProfile<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    /// CHECK: node_pubkey read by VoteAccountSummary
+    #[account(owner = vote::program::ID @ MarinadeError::InvalidVoteAccount)]
+    pub validator_vote: UncheckedAccount<'info>,
+    // node_pubkey of validator_vote
+    pub identity: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<ValidatorProfile>(),
+        seeds = [
+            &state.key().to_bytes(),
+            ValidatorProfile::SEED,
+            &validator_vote.key().to_bytes()
+        ],
+        bump
+    )]
+    pub validator_profile: Box<Account<'info, ValidatorProfile>>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub clock: Sysvar<'info, Clock>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitValidatorProfile<'info> {
+    // fn init_validator_profile()
+    pub fn process(&mut self, data: ValidatorProfileData) -> Result<()> {
+        require_keys_eq!(
+            VoteAccountSummary::node_pubkey(&self.validator_vote)?,
+            self.identity.key(),
+            MarinadeError::InvalidValidatorIdentity
+        );
+        data.max_commission.check()?;
+
+        self.validator_profile.set_inner(ValidatorProfile {
+            state_address: self.state.key(),
+            validator_account: self.validator_vote.key(),
+            identity: self.identity.key(),
+            data,
+            updated_epoch: self.clock.epoch,
+        });
+
+        emit!(ValidatorProfileEvent {
+            state: self.state.key(),
+            validator: self.validator_vote.key(),
+            identity: self.identity.key(),
+            data,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    checks::check_token_source_account, error::MarinadeError, events::user::InstantWithdrawEvent,
+    require_lte, State,
+};
+
+#[derive(Accounts)]
+pub struct InstantWithdraw<'info> {
+    #[account(
+        mut,
+        has_one = msol_mint
+    )]
+    pub state: Box<Account<'info, State>>,
+    #[account(mut)]
+    pub msol_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = state.msol_mint
+    )]
+    pub burn_msol_from: Box<Account<'info, TokenAccount>>,
+    pub burn_msol_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            State::RESERVE_SEED
+        ],
+        bump = state.reserve_bump_seed
+    )]
+    pub reserve_pda: SystemAccount<'info>,
+    #[account(mut)]
+    pub transfer_sol_to: SystemAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> InstantWithdraw<'info> {
+    /// Unstakes mSOL at the mSOL price and without fee against the reserve lamports waiting to be staked,
+    /// the ones not needed for delayed-unstake tickets. At most instant_withdraw_cap_per_epoch lamports per epoch
+    // fn instant_withdraw()
+    pub fn process(&mut self, msol_amount: u64) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::LIQUID_UNSTAKE)?;
+
+        check_token_source_account(
+            &self.burn_msol_from,
+            self.burn_msol_authority.key,
+            msol_amount,
+        )
+        .map_err(|e| e.with_account_name("burn_msol_from"))?;
+        let user_msol_balance = self.burn_msol_from.amount;
+        let reserve_balance = self.reserve_pda.lamports();
+
+        // save msol price source
+        let total_virtual_staked_lamports = self.state.total_virtual_staked_lamports();
+        let msol_supply = self.state.msol_supply;
+
+        let lamports = self.state.msol_to_sol(msol_amount)?;
+        require_gte!(
+            lamports,
+            self.state.min_withdraw,
+            MarinadeError::WithdrawAmountIsTooLow
+        );
+        let idle_reserve = self.state.stake_delta(reserve_balance).max(0) as u64;
+        require_lte!(lamports, idle_reserve, MarinadeError::NotEnoughIdleReserve);
+        self.state.on_instant_withdraw(lamports, &self.clock)?;
+
+        burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.msol_mint.to_account_info(),
+                    from: self.burn_msol_from.to_account_info(),
+                    authority: self.burn_msol_authority.to_account_info(),
+                },
+            ),
+            msol_amount,
+        )?;
+        self.state.on_msol_burn(msol_amount);
+
+        transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.reserve_pda.to_account_info(),
+                    to: self.transfer_sol_to.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    State::RESERVE_SEED,
+                    &[self.state.reserve_bump_seed],
+                ]],
+            ),
+            lamports,
+        )?;
+        self.state.on_transfer_from_reserve(lamports);
+
+        emit!(InstantWithdrawEvent {
+            state: self.state.key(),
+            epoch: self.clock.epoch,
+            msol_owner: self.burn_msol_from.owner,
+            user_msol_balance,
+            reserve_balance,
+            msol_burned: msol_amount,
+            lamports,
+            instant_withdrawn: self.state.instant_withdrawn,
+            total_virtual_staked_lamports,
+            msol_supply,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    checks::check_token_source_account,
+    error::MarinadeError,
+    events::user::LockGaugeEvent,
+    state::gauge::{Gauge, GaugeLock},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct LockGauge<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        constraint = gauge.state_address == state.key() @ MarinadeError::WrongGauge
+    )]
+    pub gauge: Box<Account<'info, Gauge>>,
+
+    // Note: lock owner is transfer_from.owner
+    #[account(
+        zero,
+        rent_exempt = enforce
+    )]
+    pub new_gauge_lock: Box<Account<'info, GaugeLock>>,
+
+    #[account(
+        mut,
+        token::mint = state.msol_mint
+    )]
+    pub transfer_from: Box<Account<'info, TokenAccount>>,
+    pub transfer_from_authority: Signer<'info>, // transfer_from owner or delegate_authority
+
+    // holds the locked mSOL of all the gauges
+    #[account(
+        mut,
+        token::mint = state.msol_mint,
+        token::authority = vault_authority
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            Gauge::VAULT_AUTHORITY_SEED
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> LockGauge<'info> {
+    // fn lock_gauge()
+    pub fn process(&mut self, msol_amount: u64) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::GAUGES)?;
+        require!(
+            self.state.gauge_stake_share.basis_points > 0,
+            MarinadeError::InvalidGaugeLock
+        );
+        require_gt!(msol_amount, 0, MarinadeError::InvalidGaugeLock);
+        check_token_source_account(
+            &self.transfer_from,
+            self.transfer_from_authority.key,
+            msol_amount,
+        )
+        .map_err(|e| e.with_account_name("transfer_from"))?;
+
+        transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.transfer_from.to_account_info(),
+                    to: self.vault.to_account_info(),
+                    authority: self.transfer_from_authority.to_account_info(),
+                },
+            ),
+            msol_amount,
+        )?;
+        self.gauge.locked_msol += msol_amount;
+
+        let unlock_epoch = self.clock.epoch + self.state.gauge_lock_epochs;
+        self.new_gauge_lock.set_inner(GaugeLock {
+            state_address: self.state.key(),
+            gauge: self.gauge.key(),
+            owner: self.transfer_from.owner,
+            msol_amount,
+            unlock_epoch,
+        });
+
+        emit!(LockGaugeEvent {
+            state: self.state.key(),
+            gauge: self.gauge.key(),
+            validator: self.gauge.validator_account,
+            gauge_lock: self.new_gauge_lock.key(),
+            owner: self.transfer_from.owner,
+            msol_amount,
+            unlock_epoch,
+            locked_msol: self.gauge.locked_msol,
+        });
+        Ok(())
+    }
+}
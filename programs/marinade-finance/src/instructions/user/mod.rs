@@ -1,7 +1,43 @@
+pub mod claim_referral_rewards;
 pub mod deposit;
+pub mod deposit_deactivating_stake_account;
+pub mod deposit_directed;
 pub mod deposit_stake_account;
+pub mod deposit_stake_account_split;
+pub mod deposit_stake_account_with_referral;
+pub mod deposit_stake_accounts;
+pub mod deposit_validator_bond;
+pub mod deposit_with_referral;
+pub mod gift_deposit;
+pub mod init_directed_stake;
+pub mod init_gauge;
+pub mod init_validator_bond;
+pub mod init_validator_profile;
+pub mod instant_withdraw;
+pub mod lock_gauge;
+pub mod unlock_gauge;
+pub mod update_validator_profile;
 pub mod withdraw_stake_account;
+pub mod withdraw_validator_bond;
 
+pub use claim_referral_rewards::*;
 pub use deposit::*;
+pub use deposit_deactivating_stake_account::*;
+pub use deposit_directed::*;
 pub use deposit_stake_account::*;
+pub use deposit_stake_account_split::*;
+pub use deposit_stake_account_with_referral::*;
+pub use deposit_stake_accounts::*;
+pub use deposit_validator_bond::*;
+pub use deposit_with_referral::*;
+pub use gift_deposit::*;
+pub use init_directed_stake::*;
+pub use init_gauge::*;
+pub use init_validator_bond::*;
+pub use init_validator_profile::*;
+pub use instant_withdraw::*;
+pub use lock_gauge::*;
+pub use unlock_gauge::*;
+pub use update_validator_profile::*;
 pub use withdraw_stake_account::*;
+pub use withdraw_validator_bond::*;
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    error::MarinadeError,
+    events::user::UnlockGaugeEvent,
+    state::gauge::{Gauge, GaugeLock},
+    State,
+};
+
+#[derive(Accounts)]
+pub struct UnlockGauge<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(mut)]
+    pub gauge: Box<Account<'info, Gauge>>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ MarinadeError::InvalidGaugeLock,
+        has_one = gauge @ MarinadeError::WrongGauge,
+        constraint = gauge_lock.state_address == state.key() @ MarinadeError::InvalidGaugeLock
+    )]
+    pub gauge_lock: Box<Account<'info, GaugeLock>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = state.msol_mint,
+        token::authority = vault_authority
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            Gauge::VAULT_AUTHORITY_SEED
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = state.msol_mint
+    )]
+    pub transfer_msol_to: Box<Account<'info, TokenAccount>>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> UnlockGauge<'info> {
+    // fn unlock_gauge()
+    pub fn process(&mut self, vault_authority_bump: u8) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::GAUGES)?;
+        require_gte!(
+            self.clock.epoch,
+            self.gauge_lock.unlock_epoch,
+            MarinadeError::GaugeLockNotExpired
+        );
+
+        let msol_amount = self.gauge_lock.msol_amount;
+        transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.vault.to_account_info(),
+                    to: self.transfer_msol_to.to_account_info(),
+                    authority: self.vault_authority.to_account_info(),
+                },
+                &[&[
+                    &self.state.key().to_bytes(),
+                    Gauge::VAULT_AUTHORITY_SEED,
+                    &[vault_authority_bump],
+                ]],
+            ),
+            msol_amount,
+        )?;
+        // the gauge weight follows at the next tally
+        self.gauge.locked_msol -= msol_amount;
+
+        emit!(UnlockGaugeEvent {
+            state: self.state.key(),
+            gauge: self.gauge.key(),
+            validator: self.gauge.validator_account,
+            gauge_lock: self.gauge_lock.key(),
+            owner: self.owner.key(),
+            msol_amount,
+            locked_msol: self.gauge.locked_msol,
+        });
+        Ok(())
+    }
+}
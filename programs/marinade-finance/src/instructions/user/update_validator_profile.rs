@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote;
+
+use crate::{
+    error::MarinadeError,
+    events::user::ValidatorProfileEvent,
+    state::{
+        validator_criteria::VoteAccountSummary,
+        validator_profile::{ValidatorProfile, ValidatorProfileData},
+    },
+    State,
+};
+
+#[derive(Accounts)]
+pub struct UpdateValidatorProfile<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    /// CHECK: node_pubkey read by VoteAccountSummary
+    #[account(owner = vote::program::ID @ MarinadeError::InvalidVoteAccount)]
+    pub validator_vote: UncheckedAccount<'info>,
+    // current node_pubkey of validator_vote
+    pub identity: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            &state.key().to_bytes(),
+            ValidatorProfile::SEED,
+            &validator_vote.key().to_bytes()
+        ],
+        bump
+    )]
+    pub validator_profile: Box<Account<'info, ValidatorProfile>>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> UpdateValidatorProfile<'info> {
+    // fn update_validator_profile()
+    pub fn process(&mut self, data: ValidatorProfileData) -> Result<()> {
+        require_keys_eq!(
+            VoteAccountSummary::node_pubkey(&self.validator_vote)?,
+            self.identity.key(),
+            MarinadeError::InvalidValidatorIdentity
+        );
+        data.max_commission.check()?;
+
+        self.validator_profile.identity = self.identity.key();
+        self.validator_profile.data = data;
+        self.validator_profile.updated_epoch = self.clock.epoch;
+
+        emit!(ValidatorProfileEvent {
+            state: self.state.key(),
+            validator: self.validator_vote.key(),
+            identity: self.identity.key(),
+            data,
+        });
+        Ok(())
+    }
+}
@@ -23,6 +23,7 @@ use anchor_spl::{
 };
 
 use crate::checks::check_stake_amount_and_validator;
+use crate::state::pause_flags::PauseFlags;
 
 #[derive(Accounts)]
 pub struct WithdrawStakeAccount<'info> {
@@ -108,7 +109,7 @@ impl<'info> WithdrawStakeAccount<'info> {
         msol_amount: u64,
         beneficiary: Pubkey,
     ) -> Result<()> {
-        require!(!self.state.paused, MarinadeError::ProgramIsPaused);
+        self.state.check_not_paused(PauseFlags::LIQUID_UNSTAKE)?;
         require!(
             self.state.withdraw_stake_account_enabled,
             MarinadeError::WithdrawStakeAccountIsNotEnabled
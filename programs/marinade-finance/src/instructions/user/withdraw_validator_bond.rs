@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::state::pause_flags::PauseFlags;
+use crate::{
+    error::MarinadeError,
+    events::user::WithdrawValidatorBondEvent,
+    state::{
+        validator_bond::ValidatorBond,
+        validator_system::{ValidatorList, ValidatorRecord},
+    },
+    State, ID,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawValidatorBond<'info> {
+    pub state: Box<Account<'info, State>>,
+
+    #[account(
+        mut,
+        has_one = authority @ MarinadeError::InvalidBondAuthority,
+        constraint = validator_bond.state_address == state.key()
+            @ MarinadeError::WrongValidatorBond
+    )]
+    pub validator_bond: Box<Account<'info, ValidatorBond>>,
+    pub authority: Signer<'info>,
+
+    #[account(address = state.validator_system.validator_list.account)]
+    pub validator_list: Account<'info, ValidatorList>,
+    /// CHECK: initialized while the validator is in the list
+    #[account(
+        seeds = [
+            &state.key().to_bytes(),
+            ValidatorRecord::DUPLICATE_FLAG_SEED,
+            &validator_bond.validator_account.to_bytes(),
+        ],
+        bump,
+    )]
+    pub duplication_flag: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub transfer_sol_to: SystemAccount<'info>,
+}
+
+impl<'info> WithdrawValidatorBond<'info> {
+    /// the bond left must still back the stake of the validator when it is in the list
+    // fn withdraw_validator_bond()
+    pub fn process(&mut self, lamports: u64, validator_index: u32) -> Result<()> {
+        self.state.check_not_paused(PauseFlags::VALIDATOR_BONDS)?;
+        require_gte!(
+            self.validator_bond.bonded_lamports,
+            lamports,
+            MarinadeError::BondIsTooLow
+        );
+        self.validator_bond.bonded_lamports -= lamports;
+
+        if self.duplication_flag.owner == &ID {
+            let validator = self.state.validator_system.get_checked(
+                &self.validator_list.to_account_info().data.as_ref().borrow(),
+                validator_index,
+                &self.validator_bond.validator_account,
+            )?;
+            self.validator_bond
+                .check_backs(self.state.bond_stake_multiplier, validator.active_balance)?;
+        }
+
+        **self
+            .validator_bond
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= lamports;
+        **self.transfer_sol_to.try_borrow_mut_lamports()? += lamports;
+
+        emit!(WithdrawValidatorBondEvent {
+            state: self.state.key(),
+            validator: self.validator_bond.validator_account,
+            lamports,
+            bonded_lamports: self.validator_bond.bonded_lamports,
+        });
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 #![cfg_attr(not(debug_assertions), deny(warnings))]
 
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, InstructionData};
 
 use error::MarinadeError;
 
@@ -12,6 +12,11 @@ pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::{
+    admin_log::AdminLogWriter, config_proposal::ConfigChange, score_oracle::ScoreUpdate,
+    treasury_split::TreasurySplitEntry, unstake_fee_curve::FeeCurvePoint,
+    validator_profile::ValidatorProfileData, Fee,
+};
 
 #[cfg(not(feature = "no-entrypoint"))]
 use solana_security_txt::security_txt;
@@ -31,10 +36,30 @@ security_txt! {
     auditors: "https://docs.marinade.finance/marinade-protocol/security/audits"
 }
 
-fn check_context<T>(ctx: &Context<T>) -> Result<()> {
+fn check_program_id<T>(ctx: &Context<T>) -> Result<()> {
     if !check_id(ctx.program_id) {
         return err!(MarinadeError::InvalidProgramId);
     }
+    Ok(())
+}
+
+// check_context of the privileged instructions, the AdminLog is the only extra account allowed
+fn check_admin_context<'info, T>(
+    ctx: &Context<'_, '_, '_, 'info, T>,
+    state: &Account<State>,
+    instruction: &impl InstructionData,
+) -> Result<AdminLogWriter<'info>> {
+    check_program_id(ctx)?;
+    let (admin_log, remaining_accounts) =
+        AdminLogWriter::begin(state, instruction, ctx.remaining_accounts)?;
+    if !remaining_accounts.is_empty() {
+        return err!(MarinadeError::UnexpectedAccount);
+    }
+    Ok(admin_log)
+}
+
+fn check_context<T>(ctx: &Context<T>) -> Result<()> {
+    check_program_id(ctx)?;
     // make sure there are no extra accounts
     if !ctx.remaining_accounts.is_empty() {
         return err!(MarinadeError::UnexpectedAccount);
@@ -69,13 +94,71 @@ pub mod marinade_finance {
         ctx: Context<ChangeAuthority>,
         data: ChangeAuthorityData,
     ) -> Result<()> {
-        check_context(&ctx)?;
-        ctx.accounts.process(data)
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::ChangeAuthority { data },
+        )?;
+        ctx.accounts.process(data)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let admin_log =
+            check_admin_context(&ctx, &ctx.accounts.state, &instruction::AcceptAuthority)?;
+        ctx.accounts.process()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.new_admin_authority.key())
+    }
+
+    // the pending admin is the native treasury PDA of the governance
+    pub fn accept_authority_governance(ctx: Context<AcceptAuthorityGovernance>) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::AcceptAuthorityGovernance,
+        )?;
+        ctx.accounts.process()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.state.admin_authority)
+    }
+
+    pub fn change_treasury(
+        ctx: Context<ChangeTreasury>,
+        treasury_msol_account: Pubkey,
+    ) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::ChangeTreasury {
+                treasury_msol_account,
+            },
+        )?;
+        ctx.accounts.process(treasury_msol_account)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.treasury_authority.key())
     }
 
     pub fn add_validator(ctx: Context<AddValidator>, score: u32) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::AddValidator { score },
+        )?;
+        ctx.accounts.process(score)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.manager_authority.key())
+    }
+
+    // anyone can add a vote account meeting state.validator_criteria
+    pub fn add_validator_permissionless(ctx: Context<AddValidatorPermissionless>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    pub fn update_oracle_scores(
+        ctx: Context<UpdateOracleScores>,
+        start_index: u32,
+        scores: Vec<ScoreUpdate>,
+    ) -> Result<()> {
         check_context(&ctx)?;
-        ctx.accounts.process(score)
+        ctx.accounts.process(start_index, scores)
     }
 
     pub fn remove_validator(
@@ -83,8 +166,16 @@ pub mod marinade_finance {
         index: u32,
         validator_vote: Pubkey,
     ) -> Result<()> {
-        check_context(&ctx)?;
-        ctx.accounts.process(index, validator_vote)
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::RemoveValidator {
+                index,
+                validator_vote,
+            },
+        )?;
+        ctx.accounts.process(index, validator_vote)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.manager_authority.key())
     }
 
     pub fn set_validator_score(
@@ -93,16 +184,71 @@ pub mod marinade_finance {
         validator_vote: Pubkey,
         score: u32,
     ) -> Result<()> {
-        check_context(&ctx)?;
-        ctx.accounts.process(index, validator_vote, score)
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::SetValidatorScore {
+                index,
+                validator_vote,
+                score,
+            },
+        )?;
+        ctx.accounts.process(index, validator_vote, score)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.manager_authority.key())
+    }
+
+    pub fn protect_validator(ctx: Context<ProtectValidator>, validator_vote: Pubkey) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::ProtectValidator { validator_vote },
+        )?;
+        ctx.accounts.process(validator_vote)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.manager_authority.key())
+    }
+
+    pub fn unprotect_validator(ctx: Context<UnprotectValidator>) -> Result<()> {
+        let admin_log =
+            check_admin_context(&ctx, &ctx.accounts.state, &instruction::UnprotectValidator)?;
+        ctx.accounts.process()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.manager_authority.key())
+    }
+
+    pub fn set_validator_scores_bulk(
+        ctx: Context<SetValidatorScoresBulk>,
+        validator_list_hash: [u8; 32],
+        scores: Vec<ValidatorScoreUpdate>,
+    ) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::SetValidatorScoresBulk {
+                validator_list_hash,
+                scores: scores.clone(),
+            },
+        )?;
+        ctx.accounts.process(validator_list_hash, scores)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.manager_authority.key())
+    }
+
+    pub fn reset_directed_stake(ctx: Context<ResetDirectedStake>) -> Result<()> {
+        let admin_log =
+            check_admin_context(&ctx, &ctx.accounts.state, &instruction::ResetDirectedStake)?;
+        ctx.accounts.process()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.manager_authority.key())
     }
 
     pub fn config_validator_system(
         ctx: Context<ConfigValidatorSystem>,
         extra_runs: u32,
     ) -> Result<()> {
-        check_context(&ctx)?;
-        ctx.accounts.process(extra_runs)
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::ConfigValidatorSystem { extra_runs },
+        )?;
+        ctx.accounts.process(extra_runs)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.manager_authority.key())
     }
 
     // deposit AKA stake, AKA deposit_sol
@@ -111,6 +257,129 @@ pub mod marinade_finance {
         ctx.accounts.process(lamports)
     }
 
+    // deposit to a third party mSOL account, attributed by an optional memo
+    pub fn gift_deposit(
+        ctx: Context<GiftDeposit>,
+        lamports: u64,
+        memo: Option<[u8; 64]>,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(lamports, memo)
+    }
+
+    pub fn init_directed_stake(
+        ctx: Context<InitDirectedStake>,
+        validator_index: u32,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(validator_index)
+    }
+
+    pub fn deposit_with_referral(ctx: Context<DepositWithReferral>, lamports: u64) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(lamports)
+    }
+
+    pub fn deposit_deactivating_stake_account(
+        ctx: Context<DepositDeactivatingStakeAccount>,
+        validator_index: u32,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(validator_index)
+    }
+
+    pub fn deposit_stake_account_split(
+        ctx: Context<DepositStakeAccountSplit>,
+        lamports: u64,
+        validator_index: u32,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(lamports, validator_index)
+    }
+
+    // the other stake accounts are passed as remaining accounts and merged into stake_account
+    pub fn deposit_stake_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositStakeAccounts<'info>>,
+        validator_index: u32,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
+        ctx.accounts
+            .process(validator_index, ctx.remaining_accounts)
+    }
+
+    pub fn deposit_stake_account_with_referral(
+        ctx: Context<DepositStakeAccountWithReferral>,
+        validator_index: u32,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(validator_index)
+    }
+
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(&ctx.bumps)
+    }
+
+    pub fn init_gauge(ctx: Context<InitGauge>, validator_index: u32) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(validator_index)
+    }
+
+    pub fn lock_gauge(ctx: Context<LockGauge>, msol_amount: u64) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(msol_amount)
+    }
+
+    pub fn unlock_gauge(ctx: Context<UnlockGauge>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts
+            .process(*ctx.bumps.get("vault_authority").unwrap())
+    }
+
+    pub fn init_validator_bond(ctx: Context<InitValidatorBond>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    pub fn deposit_validator_bond(ctx: Context<DepositValidatorBond>, lamports: u64) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(lamports)
+    }
+
+    pub fn withdraw_validator_bond(
+        ctx: Context<WithdrawValidatorBond>,
+        lamports: u64,
+        validator_index: u32,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(lamports, validator_index)
+    }
+
+    pub fn init_validator_profile(
+        ctx: Context<InitValidatorProfile>,
+        data: ValidatorProfileData,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(data)
+    }
+
+    pub fn update_validator_profile(
+        ctx: Context<UpdateValidatorProfile>,
+        data: ValidatorProfileData,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(data)
+    }
+
+    pub fn deposit_directed(
+        ctx: Context<DepositDirected>,
+        lamports: u64,
+        validator_index: u32,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(lamports, validator_index)
+    }
+
     // SPL stake pool like
     pub fn deposit_stake_account(
         ctx: Context<DepositStakeAccount>,
@@ -122,7 +391,33 @@ pub mod marinade_finance {
 
     pub fn liquid_unstake(ctx: Context<LiquidUnstake>, msol_amount: u64) -> Result<()> {
         check_context(&ctx)?;
-        ctx.accounts.process(msol_amount)
+        ctx.accounts.process(msol_amount, 0)
+    }
+
+    // liquid_unstake failing when the fee leaves less than min_lamports_out to the user
+    pub fn liquid_unstake_with_min_out(
+        ctx: Context<LiquidUnstake>,
+        msol_amount: u64,
+        min_lamports_out: u64,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(msol_amount, min_lamports_out)
+    }
+
+    // unstake through the idle reserve, then the liq-pool, then a ticket for the residue
+    pub fn best_effort_unstake(
+        ctx: Context<BestEffortUnstake>,
+        msol_amount: u64,
+        min_lamports_out: u64,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(msol_amount, min_lamports_out)
+    }
+
+    // buy the liq-pool mSOL leg with SOL at the mSOL price, while it lowers the liquid_unstake fee
+    pub fn arb_liq_pool(ctx: Context<ArbLiqPool>, lamports: u64, min_msol_out: u64) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(lamports, min_msol_out)
     }
 
     pub fn add_liquidity(ctx: Context<AddLiquidity>, lamports: u64) -> Result<()> {
@@ -130,22 +425,449 @@ pub mod marinade_finance {
         ctx.accounts.process(lamports)
     }
 
+    // add liquidity paying in mSOL only
+    pub fn add_liquidity_msol(ctx: Context<AddLiquidityMsol>, msol_amount: u64) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(msol_amount)
+    }
+
+    // deposit + add_liquidity at the current leg ratio
+    pub fn zap_add_liquidity(ctx: Context<ZapAddLiquidity>, lamports: u64) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(lamports)
+    }
+
     pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, tokens: u64) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(tokens)?;
+        Ok(())
+    }
+
+    // remove_liquidity failing when a leg pays out less than expected
+    pub fn remove_liquidity_with_min_out(
+        ctx: Context<RemoveLiquidity>,
+        tokens: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts
+            .process_with_min_out(tokens, min_sol_out, min_msol_out)?;
+        Ok(())
+    }
+
+    // remove liquidity ordering a delayed unstake of the mSOL part
+    pub fn remove_liquidity_order_unstake(
+        ctx: Context<RemoveLiquidityOrderUnstake>,
+        tokens: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(tokens, min_sol_out, min_msol_out)
+    }
+
+    // remove liquidity staking the SOL part into the mSOL leg for an LP credit
+    pub fn remove_liquidity_rebalance(
+        ctx: Context<RemoveLiquidityRebalance>,
+        tokens: u64,
+    ) -> Result<()> {
         check_context(&ctx)?;
         ctx.accounts.process(tokens)
     }
 
+    // remove liquidity paying everything in SOL, the mSOL part is liquid-unstaked
+    pub fn remove_liquidity_sol_only(
+        ctx: Context<RemoveLiquiditySolOnly>,
+        tokens: u64,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(tokens)
+    }
+
+    // remove liquidity paying a tip from the SOL output to the keeper submitting the transaction
+    pub fn remove_liquidity_with_keeper_tip(
+        ctx: Context<RemoveLiquidityWithKeeperTip>,
+        tokens: u64,
+        tip: u64,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(tokens, tip)
+    }
+
+    // remove liquidity and mint a soulbound receipt NFT recording the removal
+    pub fn remove_liquidity_with_receipt(
+        ctx: Context<RemoveLiquidityWithReceipt>,
+        tokens: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(
+            tokens,
+            min_sol_out,
+            min_msol_out,
+            *ctx.bumps.get("receipt_authority").unwrap(),
+        )
+    }
+
+    // remove liquidity locking the outputs into a linear vesting schedule
+    pub fn remove_liquidity_vested(
+        ctx: Context<RemoveLiquidityVested>,
+        tokens: u64,
+        start_slot: u64,
+        cliff_slot: u64,
+        end_slot: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(
+            tokens,
+            start_slot,
+            cliff_slot,
+            end_slot,
+            min_sol_out,
+            min_msol_out,
+            *ctx.bumps.get("vesting_authority").unwrap(),
+        )
+    }
+
+    // escrow LP tokens until a slot for a boosted share of the liquid unstake fees
+    pub fn lock_liquidity(
+        ctx: Context<LockLiquidity>,
+        lp_amount: u64,
+        lock_slots: u64,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(lp_amount, lock_slots)
+    }
+
+    pub fn extend_lock(ctx: Context<ExtendLock>, unlock_slot: u64) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(unlock_slot)
+    }
+
+    pub fn unlock_liquidity(ctx: Context<UnlockLiquidity>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    // lend the legs share of an LP position until end_flash_remove_liquidity in the same transaction
+    pub fn begin_flash_remove_liquidity(
+        ctx: Context<BeginFlashRemoveLiquidity>,
+        tokens: u64,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(tokens)
+    }
+
+    pub fn end_flash_remove_liquidity(ctx: Context<EndFlashRemoveLiquidity>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    // lend lamports of the SOL leg until flash_repay_sol in the same transaction
+    pub fn flash_loan_sol(ctx: Context<FlashLoanSol>, lamports: u64) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(lamports)
+    }
+
+    pub fn flash_repay_sol(ctx: Context<FlashRepaySol>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    // view: what remove_liquidity would deliver for this amount of LP tokens
+    // and which of its checks would fail
+    pub fn simulate_remove_breakdown(
+        ctx: Context<SimulateRemoveBreakdown>,
+        tokens: u64,
+        min_sol_out: u64,
+        min_msol_out: u64,
+    ) -> Result<RemoveLiquidityBreakdown> {
+        check_context(&ctx)?;
+        ctx.accounts.process(tokens, min_sol_out, min_msol_out)
+    }
+
+    // view: value lost to rounding by removing this amount of LP tokens, in basis points
+    pub fn estimate_removal_slippage_bps(
+        ctx: Context<SimulateRemoveBreakdown>,
+        tokens: u64,
+    ) -> Result<u16> {
+        check_context(&ctx)?;
+        ctx.accounts.estimate_slippage_bps(tokens)
+    }
+
+    // view: value backing all the LP tokens (liq-pool TVL)
+    pub fn liq_pool_total_claimable_lamports(ctx: Context<SimulateRemoveBreakdown>) -> Result<u64> {
+        check_context(&ctx)?;
+        ctx.accounts.total_claimable_lamports()
+    }
+
     pub fn config_lp(ctx: Context<ConfigLp>, params: ConfigLpParams) -> Result<()> {
         check_context(&ctx)?;
         ctx.accounts.process(params)
     }
 
+    // one field of config_marinade or config_lp
+    pub fn set_config_param(ctx: Context<SetConfigParam>, param: ConfigParam) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::SetConfigParam { param },
+        )?;
+        ctx.accounts.process(param)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    // exempt a wallet from lp_account_cap and liquidity_sol_cap
+    pub fn add_lp_cap_bypass(ctx: Context<AddLpCapBypass>, wallet: Pubkey) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::AddLpCapBypass { wallet },
+        )?;
+        ctx.accounts.process(wallet)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    // exempt a wallet from staking_sol_cap
+    pub fn add_staking_cap_bypass(ctx: Context<AddStakingCapBypass>, wallet: Pubkey) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::AddStakingCapBypass { wallet },
+        )?;
+        ctx.accounts.process(wallet)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn remove_staking_cap_bypass(ctx: Context<RemoveStakingCapBypass>) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::RemoveStakingCapBypass,
+        )?;
+        ctx.accounts.process()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn init_score_oracle(
+        ctx: Context<InitScoreOracle>,
+        scorer: Pubkey,
+        max_age_epochs: u64,
+    ) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::InitScoreOracle {
+                scorer,
+                max_age_epochs,
+            },
+        )?;
+        ctx.accounts.process(scorer, max_age_epochs)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn init_referral(ctx: Context<InitReferral>, partner: Pubkey, fee: Fee) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::InitReferral { partner, fee },
+        )?;
+        ctx.accounts.process(partner, fee)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn set_validator_stake_caps<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetValidatorStakeCaps<'info>>,
+        caps: Vec<ValidatorStakeCapUpdate>,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
+        let (admin_log, remaining_accounts) = AdminLogWriter::begin(
+            &ctx.accounts.state,
+            &instruction::SetValidatorStakeCaps { caps: caps.clone() },
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.process(caps, remaining_accounts)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn blacklist_validator(
+        ctx: Context<BlacklistValidator>,
+        validator_vote: Pubkey,
+    ) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::BlacklistValidator { validator_vote },
+        )?;
+        ctx.accounts.process(validator_vote)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn unblacklist_validator(ctx: Context<UnblacklistValidator>) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::UnblacklistValidator,
+        )?;
+        ctx.accounts.process()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn slash_validator_bond(ctx: Context<SlashValidatorBond>, lamports: u64) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::SlashValidatorBond { lamports },
+        )?;
+        ctx.accounts.process(lamports)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn remove_lp_cap_bypass(ctx: Context<RemoveLpCapBypass>) -> Result<()> {
+        let admin_log =
+            check_admin_context(&ctx, &ctx.accounts.state, &instruction::RemoveLpCapBypass)?;
+        ctx.accounts.process()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn config_locked_lp(ctx: Context<ConfigLockedLp>, boost_fee_share: Fee) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::ConfigLockedLp { boost_fee_share },
+        )?;
+        ctx.accounts.process(
+            boost_fee_share,
+            *ctx.bumps.get("locked_lp_authority").unwrap(),
+        )?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    // empty points go back to the liq_pool min/max linear fee
+    pub fn config_unstake_fee_curve(
+        ctx: Context<ConfigUnstakeFeeCurve>,
+        points: Vec<FeeCurvePoint>,
+    ) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::ConfigUnstakeFeeCurve {
+                points: points.clone(),
+            },
+        )?;
+        ctx.accounts.process(points)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn force_sync_lp_supply_up(ctx: Context<ForceSyncLpSupplyUp>) -> Result<()> {
+        let admin_log =
+            check_admin_context(&ctx, &ctx.accounts.state, &instruction::ForceSyncLpSupplyUp)?;
+        ctx.accounts.process()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    // only while paused, liq-pool instructions stay blocked until restore_liq_pool
+    pub fn emergency_drain_liq_pool(ctx: Context<EmergencyDrainLiqPool>) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::EmergencyDrainLiqPool,
+        )?;
+        ctx.accounts.process(*ctx.bumps.get("escrow").unwrap())?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn restore_liq_pool(ctx: Context<RestoreLiqPool>) -> Result<()> {
+        let admin_log =
+            check_admin_context(&ctx, &ctx.accounts.state, &instruction::RestoreLiqPool)?;
+        ctx.accounts.process()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    // name, symbol and uri shown by wallets for the mSOL-SOL-LP token
+    pub fn set_lp_mint_metadata(
+        ctx: Context<SetLpMintMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::SetLpMintMetadata {
+                name: name.clone(),
+                symbol: symbol.clone(),
+                uri: uri.clone(),
+            },
+        )?;
+        ctx.accounts.process(name, symbol, uri)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        let admin_log =
+            check_admin_context(&ctx, &ctx.accounts.state, &instruction::ResetCircuitBreaker)?;
+        ctx.accounts.process()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
     pub fn config_marinade(
         ctx: Context<ConfigMarinade>,
         params: ConfigMarinadeParams,
     ) -> Result<()> {
-        check_context(&ctx)?;
-        ctx.accounts.process(params)
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::ConfigMarinade { params },
+        )?;
+        ctx.accounts.process(params)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn propose_config(
+        ctx: Context<ProposeConfig>,
+        id: u64,
+        change: ConfigChange,
+    ) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::ProposeConfig {
+                id,
+                change: change.clone(),
+            },
+        )?;
+        ctx.accounts.process(id, change)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn execute_config_proposal(ctx: Context<ExecuteConfigProposal>) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::ExecuteConfigProposal,
+        )?;
+        ctx.accounts.process()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn cancel_config_proposal(ctx: Context<CancelConfigProposal>) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::CancelConfigProposal,
+        )?;
+        ctx.accounts.process()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
     }
 
     //-------------------------------------------------------------------------------------
@@ -161,7 +883,80 @@ pub mod marinade_finance {
         ctx.accounts.process(msol_amount)
     }
 
+    // the beneficiary lets crank_claim pay up to max_claim_tip of the ticket to its caller
+    pub fn order_unstake_with_claim_tip(
+        ctx: Context<OrderUnstake>,
+        msol_amount: u64,
+        max_claim_tip: Fee,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts
+            .process_with_claim_tip(msol_amount, max_claim_tip)
+    }
+
+    // one ticket per amount, the new ticket accounts are passed as remaining accounts
+    pub fn order_unstake_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, OrderUnstakeBatch<'info>>,
+        msol_amounts: Vec<u64>,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
+        ctx.accounts.process(msol_amounts, ctx.remaining_accounts)
+    }
+
     pub fn claim(ctx: Context<Claim>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(None)
+    }
+
+    // claims part of the ticket, the rest stays claimable
+    pub fn claim_partial(ctx: Context<Claim>, lamports: u64) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(Some(lamports))
+    }
+
+    // the new ticket gets lamports out of ticket_account, with the same created_epoch
+    pub fn split_ticket(ctx: Context<SplitTicket>, lamports: u64) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(lamports)
+    }
+
+    pub fn transfer_ticket(ctx: Context<TransferTicket>, new_beneficiary: Pubkey) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(new_beneficiary)
+    }
+
+    // void a ticket before it is due, getting the mSOL back
+    pub fn cancel_unstake_ticket(ctx: Context<CancelUnstakeTicket>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    // per-beneficiary list of pending tickets, filled by order_unstake and emptied by claim
+    pub fn init_ticket_registry(ctx: Context<InitTicketRegistry>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    // remaining_accounts: listed tickets to check, the ones no longer pending are removed
+    pub fn prune_ticket_registry<'info>(
+        ctx: Context<'_, '_, '_, 'info, PruneTicketRegistry<'info>>,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
+        ctx.accounts.process(ctx.remaining_accounts)
+    }
+
+    pub fn close_registry(ctx: Context<CloseRegistry>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    pub fn gc_ticket(ctx: Context<GcTicket>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    // closes the TicketEta of a closed ticket, rent back to its payer
+    pub fn close_ticket_eta(ctx: Context<CloseTicketEta>) -> Result<()> {
         check_context(&ctx)?;
         ctx.accounts.process()
     }
@@ -171,17 +966,87 @@ pub mod marinade_finance {
         ctx.accounts.process(validator_index)
     }
 
-    pub fn update_active(
-        ctx: Context<UpdateActive>,
+    pub fn rebalance_liq_pool(ctx: Context<RebalanceLiqPool>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    pub fn crank_claim(ctx: Context<CrankClaim>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    pub fn apply_oracle_scores(
+        ctx: Context<ApplyOracleScores>,
+        start_index: u32,
+        count: u32,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(start_index, count)
+    }
+
+    pub fn init_epoch_report(ctx: Context<InitEpochReport>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    pub fn init_validator_history(
+        ctx: Context<InitValidatorHistory>,
+        validator_index: u32,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(validator_index)
+    }
+
+    pub fn remove_dead_validator(
+        ctx: Context<RemoveDeadValidator>,
+        index: u32,
+        validator_vote: Pubkey,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(index, validator_vote)
+    }
+
+    pub fn tally_gauge(ctx: Context<TallyGauge>) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process()
+    }
+
+    // while state.treasury_split_enabled, the split destinations are passed as remaining accounts
+    pub fn update_active<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateActive<'info>>,
+        stake_index: u32,
+        validator_index: u32,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
+        ctx.accounts
+            .process(stake_index, validator_index, ctx.remaining_accounts)
+    }
+
+    pub fn unstake_delinquent(
+        ctx: Context<UnstakeDelinquent>,
         stake_index: u32,
         validator_index: u32,
     ) -> Result<()> {
         check_context(&ctx)?;
         ctx.accounts.process(stake_index, validator_index)
     }
-    pub fn update_deactivated(ctx: Context<UpdateDeactivated>, stake_index: u32) -> Result<()> {
-        check_context(&ctx)?;
-        ctx.accounts.process(stake_index)
+
+    // while state.treasury_split_enabled, the split destinations are passed as remaining accounts
+    pub fn update_deactivated<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateDeactivated<'info>>,
+        stake_index: u32,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
+        ctx.accounts.process(stake_index, ctx.remaining_accounts)
+    }
+
+    // while state.treasury_split_enabled, the split destinations are passed as remaining accounts
+    pub fn claim_vested_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimVestedFees<'info>>,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
+        ctx.accounts.process(ctx.remaining_accounts)
     }
 
     pub fn deactivate_stake(
@@ -198,8 +1063,19 @@ pub mod marinade_finance {
         stake_index: u32,
         validator_index: u32,
     ) -> Result<()> {
-        check_context(&ctx)?;
-        ctx.accounts.process(stake_index, validator_index)
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::EmergencyUnstake {
+                stake_index,
+                validator_index,
+            },
+        )?;
+        ctx.accounts.process(stake_index, validator_index)?;
+        admin_log.append(
+            &ctx.accounts.state,
+            ctx.accounts.validator_manager_authority.key(),
+        )
     }
 
     pub fn partial_unstake(
@@ -208,9 +1084,21 @@ pub mod marinade_finance {
         validator_index: u32,
         desired_unstake_amount: u64,
     ) -> Result<()> {
-        check_context(&ctx)?;
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::PartialUnstake {
+                stake_index,
+                validator_index,
+                desired_unstake_amount,
+            },
+        )?;
         ctx.accounts
-            .process(stake_index, validator_index, desired_unstake_amount)
+            .process(stake_index, validator_index, desired_unstake_amount)?;
+        admin_log.append(
+            &ctx.accounts.state,
+            ctx.accounts.validator_manager_authority.key(),
+        )
     }
 
     pub fn merge_stakes(
@@ -224,6 +1112,22 @@ pub mod marinade_finance {
             .process(destination_stake_index, source_stake_index, validator_index)
     }
 
+    // the sources after the first are passed as remaining accounts
+    pub fn merge_stakes_multi<'info>(
+        ctx: Context<'_, '_, '_, 'info, MergeStakesMulti<'info>>,
+        destination_stake_index: u32,
+        source_stake_indexes: Vec<u32>,
+        validator_index: u32,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
+        ctx.accounts.process(
+            destination_stake_index,
+            source_stake_indexes,
+            validator_index,
+            ctx.remaining_accounts,
+        )
+    }
+
     pub fn redelegate(
         ctx: Context<ReDelegate>,
         stake_index: u32,
@@ -237,14 +1141,119 @@ pub mod marinade_finance {
 
     // emergency pauses the contract
     pub fn pause(ctx: Context<EmergencyPause>) -> Result<()> {
-        check_context(&ctx)?;
-        ctx.accounts.pause()
+        let admin_log = check_admin_context(&ctx, &ctx.accounts.state, &instruction::Pause)?;
+        ctx.accounts.pause()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.pause_authority.key())
     }
 
     // resumes the contract
     pub fn resume(ctx: Context<EmergencyPause>) -> Result<()> {
-        check_context(&ctx)?;
-        ctx.accounts.resume()
+        let admin_log = check_admin_context(&ctx, &ctx.accounts.state, &instruction::Resume)?;
+        ctx.accounts.resume()?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.pause_authority.key())
+    }
+
+    pub fn set_pause_flags(ctx: Context<EmergencyPause>, pause_flags: u8) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::SetPauseFlags { pause_flags },
+        )?;
+        ctx.accounts.set_pause_flags(pause_flags)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.pause_authority.key())
+    }
+
+    pub fn init_pause_guardians(
+        ctx: Context<InitPauseGuardians>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::InitPauseGuardians {
+                guardians: guardians.clone(),
+                threshold,
+            },
+        )?;
+        ctx.accounts.process(guardians, threshold)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn set_pause_guardians(
+        ctx: Context<SetPauseGuardians>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::SetPauseGuardians {
+                guardians: guardians.clone(),
+                threshold,
+            },
+        )?;
+        ctx.accounts.process(guardians, threshold)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    // the guardians of the quorum sign as remaining accounts, after the AdminLog when enabled
+    pub fn guardian_pause<'info>(
+        ctx: Context<'_, '_, '_, 'info, GuardianPause<'info>>,
+        pause_flags: u8,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
+        let (admin_log, remaining_accounts) = AdminLogWriter::begin(
+            &ctx.accounts.state,
+            &instruction::GuardianPause { pause_flags },
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.process(pause_flags, remaining_accounts)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.pause_guardians.key())
+    }
+
+    pub fn init_treasury_vesting(
+        ctx: Context<InitTreasuryVesting>,
+        vesting_epochs: u64,
+    ) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::InitTreasuryVesting { vesting_epochs },
+        )?;
+        ctx.accounts
+            .process(vesting_epochs, *ctx.bumps.get("treasury_vesting").unwrap())?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    pub fn init_treasury_split(
+        ctx: Context<InitTreasurySplit>,
+        entries: Vec<TreasurySplitEntry>,
+    ) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::InitTreasurySplit {
+                entries: entries.clone(),
+            },
+        )?;
+        ctx.accounts.process(entries)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.treasury_authority.key())
+    }
+
+    pub fn set_treasury_split(
+        ctx: Context<SetTreasurySplit>,
+        entries: Vec<TreasurySplitEntry>,
+    ) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::SetTreasurySplit {
+                entries: entries.clone(),
+            },
+        )?;
+        ctx.accounts.process(entries)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.treasury_authority.key())
     }
 
     // immediate withdraw of an active stake account - feature can be enabled or disable by the DAO
@@ -260,13 +1269,42 @@ pub mod marinade_finance {
             .process(stake_index, validator_index, msol_amount, beneficiary)
     }
 
-    pub fn realloc_validator_list(ctx: Context<ReallocValidatorList>, capacity: u32) -> Result<()> {
+    // immediate fee-free unstake against the idle reserve, capped per epoch
+    pub fn instant_withdraw(ctx: Context<InstantWithdraw>, msol_amount: u64) -> Result<()> {
+        check_context(&ctx)?;
+        ctx.accounts.process(msol_amount)
+    }
+
+    // from now on the privileged instructions require the log as their first remaining account
+    pub fn init_admin_log(ctx: Context<InitAdminLog>) -> Result<()> {
         check_context(&ctx)?;
-        ctx.accounts.process(capacity)
+        ctx.accounts.process()
+    }
+
+    pub fn realloc_validator_list(ctx: Context<ReallocValidatorList>, capacity: u32) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::ReallocValidatorList { capacity },
+        )?;
+        ctx.accounts.process(capacity)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
     }
 
     pub fn realloc_stake_list(ctx: Context<ReallocStakeList>, capacity: u32) -> Result<()> {
+        let admin_log = check_admin_context(
+            &ctx,
+            &ctx.accounts.state,
+            &instruction::ReallocStakeList { capacity },
+        )?;
+        ctx.accounts.process(capacity)?;
+        admin_log.append(&ctx.accounts.state, ctx.accounts.admin_authority.key())
+    }
+
+    // grows a State account created by an older program version to the current layout.
+    // Once upgraded, the program fails on the old State until this is called
+    pub fn realloc_state(ctx: Context<ReallocState>, max_fee_change_per_epoch: Fee) -> Result<()> {
         check_context(&ctx)?;
-        ctx.accounts.process(capacity)
+        ctx.accounts.process(max_fee_change_per_epoch)
     }
 }
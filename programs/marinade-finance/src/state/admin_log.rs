@@ -0,0 +1,159 @@
+use crate::{error::MarinadeError, State, ID};
+use anchor_lang::{
+    prelude::*,
+    solana_program::hash::{hash, hashv, Hash},
+    InstructionData,
+};
+
+pub const ADMIN_LOG_CAPACITY: usize = 32;
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct AdminLogEntry {
+    pub index: u64, // sequence number of the entry
+    pub slot: u64,
+    pub unix_timestamp: i64,
+    pub authority: Pubkey,
+    pub action: [u8; 8], // discriminator of the instruction
+    // hash of the instruction data, the State before and the State after
+    pub changes_hash: [u8; 32],
+}
+
+/// Ring buffer of the last ADMIN_LOG_CAPACITY privileged instructions,
+/// entry #index is at entries[index % ADMIN_LOG_CAPACITY].
+/// PDA of [state, SEED], required by the privileged instructions once initialized
+#[account]
+#[derive(Debug)]
+pub struct AdminLog {
+    pub state_address: Pubkey,
+    pub count: u64, // total of entries appended, next index
+    pub entries: [AdminLogEntry; ADMIN_LOG_CAPACITY],
+}
+
+impl AdminLog {
+    pub const SEED: &'static [u8] = b"admin_log";
+
+    pub fn find_address(state: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes(), Self::SEED], &ID)
+    }
+
+    pub fn append(&mut self, mut entry: AdminLogEntry) {
+        entry.index = self.count;
+        self.entries[(self.count % ADMIN_LOG_CAPACITY as u64) as usize] = entry;
+        self.count += 1;
+    }
+
+    /// entries from the oldest to the newest
+    pub fn ordered(&self) -> impl Iterator<Item = &AdminLogEntry> {
+        let start = self.count.saturating_sub(ADMIN_LOG_CAPACITY as u64);
+        (start..self.count).map(|index| &self.entries[(index % ADMIN_LOG_CAPACITY as u64) as usize])
+    }
+}
+
+/// Records a privileged instruction into the AdminLog, passed as the first remaining account
+/// while state.admin_log_enabled: `begin` before processing, `append` after
+pub struct AdminLogWriter<'info> {
+    admin_log: Option<Account<'info, AdminLog>>,
+    action: [u8; 8],
+    data_hash: Hash,
+    state_hash: Hash,
+}
+
+impl<'info> AdminLogWriter<'info> {
+    /// returns the remaining accounts after the log
+    pub fn begin<'c>(
+        state: &Account<State>,
+        instruction: &impl InstructionData,
+        remaining_accounts: &'c [AccountInfo<'info>],
+    ) -> Result<(Self, &'c [AccountInfo<'info>])> {
+        let (admin_log, remaining_accounts) = if state.admin_log_enabled {
+            let (admin_log, remaining_accounts) = remaining_accounts
+                .split_first()
+                .ok_or_else(|| error!(MarinadeError::MissingAdminLog))?;
+            let admin_log = Account::<AdminLog>::try_from(admin_log)?;
+            require_keys_eq!(
+                admin_log.state_address,
+                state.key(),
+                MarinadeError::WrongAdminLog
+            );
+            (Some(admin_log), remaining_accounts)
+        } else {
+            (None, remaining_accounts)
+        };
+        let data = instruction.data();
+        let mut action = [0; 8];
+        action.copy_from_slice(&data[..8]);
+        Ok((
+            Self {
+                action,
+                data_hash: hash(&data),
+                state_hash: if admin_log.is_some() {
+                    hash(&state.try_to_vec()?)
+                } else {
+                    Hash::default()
+                },
+                admin_log,
+            },
+            remaining_accounts,
+        ))
+    }
+
+    pub fn append(self, state: &Account<State>, authority: Pubkey) -> Result<()> {
+        let mut admin_log = match self.admin_log {
+            Some(admin_log) => admin_log,
+            None => return Ok(()),
+        };
+        let clock = Clock::get()?;
+        admin_log.append(AdminLogEntry {
+            index: 0, // set by append
+            slot: clock.slot,
+            unix_timestamp: clock.unix_timestamp,
+            authority,
+            action: self.action,
+            changes_hash: hashv(&[
+                self.data_hash.as_ref(),
+                self.state_hash.as_ref(),
+                hash(&state.try_to_vec()?).as_ref(),
+            ])
+            .to_bytes(),
+        });
+        // not one of the instruction accounts, so not written back by anchor
+        admin_log.exit(&ID)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::{AdminLog, AdminLogEntry, ADMIN_LOG_CAPACITY};
+
+    #[test]
+    fn test_append() {
+        let mut log = AdminLog {
+            state_address: Pubkey::default(),
+            count: 0,
+            entries: [AdminLogEntry::default(); ADMIN_LOG_CAPACITY],
+        };
+        let entry = |slot| AdminLogEntry {
+            slot,
+            ..Default::default()
+        };
+        log.append(entry(1));
+        log.append(entry(2));
+        assert_eq!(
+            log.ordered().map(|e| (e.index, e.slot)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2)]
+        );
+        for slot in 3..=(ADMIN_LOG_CAPACITY as u64 + 3) {
+            log.append(entry(slot));
+        }
+        let ordered = log.ordered().collect::<Vec<_>>();
+        assert_eq!(ordered.len(), ADMIN_LOG_CAPACITY);
+        assert_eq!(ordered[0].index, 3);
+        assert_eq!(ordered[0].slot, 4);
+        assert_eq!(
+            ordered[ADMIN_LOG_CAPACITY - 1].slot,
+            ADMIN_LOG_CAPACITY as u64 + 3
+        );
+    }
+}
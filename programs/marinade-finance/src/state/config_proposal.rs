@@ -0,0 +1,52 @@
+use crate::{
+    instructions::{ConfigLpParams, ConfigMarinadeParams},
+    ID,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum ConfigChange {
+    Marinade(Box<ConfigMarinadeParams>), // boxed, much larger than the other variants
+    Lp(ConfigLpParams),
+    ResumeGuardianPause(u8), // PauseFlags bits paused by guardian_pause to resume
+}
+
+/// Config change waiting out State::config_timelock_seconds.
+/// PDA of [state, SEED, id as u64 le bytes]
+#[account]
+#[derive(Debug)]
+pub struct ConfigProposal {
+    pub state_address: Pubkey,
+    pub id: u64,
+    pub change: ConfigChange,
+    pub proposed_at: i64,
+    pub rent_payer: Pubkey, // refunded when executed or canceled
+}
+
+impl ConfigProposal {
+    pub const SEED: &'static [u8] = b"config_proposal";
+
+    pub fn find_address(state: &Pubkey, id: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes(), Self::SEED, &id.to_le_bytes()], &ID)
+    }
+
+    /// account space for a proposal of `change`
+    pub fn space(change: &ConfigChange) -> usize {
+        8 + ConfigProposal {
+            state_address: Pubkey::default(),
+            id: 0,
+            change: change.clone(),
+            proposed_at: 0,
+            rent_payer: Pubkey::default(),
+        }
+        .try_to_vec()
+        .unwrap()
+        .len()
+    }
+
+    /// the timelock in force at execution applies, so raising it also delays pending proposals
+    pub fn executable_at(&self, config_timelock_seconds: u64) -> i64 {
+        self.proposed_at
+            .saturating_add(config_timelock_seconds.min(i64::MAX as u64) as i64)
+    }
+}
@@ -1,10 +1,162 @@
 use anchor_lang::prelude::*;
+use std::ops::{Deref, DerefMut};
+
+use crate::{calc::proportional, state::Fee, ID};
 
 #[account]
 #[derive(Debug)]
 pub struct TicketAccountData {
     pub state_address: Pubkey, // instance of marinade state this ticket belongs to
-    pub beneficiary: Pubkey,   // main account where to send SOL when claimed
-    pub lamports_amount: u64,  // amount this ticked is worth
+    pub beneficiary: Pubkey, // main account where to send SOL when claimed, the ticket mint for tokenized tickets
+    pub lamports_amount: u64, // amount this ticked is worth
     pub created_epoch: u64, // epoch when this acc was created (epoch when delayed-unstake was requested)
+    pub extension: MaybeTicketExtension, // None in the accounts of ORIGINAL_SPACE
+}
+
+impl TicketAccountData {
+    pub const MINT_AUTHORITY_SEED: &'static [u8] = b"ticket_mint";
+    // the ticket accounts are created by the client, the ones of ORIGINAL_SPACE have no extension
+    pub const ORIGINAL_SPACE: usize = 8 + 32 + 32 + 8 + 8;
+    pub const SPACE: usize = Self::ORIGINAL_SPACE + TicketExtension::LEN;
+
+    pub fn find_mint_authority(state: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes()[..32], Self::MINT_AUTHORITY_SEED], &ID)
+    }
+
+    /// mSOL burned for lamports_amount, 0 when not recorded
+    pub fn msol_burned(&self) -> u64 {
+        self.extension.map_or(0, |extension| extension.msol_burned)
+    }
+
+    /// State::claim_tip is paid up to this, 0 unless the beneficiary opted in
+    pub fn max_claim_tip(&self) -> Fee {
+        self.extension
+            .map_or(Fee::default(), |extension| extension.max_claim_tip)
+    }
+
+    /// receives the rent of the ticket account from gc_ticket.
+    /// The beneficiary for the tickets without the record
+    pub fn rent_payer(&self) -> Pubkey {
+        self.extension
+            .map_or(self.beneficiary, |extension| extension.rent_payer)
+    }
+
+    /// takes lamports out of the ticket with their share of msol_burned, which is returned
+    pub fn take_lamports(&mut self, lamports: u64) -> Result<u64> {
+        let lamports_amount = self.lamports_amount;
+        let msol_burned = match self.extension.as_mut() {
+            Some(extension) => {
+                let share = proportional(extension.msol_burned, lamports, lamports_amount)?;
+                extension.msol_burned -= share;
+                share
+            }
+            None => 0,
+        };
+        self.lamports_amount -= lamports;
+        Ok(msol_burned)
+    }
+}
+
+/// Ticket fields stored after the original ones, in the ticket accounts of TicketAccountData::SPACE
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct TicketExtension {
+    pub msol_burned: u64, // for lamports_amount, cancel_unstake_ticket re-mints at most this
+    // crank_claim tip accepted by the beneficiary, set by order_unstake_with_claim_tip
+    pub max_claim_tip: Fee,
+    pub rent_payer: Pubkey, // funded the ticket account, gets its rent back from gc_ticket
+}
+
+impl TicketExtension {
+    pub const LEN: usize = 8 + 4 + 32;
+}
+
+// serialized without the Option tag: read when the account has room for it,
+// so a new ticket gets Some when its account was created with TicketAccountData::SPACE
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MaybeTicketExtension(pub Option<TicketExtension>);
+
+impl AnchorSerialize for MaybeTicketExtension {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        if let Some(extension) = &self.0 {
+            extension.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl AnchorDeserialize for MaybeTicketExtension {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        if buf.len() < TicketExtension::LEN {
+            return Ok(Self(None));
+        }
+        Ok(Self(Some(AnchorDeserialize::deserialize(buf)?)))
+    }
+}
+
+impl Deref for MaybeTicketExtension {
+    type Target = Option<TicketExtension>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for MaybeTicketExtension {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::{MaybeTicketExtension, TicketAccountData, TicketExtension};
+    use crate::state::Fee;
+
+    #[test]
+    fn test_extension_needs_room() -> Result<()> {
+        let mut ticket = TicketAccountData {
+            state_address: Pubkey::new_unique(),
+            beneficiary: Pubkey::new_unique(),
+            lamports_amount: 3_000,
+            created_epoch: 10,
+            extension: MaybeTicketExtension(None),
+        };
+        let mut data = vec![];
+        ticket.try_serialize(&mut data)?;
+        assert_eq!(data.len(), TicketAccountData::ORIGINAL_SPACE);
+        // a ticket of the original size reads without extension
+        let original = TicketAccountData::try_deserialize(&mut &data[..])?;
+        assert_eq!(original.extension, MaybeTicketExtension(None));
+        assert_eq!(original.msol_burned(), 0);
+        assert_eq!(original.rent_payer(), original.beneficiary);
+
+        // an account created with SPACE has room for it
+        data.resize(TicketAccountData::SPACE, 0);
+        let zeroed = TicketAccountData::try_deserialize(&mut &data[..])?;
+        assert_eq!(
+            zeroed.extension,
+            MaybeTicketExtension(Some(TicketExtension::default()))
+        );
+
+        ticket.extension = MaybeTicketExtension(Some(TicketExtension {
+            msol_burned: 2_000,
+            max_claim_tip: Fee::from_basis_points(10),
+            rent_payer: Pubkey::new_unique(),
+        }));
+        let mut data = vec![];
+        ticket.try_serialize(&mut data)?;
+        assert_eq!(data.len(), TicketAccountData::SPACE);
+        let mut ticket = TicketAccountData::try_deserialize(&mut &data[..])?;
+        assert_eq!(ticket.msol_burned(), 2_000);
+        assert_eq!(ticket.max_claim_tip(), Fee::from_basis_points(10));
+
+        assert_eq!(ticket.take_lamports(1_500)?, 1_000);
+        assert_eq!(ticket.lamports_amount, 1_500);
+        assert_eq!(ticket.msol_burned(), 1_000);
+        assert_eq!(ticket.take_lamports(1_500)?, 1_000);
+        assert_eq!(ticket.msol_burned(), 0);
+        Ok(())
+    }
 }
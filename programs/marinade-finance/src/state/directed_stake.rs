@@ -0,0 +1,68 @@
+use crate::{calc::proportional, ID};
+use anchor_lang::prelude::*;
+
+/// Stake directed by depositors to a validator of the list.
+/// PDA of [state, SEED, validator_vote], kept outside of the ValidatorRecord whose size is fixed
+/// by the existing validator lists. The cranks honor the directed lamports up to
+/// State::directed_stake_max_share of the total stake target, the rest is spread by score
+#[account]
+#[derive(Debug)]
+pub struct DirectedStakeRecord {
+    pub state_address: Pubkey,
+    pub validator_account: Pubkey,
+    pub directed_lamports: u64,
+}
+
+impl DirectedStakeRecord {
+    pub const SEED: &'static [u8] = b"directed_stake";
+
+    pub fn find_address(state: &Pubkey, validator_account: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[&state.to_bytes(), Self::SEED, &validator_account.to_bytes()],
+            &ID,
+        )
+    }
+
+    /// `info` is the PDA of the validator when passed, checked by the caller.
+    /// None when nothing was directed to the validator
+    pub fn load(info: Option<&AccountInfo>) -> Result<Option<Self>> {
+        match info {
+            Some(info) if info.owner == &ID && !info.data_is_empty() => {
+                Ok(Some(Self::try_deserialize(&mut &info.data.borrow()[..])?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// part of directed_lamports the cranks stake into the validator.
+    /// When all the directed stake is over max_directed every record is scaled down alike
+    pub fn honored_lamports(&self, total_directed: u64, max_directed: u64) -> Result<u64> {
+        if total_directed <= max_directed {
+            return Ok(self.directed_lamports);
+        }
+        proportional(self.directed_lamports, max_directed, total_directed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::DirectedStakeRecord;
+
+    #[test]
+    fn test_honored_lamports() -> Result<()> {
+        let record = DirectedStakeRecord {
+            state_address: Pubkey::default(),
+            validator_account: Pubkey::default(),
+            directed_lamports: 300,
+        };
+        // under the cap everything is honored
+        assert_eq!(record.honored_lamports(1_000, 1_000)?, 300);
+        assert_eq!(record.honored_lamports(1_000, 5_000)?, 300);
+        // over the cap the record keeps its share of the cap
+        assert_eq!(record.honored_lamports(1_000, 500)?, 150);
+        assert_eq!(record.honored_lamports(1_000, 0)?, 0);
+        Ok(())
+    }
+}
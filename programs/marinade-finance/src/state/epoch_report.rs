@@ -0,0 +1,63 @@
+use crate::ID;
+use anchor_lang::prelude::*;
+
+/// Totals of the crank work of one epoch, written by the cranks when passed.
+/// PDA of [state, SEED, epoch as u64 le bytes]. Initialized before the first update of the epoch,
+/// it is complete once every stake account listed at init went through update_active or update_deactivated
+#[account]
+#[derive(Debug)]
+pub struct EpochReport {
+    pub state_address: Pubkey,
+    pub epoch: u64,
+    pub rewards: u64, // staking and MEV rewards observed by the update cranks
+    pub treasury_msol_fees: u64, // protocol fees minted to the treasury
+    pub stake_activated: u64, // by stake_reserve
+    pub stake_deactivated: u64, // by deactivate_stake
+    pub stake_accounts_to_update: u32, // stake list length at init
+    pub stake_accounts_updated: u32,
+    pub complete: bool,
+}
+
+impl EpochReport {
+    pub const SEED: &'static [u8] = b"epoch_report";
+
+    pub fn find_address(state: &Pubkey, epoch: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes(), Self::SEED, &epoch.to_le_bytes()], &ID)
+    }
+
+    /// first update of a stake account in the epoch
+    pub fn on_stake_account_updated(&mut self, rewards: u64, treasury_msol_fees: u64) {
+        self.rewards += rewards;
+        self.treasury_msol_fees += treasury_msol_fees;
+        self.stake_accounts_updated += 1;
+        self.complete = self.stake_accounts_updated >= self.stake_accounts_to_update;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::EpochReport;
+
+    #[test]
+    fn test_completion() {
+        let mut report = EpochReport {
+            state_address: Pubkey::default(),
+            epoch: 500,
+            rewards: 0,
+            treasury_msol_fees: 0,
+            stake_activated: 0,
+            stake_deactivated: 0,
+            stake_accounts_to_update: 2,
+            stake_accounts_updated: 0,
+            complete: false,
+        };
+        report.on_stake_account_updated(1_000, 60);
+        assert!(!report.complete);
+        report.on_stake_account_updated(500, 30);
+        assert!(report.complete);
+        assert_eq!(report.rewards, 1_500);
+        assert_eq!(report.treasury_msol_fees, 90);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{error::MarinadeError, state::Fee};
+use anchor_lang::prelude::*;
+
+/// Bounds how far the admin can move a fee within one epoch.
+/// Each fee is compared to its value before its first change of the epoch
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct FeeRateLimiter {
+    pub max_change_per_epoch: Fee, // set at initialize
+    pub epoch: u64,
+    pub changed: u8, // bit per FeeRateLimiter index changed during `epoch`
+    pub bases: [Fee; 4],
+}
+
+impl FeeRateLimiter {
+    pub const MAX_CHANGE_PER_EPOCH: Fee = Fee::from_basis_points(500); // 5% per epoch hard cap
+
+    pub const REWARD_FEE: usize = 0;
+    pub const LP_MIN_FEE: usize = 1;
+    pub const LP_MAX_FEE: usize = 2;
+    pub const TREASURY_CUT: usize = 3;
+
+    pub fn new(max_change_per_epoch: Fee) -> Result<Self> {
+        require!(
+            max_change_per_epoch.basis_points > 0
+                && max_change_per_epoch <= Self::MAX_CHANGE_PER_EPOCH,
+            MarinadeError::InvalidMaxFeeChangePerEpoch
+        );
+        Ok(Self {
+            max_change_per_epoch,
+            ..Default::default()
+        })
+    }
+
+    pub fn check_change(&mut self, index: usize, old: Fee, new: Fee, epoch: u64) -> Result<()> {
+        if epoch != self.epoch {
+            self.epoch = epoch;
+            self.changed = 0;
+        }
+        if self.changed & (1 << index) == 0 {
+            self.bases[index] = old;
+            self.changed |= 1 << index;
+        }
+        require_gte!(
+            self.max_change_per_epoch.basis_points,
+            new.basis_points.abs_diff(self.bases[index].basis_points),
+            MarinadeError::FeeChangeIsTooHigh
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FeeRateLimiter;
+    use crate::state::Fee;
+
+    #[test]
+    fn test_check_change() {
+        let mut limiter = FeeRateLimiter::new(Fee::from_basis_points(100)).unwrap();
+        let fee = |bp| Fee::from_basis_points(bp);
+        let index = FeeRateLimiter::REWARD_FEE;
+        // steps add up within the epoch
+        assert!(limiter.check_change(index, fee(200), fee(260), 10).is_ok());
+        assert!(limiter.check_change(index, fee(260), fee(300), 10).is_ok());
+        assert!(limiter.check_change(index, fee(300), fee(301), 10).is_err());
+        // other fees have their own base
+        assert!(limiter
+            .check_change(FeeRateLimiter::TREASURY_CUT, fee(0), fee(100), 10)
+            .is_ok());
+        // the next epoch starts from the current value
+        assert!(limiter.check_change(index, fee(300), fee(200), 11).is_ok());
+        assert!(limiter.check_change(index, fee(200), fee(199), 11).is_err());
+
+        assert!(FeeRateLimiter::new(fee(0)).is_err());
+        assert!(FeeRateLimiter::new(fee(501)).is_err());
+    }
+}
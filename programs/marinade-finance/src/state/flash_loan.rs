@@ -0,0 +1,70 @@
+use crate::{error::MarinadeError, state::Fee};
+use anchor_lang::prelude::*;
+
+/// Transient record of a flash loan from the liq-pool SOL leg.
+/// Only set between flash_loan_sol and flash_repay_sol of the same transaction
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct FlashLoan {
+    pub in_progress: bool,
+    pub sol_leg_target: u64, // sol leg lamports required at the end (balance before + fee)
+    pub repay_lamports: u64, // lent lamports + fee, paid by the borrower in flash_repay_sol
+}
+
+impl FlashLoan {
+    pub fn check_not_in_progress(&self) -> Result<()> {
+        require!(!self.in_progress, MarinadeError::FlashLoanInProgress);
+        Ok(())
+    }
+
+    pub fn begin(&mut self, sol_leg_balance: u64, lamports: u64, fee: Fee) -> Result<()> {
+        self.check_not_in_progress()?;
+        let fee_lamports = fee.apply(lamports);
+        *self = Self {
+            in_progress: true,
+            sol_leg_target: sol_leg_balance + fee_lamports,
+            repay_lamports: lamports + fee_lamports,
+        };
+        Ok(())
+    }
+
+    /// lamports the borrower must pay back, whatever else reached the sol leg meanwhile
+    pub fn owed(&self) -> Result<u64> {
+        require!(self.in_progress, MarinadeError::FlashLoanNotInProgress);
+        Ok(self.repay_lamports)
+    }
+
+    pub fn end(&mut self, sol_leg_balance: u64) -> Result<()> {
+        require!(self.in_progress, MarinadeError::FlashLoanNotInProgress);
+        require_gte!(
+            sol_leg_balance,
+            self.sol_leg_target,
+            MarinadeError::FlashLoanNotRepaid
+        );
+        *self = Self::default();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::FlashLoan;
+    use crate::state::Fee;
+
+    #[test]
+    fn test_loan() -> Result<()> {
+        let mut loan = FlashLoan::default();
+        assert!(loan.owed().is_err());
+        loan.begin(1_000_000, 100_000, Fee::from_basis_points(30))?;
+        assert!(loan
+            .begin(1_000_000, 1, Fee::from_basis_points(30))
+            .is_err());
+        // lamports + fee, even if something else refilled the sol leg
+        assert_eq!(loan.owed()?, 100_300);
+        assert!(loan.end(1_000_000).is_err());
+        loan.end(1_000_300)?;
+        assert_eq!(loan, FlashLoan::default());
+        Ok(())
+    }
+}
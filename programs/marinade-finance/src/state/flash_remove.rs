@@ -0,0 +1,102 @@
+use crate::{error::MarinadeError, state::Fee};
+use anchor_lang::prelude::*;
+
+/// Transient record of a flash removal from the liq-pool.
+/// Only set between begin_flash_remove_liquidity and end_flash_remove_liquidity of the same transaction
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct FlashRemove {
+    pub in_progress: bool,
+    pub sol_leg_target: u64, // sol leg lamports required at the end (balance before + fee)
+    pub msol_leg_target: u64, // msol leg amount required at the end (balance before + fee)
+}
+
+impl FlashRemove {
+    /// liq-pool instructions must not run on the drained legs of an open flash removal
+    pub fn check_not_in_progress(&self) -> Result<()> {
+        require!(!self.in_progress, MarinadeError::FlashRemoveInProgress);
+        Ok(())
+    }
+
+    pub fn begin(
+        &mut self,
+        sol_leg_balance: u64,
+        msol_leg_balance: u64,
+        sol_out_amount: u64,
+        msol_out_amount: u64,
+        fee: Fee,
+    ) -> Result<()> {
+        self.check_not_in_progress()?;
+        *self = Self {
+            in_progress: true,
+            sol_leg_target: sol_leg_balance + fee.apply(sol_out_amount),
+            msol_leg_target: msol_leg_balance + fee.apply(msol_out_amount),
+        };
+        Ok(())
+    }
+
+    /// both legs must hold at least what they held before the removal plus the fee
+    pub fn end(&mut self, sol_leg_balance: u64, msol_leg_balance: u64) -> Result<()> {
+        require!(self.in_progress, MarinadeError::FlashRemoveNotInProgress);
+        require_gte!(
+            sol_leg_balance,
+            self.sol_leg_target,
+            MarinadeError::FlashRemoveNotRestored
+        );
+        require_gte!(
+            msol_leg_balance,
+            self.msol_leg_target,
+            MarinadeError::FlashRemoveNotRestored
+        );
+        *self = Self::default();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::FlashRemove;
+    use crate::state::Fee;
+
+    #[test]
+    fn test_restored() -> Result<()> {
+        let mut flash = FlashRemove::default();
+        flash.begin(
+            1_000_000,
+            500_000,
+            100_000,
+            50_000,
+            Fee::from_basis_points(30),
+        )?;
+        assert!(flash.in_progress);
+        assert_eq!(flash.sol_leg_target, 1_000_300);
+        assert_eq!(flash.msol_leg_target, 500_150);
+        // the flag blocks nested flash removals
+        assert!(flash.begin(1, 1, 1, 1, Fee::from_basis_points(30)).is_err());
+
+        flash.end(1_000_300, 500_200)?;
+        assert_eq!(flash, FlashRemove::default());
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_restored() -> Result<()> {
+        let mut flash = FlashRemove::default();
+        assert!(flash.end(0, 0).is_err());
+
+        flash.begin(
+            1_000_000,
+            500_000,
+            100_000,
+            50_000,
+            Fee::from_basis_points(30),
+        )?;
+        // principal returned without the fee
+        assert!(flash.end(1_000_000, 500_150).is_err());
+        assert!(flash.end(1_000_300, 500_000).is_err());
+        // a failed end leaves the flag set, the whole transaction fails anyway
+        assert!(flash.in_progress);
+        Ok(())
+    }
+}
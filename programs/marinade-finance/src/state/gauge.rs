@@ -0,0 +1,94 @@
+use crate::{calc::proportional, error::MarinadeError, ID};
+use anchor_lang::prelude::*;
+
+/// mSOL locked by holders in favor of a validator of the list.
+/// PDA of [state, SEED, validator_vote]. The locked mSOL becomes the gauge weight
+/// once tallied by tally_gauge, at most once per epoch, and the cranks spread
+/// State::gauge_stake_share of the stake target by those weights
+#[account]
+#[derive(Debug)]
+pub struct Gauge {
+    pub state_address: Pubkey,
+    pub validator_account: Pubkey,
+    pub locked_msol: u64,
+    pub weight: u64, // locked_msol at tally_epoch, part of State::total_gauge_weight
+    pub tally_epoch: u64,
+}
+
+impl Gauge {
+    pub const SEED: &'static [u8] = b"gauge";
+    pub const VAULT_AUTHORITY_SEED: &'static [u8] = b"gauge_vault";
+
+    pub fn find_address(state: &Pubkey, validator_account: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[&state.to_bytes(), Self::SEED, &validator_account.to_bytes()],
+            &ID,
+        )
+    }
+
+    /// `info` is the PDA of the validator when passed, checked by the caller.
+    /// None when the validator has no gauge
+    pub fn load(info: Option<&AccountInfo>) -> Result<Option<Self>> {
+        match info {
+            Some(info) if info.owner == &ID && !info.data_is_empty() => {
+                Ok(Some(Self::try_deserialize(&mut &info.data.borrow()[..])?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// returns the previous weight
+    pub fn tally(&mut self, epoch: u64) -> Result<u64> {
+        require_gt!(epoch, self.tally_epoch, MarinadeError::GaugeAlreadyTallied);
+        let old_weight = self.weight;
+        self.weight = self.locked_msol;
+        self.tally_epoch = epoch;
+        Ok(old_weight)
+    }
+
+    /// part of gauge_lamports going to the validator
+    pub fn gauge_lamports(&self, gauge_lamports: u64, total_weight: u64) -> Result<u64> {
+        if total_weight == 0 {
+            return Ok(0);
+        }
+        proportional(self.weight, gauge_lamports, total_weight)
+    }
+}
+
+/// mSOL of one holder locked into a gauge until unlock_epoch, one account per lock
+#[account]
+#[derive(Debug)]
+pub struct GaugeLock {
+    pub state_address: Pubkey,
+    pub gauge: Pubkey,
+    pub owner: Pubkey,
+    pub msol_amount: u64,
+    pub unlock_epoch: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::Gauge;
+
+    #[test]
+    fn test_tally() -> Result<()> {
+        let mut gauge = Gauge {
+            state_address: Pubkey::default(),
+            validator_account: Pubkey::default(),
+            locked_msol: 300,
+            weight: 100,
+            tally_epoch: 10,
+        };
+        assert!(gauge.tally(10).is_err());
+        assert_eq!(gauge.tally(11)?, 100);
+        assert_eq!(gauge.weight, 300);
+        // locks after the tally count from the next one
+        gauge.locked_msol = 500;
+        assert!(gauge.tally(11).is_err());
+        assert_eq!(gauge.gauge_lamports(1_000, 600)?, 500);
+        assert_eq!(gauge.gauge_lamports(1_000, 0)?, 0);
+        Ok(())
+    }
+}
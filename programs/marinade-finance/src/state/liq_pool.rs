@@ -1,5 +1,14 @@
-use crate::{calc::proportional, error::MarinadeError, require_lte, state::Fee, ID};
-use anchor_lang::{prelude::*, solana_program::native_token::LAMPORTS_PER_SOL};
+use crate::{
+    calc::{proportional, shares_from_value},
+    error::MarinadeError,
+    require_lte,
+    state::Fee,
+    ID,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::PubkeyError},
+};
 use anchor_spl::token::spl_token;
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug)]
@@ -31,9 +40,11 @@ impl LiqPool {
     pub const SOL_LEG_SEED: &'static [u8] = b"liq_sol";
     pub const MSOL_LEG_AUTHORITY_SEED: &'static [u8] = b"liq_st_sol_authority";
     pub const MSOL_LEG_SEED: &'static str = "liq_st_sol";
+    pub const RECEIPT_AUTHORITY_SEED: &'static [u8] = b"liq_receipt";
     pub const MAX_FEE: Fee = Fee::from_basis_points(1000); // 10%
     pub const MIN_LIQUIDITY_TARGET: u64 = 50 * LAMPORTS_PER_SOL; // 50 SOL
     pub const MAX_TREASURY_CUT: Fee = Fee::from_basis_points(7500); // 75%
+    pub const MAX_KEEPER_TIP: Fee = Fee::from_basis_points(100); // 1% of the SOL output
 
     pub fn find_lp_mint_authority(state: &Pubkey) -> (Pubkey, u8) {
         Pubkey::find_program_address(
@@ -53,6 +64,26 @@ impl LiqPool {
         )
     }
 
+    /// msol leg authority from the bump stored at initialize, no bump search.
+    /// Same derivation the `bump = state.liq_pool.msol_leg_authority_bump_seed` constraints do
+    pub fn msol_leg_authority(&self, state: &Pubkey) -> std::result::Result<Pubkey, PubkeyError> {
+        Pubkey::create_program_address(
+            &[
+                &state.to_bytes()[..32],
+                Self::MSOL_LEG_AUTHORITY_SEED,
+                &[self.msol_leg_authority_bump_seed],
+            ],
+            &ID,
+        )
+    }
+
+    pub fn find_receipt_authority(state: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[&state.to_bytes()[..32], Self::RECEIPT_AUTHORITY_SEED],
+            &ID,
+        )
+    }
+
     pub fn default_msol_leg_address(state: &Pubkey) -> Pubkey {
         Pubkey::create_with_seed(state, Self::MSOL_LEG_SEED, &spl_token::ID).unwrap()
     }
@@ -76,6 +107,53 @@ impl LiqPool {
         }
     }
 
+    /// liquid-unstake fee for taking `remove_lamports` out of `sol_leg_available`,
+    /// computed on the liquidity left *after* the removal
+    pub fn liquid_unstake_fee(&self, sol_leg_available: u64, remove_lamports: u64) -> Fee {
+        if remove_lamports >= sol_leg_available {
+            // removing all liquidity
+            self.lp_max_fee
+        } else {
+            self.linear_fee(sol_leg_available - remove_lamports)
+        }
+    }
+
+    /// what all the LP together can claim: available SOL leg + mSOL leg value.
+    /// msol_leg_value must be already converted to lamports (State::msol_to_sol)
+    pub fn total_claimable_lamports(sol_leg_available: u64, msol_leg_value: u64) -> Result<u64> {
+        sol_leg_available
+            .checked_add(msol_leg_value)
+            .ok_or_else(|| error!(MarinadeError::CalculationFailure))
+    }
+
+    /// SOL share of the pool value in basis points.
+    /// msol_leg_value must be already converted to lamports (State::msol_to_sol)
+    /// An empty pool is reported as all SOL
+    pub fn leg_ratio_bps(sol_leg_available: u64, msol_leg_value: u64) -> u16 {
+        let total = sol_leg_available as u128 + msol_leg_value as u128;
+        if total == 0 {
+            return Fee::MAX_BASIS_POINTS as u16;
+        }
+        // LMT no error possible, result <= MAX_BASIS_POINTS
+        (sol_leg_available as u128 * Fee::MAX_BASIS_POINTS as u128 / total) as u16
+    }
+
+    /// lamports value of the mSOL leg above `max_msol_share` of the pool value, 0 share = no limit.
+    /// msol_leg_value must be already converted to lamports (State::msol_to_sol)
+    pub fn excess_msol_value(
+        sol_leg_available: u64,
+        msol_leg_value: u64,
+        max_msol_share: Fee,
+    ) -> u64 {
+        if max_msol_share.basis_points == 0 {
+            return 0;
+        }
+        let total = sol_leg_available as u128 + msol_leg_value as u128;
+        // LMT no error possible, allowed <= total * 100% and the result <= msol_leg_value
+        let allowed = total * max_msol_share.basis_points as u128 / Fee::MAX_BASIS_POINTS as u128;
+        (msol_leg_value as u128).saturating_sub(allowed) as u64
+    }
+
     pub fn on_lp_mint(&mut self, amount: u64) {
         self.lp_supply += amount
     }
@@ -84,6 +162,48 @@ impl LiqPool {
         self.lp_supply -= amount
     }
 
+    /// LP credited for adding `value` lamports to a pool worth `pool_value`, like add_liquidity.
+    /// No bonus on top: it would be paid by diluting the other LPs
+    pub fn rebalance_credit_lp(value: u64, pool_value: u64, lp_supply: u64) -> Result<u64> {
+        shares_from_value(value, pool_value, lp_supply)
+    }
+
+    /// splits the SOL output of a removal into (user part, keeper tip)
+    pub fn split_keeper_tip(sol_out_amount: u64, tip: u64) -> Result<(u64, u64)> {
+        require_lte!(
+            tip,
+            Self::MAX_KEEPER_TIP.apply(sol_out_amount),
+            MarinadeError::KeeperTipTooHigh
+        );
+        Ok((sol_out_amount - tip, tip))
+    }
+
+    /// Self-heal of the virtual lp_supply: it only follows the mint supply downwards (LP burned by its owners).
+    /// A mint supply above the virtual one means LP minted without the program and is never trusted here,
+    /// it fails until the admin accepts it with force_sync_lp_supply_up
+    pub fn sync_lp_supply(&mut self, lp_mint_supply: u64) -> Result<()> {
+        require_lte!(
+            lp_mint_supply,
+            self.lp_supply,
+            MarinadeError::UnregisteredLPMinted
+        );
+        self.lp_supply = lp_mint_supply;
+        Ok(())
+    }
+
+    /// Accept a mint supply above the virtual one, e.g. after a governance-authorized mint.
+    /// Returns the old virtual supply
+    pub fn force_sync_lp_supply_up(&mut self, lp_mint_supply: u64) -> Result<u64> {
+        require_gt!(
+            lp_mint_supply,
+            self.lp_supply,
+            MarinadeError::LpSupplyNotAboveVirtual
+        );
+        let old = self.lp_supply;
+        self.lp_supply = lp_mint_supply;
+        Ok(old)
+    }
+
     pub fn check_liquidity_cap(
         &self,
         transfering_lamports: u64,
@@ -133,3 +253,158 @@ impl LiqPool {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::LiqPool;
+    use crate::{error::MarinadeError, state::Fee};
+
+    fn liq_pool(msol_leg_authority_bump_seed: u8) -> LiqPool {
+        LiqPool {
+            lp_mint: Pubkey::new_unique(),
+            lp_mint_authority_bump_seed: 0,
+            sol_leg_bump_seed: 0,
+            msol_leg_authority_bump_seed,
+            msol_leg: Pubkey::new_unique(),
+            lp_liquidity_target: LiqPool::MIN_LIQUIDITY_TARGET,
+            lp_max_fee: Fee::from_basis_points(300),
+            lp_min_fee: Fee::from_basis_points(30),
+            treasury_cut: Fee::from_basis_points(2500),
+            lp_supply: 0,
+            lent_from_sol_leg: 0,
+            liquidity_sol_cap: u64::MAX,
+        }
+    }
+
+    #[test]
+    fn test_rebalance_credit_keeps_lp_value() -> Result<()> {
+        for (pool_value, lp_supply) in [
+            (1_000_000_000, 1_000_000_000),
+            (12_345_678_901, 10_000_000_000),
+            (7, 3),
+            (0, 0),
+        ] {
+            for value in [1, 1_000, 987_654_321] {
+                let shares = LiqPool::rebalance_credit_lp(value, pool_value, lp_supply)?;
+                let new_supply = (lp_supply + shares) as u128;
+                let new_value = (pool_value + value) as u128;
+                // holders that stay keep their value per LP
+                assert!(
+                    new_value * lp_supply as u128 >= pool_value as u128 * new_supply,
+                    "pool {:?} value {}",
+                    (pool_value, lp_supply),
+                    value
+                );
+                if pool_value > 0 {
+                    // the credit is never worth more than the value added
+                    assert!(
+                        shares as u128 * pool_value as u128 <= value as u128 * lp_supply as u128
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_keeper_tip() -> Result<()> {
+        assert_eq!(LiqPool::split_keeper_tip(1_000_000, 0)?, (1_000_000, 0));
+        assert_eq!(
+            LiqPool::split_keeper_tip(1_000_000, 4_000)?,
+            (996_000, 4_000)
+        );
+        // capped at MAX_KEEPER_TIP of the SOL output
+        assert_eq!(
+            LiqPool::split_keeper_tip(1_000_000, 10_000)?,
+            (990_000, 10_000)
+        );
+        assert!(LiqPool::split_keeper_tip(1_000_000, 10_001).is_err());
+        assert!(LiqPool::split_keeper_tip(0, 1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sync_lp_supply() -> Result<()> {
+        let mut pool = liq_pool(255);
+        pool.lp_supply = 1_000;
+        // burned outside of the program
+        pool.sync_lp_supply(900)?;
+        assert_eq!(pool.lp_supply, 900);
+        // minted outside of the program: refused by default
+        assert_eq!(
+            pool.sync_lp_supply(1_100),
+            Err(MarinadeError::UnregisteredLPMinted.into())
+        );
+        assert_eq!(pool.lp_supply, 900);
+        // admin override
+        assert_eq!(pool.force_sync_lp_supply_up(1_100)?, 900);
+        assert_eq!(pool.lp_supply, 1_100);
+        // the override only goes up
+        assert!(pool.force_sync_lp_supply_up(1_100).is_err());
+        assert!(pool.force_sync_lp_supply_up(1_000).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stored_msol_leg_authority_bump() {
+        let state = Pubkey::new_unique();
+        let (authority, bump) = LiqPool::find_msol_leg_authority(&state);
+        assert_eq!(liq_pool(bump).msol_leg_authority(&state), Ok(authority));
+        // a wrong stored bump never derives the authority
+        for wrong_bump in (0..=u8::MAX).filter(|b| *b != bump) {
+            assert_ne!(
+                liq_pool(wrong_bump).msol_leg_authority(&state),
+                Ok(authority)
+            );
+        }
+    }
+
+    #[test]
+    fn test_leg_ratio_bps() {
+        assert_eq!(LiqPool::leg_ratio_bps(1_000, 0), 10_000);
+        assert_eq!(LiqPool::leg_ratio_bps(0, 1_000), 0);
+        assert_eq!(LiqPool::leg_ratio_bps(0, 0), 10_000);
+        assert_eq!(LiqPool::leg_ratio_bps(500, 500), 5_000);
+        assert_eq!(LiqPool::leg_ratio_bps(3, 1), 7_500);
+        assert_eq!(LiqPool::leg_ratio_bps(1, 2), 3_333);
+        assert_eq!(LiqPool::leg_ratio_bps(u64::MAX, u64::MAX), 5_000);
+        assert_eq!(LiqPool::leg_ratio_bps(1, u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_liquid_unstake_fee() {
+        let pool = liq_pool(0);
+        let target = pool.lp_liquidity_target;
+        // enough liquidity left
+        assert_eq!(pool.liquid_unstake_fee(target * 2, target), pool.lp_min_fee);
+        // half of the target left
+        assert_eq!(
+            pool.liquid_unstake_fee(target, target / 2),
+            Fee::from_basis_points(165)
+        );
+        // draining the leg
+        assert_eq!(pool.liquid_unstake_fee(target, target), pool.lp_max_fee);
+        assert_eq!(pool.liquid_unstake_fee(0, 1), pool.lp_max_fee);
+    }
+
+    #[test]
+    fn test_excess_msol_value() {
+        let share = Fee::from_basis_points(6_000);
+        assert_eq!(LiqPool::excess_msol_value(300, 700, share), 100);
+        assert_eq!(LiqPool::excess_msol_value(400, 600, share), 0);
+        assert_eq!(LiqPool::excess_msol_value(0, 1_000, share), 400);
+        // no limit
+        assert_eq!(
+            LiqPool::excess_msol_value(0, 1_000, Fee::from_basis_points(0)),
+            0
+        );
+        // no overflow on large legs
+        assert_eq!(LiqPool::excess_msol_value(u64::MAX, u64::MAX, share), 0);
+        assert_eq!(
+            LiqPool::excess_msol_value(0, u64::MAX, Fee::from_basis_points(5_000)),
+            u64::MAX - u64::MAX / 2
+        );
+    }
+}
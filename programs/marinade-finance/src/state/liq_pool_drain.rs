@@ -0,0 +1,75 @@
+use crate::{error::MarinadeError, ID};
+use anchor_lang::prelude::*;
+
+/// Record of the liq-pool legs moved to the emergency escrow by emergency_drain_liq_pool.
+/// While set no liq-pool instruction runs, LP tokens can not be burned
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct LiqPoolDrain {
+    pub drained: bool,
+    pub escrow_bump_seed: u8,
+    pub msol_escrow: Pubkey, // token account owned by the escrow PDA
+    pub drained_lamports: u64,
+    pub drained_msol: u64,
+}
+
+impl LiqPoolDrain {
+    pub const ESCROW_SEED: &'static [u8] = b"liq_escrow";
+
+    pub fn find_escrow(state: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes()[..32], Self::ESCROW_SEED], &ID)
+    }
+
+    pub fn check_not_drained(&self) -> Result<()> {
+        require!(!self.drained, MarinadeError::LiqPoolDrained);
+        Ok(())
+    }
+
+    pub fn drain(
+        &mut self,
+        escrow_bump_seed: u8,
+        msol_escrow: Pubkey,
+        drained_lamports: u64,
+        drained_msol: u64,
+    ) -> Result<()> {
+        self.check_not_drained()?;
+        *self = Self {
+            drained: true,
+            escrow_bump_seed,
+            msol_escrow,
+            drained_lamports,
+            drained_msol,
+        };
+        Ok(())
+    }
+
+    pub fn restore(&mut self) -> Result<()> {
+        require!(self.drained, MarinadeError::LiqPoolNotDrained);
+        *self = Self::default();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::LiqPoolDrain;
+
+    #[test]
+    fn test_drain_restore() -> Result<()> {
+        let mut drain = LiqPoolDrain::default();
+        assert!(drain.restore().is_err());
+
+        let msol_escrow = Pubkey::new_unique();
+        drain.drain(254, msol_escrow, 1_000_000, 500_000)?;
+        assert!(drain.check_not_drained().is_err());
+        assert_eq!(drain.msol_escrow, msol_escrow);
+        // no second drain over the recorded amounts
+        assert!(drain.drain(254, msol_escrow, 0, 0).is_err());
+
+        drain.restore()?;
+        assert_eq!(drain, LiqPoolDrain::default());
+        assert!(drain.check_not_drained().is_ok());
+        Ok(())
+    }
+}
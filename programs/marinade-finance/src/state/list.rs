@@ -105,7 +105,10 @@ impl List {
         require_lt!(self.len(), capacity, MarinadeError::ListOverflow);
 
         let start = 8 + (self.len() * self.item_size()) as usize;
-        let mut cursor = Cursor::new(&mut data[start..(start + self.item_size() as usize)]);
+        let item_data = &mut data[start..(start + self.item_size() as usize)];
+        // the slot may still hold the last item of a remove
+        item_data.fill(0);
+        let mut cursor = Cursor::new(item_data);
         item.serialize(&mut cursor)?;
 
         self.count += 1;
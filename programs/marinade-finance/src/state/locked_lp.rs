@@ -0,0 +1,186 @@
+use crate::{calc::proportional, error::MarinadeError, require_lte, state::Fee};
+use anchor_lang::prelude::*;
+
+/// Fee-share boost of the LP tokens locked in LockedLpPosition accounts.
+/// boost_fee_share of the LP part of each liquid_unstake fee goes to boost_vault
+/// and is shared by the positions pro-rata to their weight
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct LockedLp {
+    pub boost_vault: Pubkey, // mSOL account of the AUTHORITY_SEED PDA, default = not configured
+    pub authority_bump_seed: u8,
+    pub boost_fee_share: Fee,
+    pub total_weight: u64,
+    pub msol_per_weight: u128, // boost accumulated per weight unit since the start, scaled by PRECISION
+}
+
+impl LockedLp {
+    pub const AUTHORITY_SEED: &'static [u8] = b"locked_lp_authority";
+    pub const PRECISION: u128 = 1_000_000_000_000;
+    pub const MAX_LOCK_SLOTS: u64 = 52 * 432_000; // ~ a year of epochs
+    pub const MAX_BOOST_FEE_SHARE: Fee = Fee::from_basis_points(5_000); // 50% of the LP fee
+
+    pub fn is_configured(&self) -> bool {
+        self.boost_vault != Pubkey::default()
+    }
+
+    pub fn check_lock_slots(lock_slots: u64) -> Result<()> {
+        require_gt!(lock_slots, 0, MarinadeError::InvalidLockDuration);
+        require_lte!(
+            lock_slots,
+            Self::MAX_LOCK_SLOTS,
+            MarinadeError::InvalidLockDuration
+        );
+        Ok(())
+    }
+
+    /// locked LP count once, plus up to once more for a MAX_LOCK_SLOTS lock
+    pub fn weight(lp_amount: u64, lock_slots: u64) -> Result<u64> {
+        let boost = proportional(
+            lp_amount,
+            lock_slots.min(Self::MAX_LOCK_SLOTS),
+            Self::MAX_LOCK_SLOTS,
+        )?;
+        lp_amount
+            .checked_add(boost)
+            .ok_or_else(|| error!(MarinadeError::CalculationFailure))
+    }
+
+    /// part of the LP fee (mSOL) going to the locked positions, 0 while nothing is locked
+    pub fn boost_cut(&self, lp_msol_fee: u64) -> u64 {
+        if self.total_weight == 0 {
+            0
+        } else {
+            self.boost_fee_share.apply(lp_msol_fee)
+        }
+    }
+
+    pub fn accrue(&mut self, boost_msol: u64) {
+        if self.total_weight > 0 {
+            self.msol_per_weight +=
+                boost_msol as u128 * Self::PRECISION / self.total_weight as u128;
+        }
+    }
+
+    pub fn accrued(&self, weight: u64) -> u128 {
+        weight as u128 * self.msol_per_weight / Self::PRECISION
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        self.boost_fee_share
+            .check()
+            .map_err(|e| e.with_source(source!()))?;
+        require_lte!(
+            self.boost_fee_share,
+            Self::MAX_BOOST_FEE_SHARE,
+            MarinadeError::LpMaxFeeIsTooHigh
+        );
+        Ok(())
+    }
+}
+
+/// LP tokens escrowed until unlock_slot, earning the locked LP boost on top of the normal LP share.
+/// PDA of [state, SEED, owner], one position per owner
+#[account]
+#[derive(Debug)]
+pub struct LockedLpPosition {
+    pub state_address: Pubkey, // instance of marinade state this position belongs to
+    pub owner: Pubkey,
+    pub lp_escrow: Pubkey, // LP token account owned by the LockedLp::AUTHORITY_SEED PDA
+    pub lp_amount: u64,
+    pub unlock_slot: u64,
+    pub weight: u64,
+    pub boost_debt: u128, // LockedLp::accrued(weight) when the boost was last paid out
+}
+
+impl LockedLpPosition {
+    pub const SEED: &'static [u8] = b"locked_lp";
+
+    /// boost earned since the last payout, marks it as paid
+    pub fn take_boost(&mut self, locked_lp: &LockedLp) -> u64 {
+        let accrued = locked_lp.accrued(self.weight);
+        // LMT no error possible, boost_debt is an older accrued value of the same weight
+        let boost = (accrued - self.boost_debt) as u64;
+        self.boost_debt = accrued;
+        boost
+    }
+
+    /// re-weights the position for a lock ending at unlock_slot, the boost must be taken first
+    pub fn relock(
+        &mut self,
+        locked_lp: &mut LockedLp,
+        unlock_slot: u64,
+        current_slot: u64,
+    ) -> Result<()> {
+        let lock_slots = unlock_slot.saturating_sub(current_slot);
+        LockedLp::check_lock_slots(lock_slots)?;
+        let weight = LockedLp::weight(self.lp_amount, lock_slots)?;
+        locked_lp.total_weight = locked_lp.total_weight - self.weight + weight;
+        self.weight = weight;
+        self.unlock_slot = unlock_slot;
+        self.boost_debt = locked_lp.accrued(weight);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::{LockedLp, LockedLpPosition};
+    use crate::state::Fee;
+
+    fn position(lp_amount: u64) -> LockedLpPosition {
+        LockedLpPosition {
+            state_address: Pubkey::default(),
+            owner: Pubkey::default(),
+            lp_escrow: Pubkey::default(),
+            lp_amount,
+            unlock_slot: 0,
+            weight: 0,
+            boost_debt: 0,
+        }
+    }
+
+    #[test]
+    fn test_weight() -> Result<()> {
+        assert_eq!(LockedLp::weight(1_000, 1)?, 1_000);
+        assert_eq!(
+            LockedLp::weight(1_000, LockedLp::MAX_LOCK_SLOTS / 2)?,
+            1_500
+        );
+        assert_eq!(LockedLp::weight(1_000, LockedLp::MAX_LOCK_SLOTS)?, 2_000);
+        assert!(LockedLp::check_lock_slots(0).is_err());
+        assert!(LockedLp::check_lock_slots(LockedLp::MAX_LOCK_SLOTS + 1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_boost_shared_by_weight() -> Result<()> {
+        let mut locked_lp = LockedLp {
+            boost_fee_share: Fee::from_basis_points(2_000),
+            ..Default::default()
+        };
+        // nothing locked, nothing cut
+        assert_eq!(locked_lp.boost_cut(1_000), 0);
+
+        let mut short = position(1_000);
+        short.relock(&mut locked_lp, 100 + LockedLp::MAX_LOCK_SLOTS / 2, 100)?;
+        let mut long = position(1_000);
+        long.relock(&mut locked_lp, 100 + LockedLp::MAX_LOCK_SLOTS, 100)?;
+        assert_eq!(locked_lp.total_weight, 3_500);
+
+        let cut = locked_lp.boost_cut(35_000);
+        assert_eq!(cut, 7_000);
+        locked_lp.accrue(cut);
+        assert_eq!(short.take_boost(&locked_lp), 3_000);
+        assert_eq!(long.take_boost(&locked_lp), 4_000);
+        // paid once
+        assert_eq!(long.take_boost(&locked_lp), 0);
+
+        // a position locked later does not share the earlier boost
+        let mut late = position(1_000);
+        late.relock(&mut locked_lp, 200 + LockedLp::MAX_LOCK_SLOTS, 200)?;
+        assert_eq!(late.take_boost(&locked_lp), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::ID;
+use anchor_lang::prelude::*;
+
+/// Wallet exempted by the admin from the liq-pool deposit caps (lp_account_cap and liquidity_sol_cap).
+/// PDA of [state, SEED, wallet], the exemption holds while the account exists
+#[account]
+#[derive(Debug)]
+pub struct LpCapBypass {
+    pub state_address: Pubkey, // instance of marinade state this exemption belongs to
+    pub wallet: Pubkey,        // owner of the LP token account receiving the deposit
+}
+
+impl LpCapBypass {
+    pub const SEED: &'static [u8] = b"lp_cap_bypass";
+
+    pub fn find_address(state: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes(), Self::SEED, &wallet.to_bytes()], &ID)
+    }
+}
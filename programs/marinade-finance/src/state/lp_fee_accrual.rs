@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+/// liquid-unstake fees kept by the liq-pool LPs (after the treasury cut and the locked LP boost),
+/// in lamports at the mSOL price of each unstake. Lets integrators compute the LP APY
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct LpFeeAccrual {
+    pub epoch: u64, // epoch of the last accrued fee
+    pub epoch_fees: u64,
+    pub previous_epoch_fees: u64, // fees of the last epoch with fees before `epoch`
+    pub lifetime_fees: u64,
+    pub fees_per_lp: u128, // lifetime fees per LP token at the lp_supply of each fee, scaled by PRECISION
+}
+
+impl LpFeeAccrual {
+    pub const PRECISION: u128 = 1_000_000_000_000;
+
+    pub fn accrue(&mut self, fee_lamports: u64, lp_supply: u64, epoch: u64) {
+        if epoch != self.epoch {
+            self.previous_epoch_fees = self.epoch_fees;
+            self.epoch_fees = 0;
+            self.epoch = epoch;
+        }
+        self.epoch_fees = self.epoch_fees.saturating_add(fee_lamports);
+        self.lifetime_fees = self.lifetime_fees.saturating_add(fee_lamports);
+        if lp_supply > 0 {
+            self.fees_per_lp += fee_lamports as u128 * Self::PRECISION / lp_supply as u128;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LpFeeAccrual;
+
+    #[test]
+    fn test_accrue() {
+        let mut accrual = LpFeeAccrual::default();
+        accrual.accrue(100, 1_000, 5);
+        accrual.accrue(50, 500, 5);
+        assert_eq!(accrual.epoch_fees, 150);
+        assert_eq!(accrual.fees_per_lp, 2 * LpFeeAccrual::PRECISION / 10);
+
+        accrual.accrue(10, 1_000, 7);
+        assert_eq!(accrual.epoch, 7);
+        assert_eq!(accrual.epoch_fees, 10);
+        assert_eq!(accrual.previous_epoch_fees, 150);
+        assert_eq!(accrual.lifetime_fees, 160);
+
+        // nothing to share with an empty pool
+        accrual.accrue(10, 0, 7);
+        assert_eq!(accrual.lifetime_fees, 170);
+        assert_eq!(accrual.fees_per_lp, 21 * LpFeeAccrual::PRECISION / 100);
+    }
+}
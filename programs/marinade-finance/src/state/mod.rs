@@ -9,14 +9,62 @@ use anchor_lang::{
 use anchor_spl::token::spl_token;
 use std::mem::MaybeUninit;
 
-use self::{liq_pool::LiqPool, stake_system::StakeSystem, validator_system::ValidatorSystem};
+use self::{
+    directed_stake::DirectedStakeRecord,
+    fee_rate_limiter::FeeRateLimiter,
+    flash_loan::FlashLoan,
+    flash_remove::FlashRemove,
+    gauge::Gauge,
+    liq_pool::LiqPool,
+    liq_pool_drain::LiqPoolDrain,
+    locked_lp::LockedLp,
+    lp_fee_accrual::LpFeeAccrual,
+    price_circuit_breaker::PriceCircuitBreaker,
+    stake_system::StakeSystem,
+    unstake_fee_curve::UnstakeFeeCurve,
+    validator_bond::ValidatorBond,
+    validator_criteria::ValidatorCriteria,
+    validator_stake_cap::ValidatorStakeCap,
+    validator_system::{ValidatorRecord, ValidatorSystem},
+};
 
+pub mod admin_log;
+pub mod config_proposal;
 pub mod delayed_unstake_ticket;
+pub mod directed_stake;
+pub mod epoch_report;
 pub mod fee;
+pub mod fee_rate_limiter;
+pub mod flash_loan;
+pub mod flash_remove;
+pub mod gauge;
 pub mod liq_pool;
+pub mod liq_pool_drain;
 pub mod list;
+pub mod locked_lp;
+pub mod lp_cap_bypass;
+pub mod lp_fee_accrual;
+pub mod pause_flags;
+pub mod pause_guardians;
+pub mod price_circuit_breaker;
+pub mod protected_validator;
+pub mod referral;
+pub mod score_oracle;
 pub mod stake_system;
+pub mod staking_cap_bypass;
+pub mod ticket_eta;
+pub mod ticket_registry;
+pub mod treasury_split;
+pub mod treasury_vesting;
+pub mod unstake_fee_curve;
+pub mod validator_blacklist;
+pub mod validator_bond;
+pub mod validator_criteria;
+pub mod validator_history;
+pub mod validator_profile;
+pub mod validator_stake_cap;
 pub mod validator_system;
+pub mod vesting_schedule;
 
 pub use fee::Fee;
 pub use fee::FeeCents;
@@ -91,6 +139,119 @@ pub struct State {
     pub last_stake_move_epoch: u64, // epoch of the last stake move action
     pub stake_moved: u64,           // total amount of moved SOL during the epoch #stake_move_epoch
     pub max_stake_moved_per_epoch: Fee, // % of total_lamports_under_control
+
+    // The fields below were appended after the first deployment, realloc_state grows the State
+    // accounts created before. Their zeroed value must stay valid
+
+    // liq-pool assets lent by begin_flash_remove_liquidity, cleared by end_flash_remove_liquidity
+    pub flash_remove: FlashRemove,
+
+    // send the rounding dust of each remove_liquidity to treasury_msol_account instead of leaving it to the LPs
+    pub removal_dust_to_treasury: bool,
+
+    pub price_circuit_breaker: PriceCircuitBreaker,
+
+    // add the leg ratios and effective price to each RemoveLiquidityEvent, for analytics
+    pub removal_event_price_impact: bool,
+
+    // liquid_unstake fee curve, the liq_pool min/max linear fee applies while it is not set
+    pub unstake_fee_curve: UnstakeFeeCurve,
+
+    // max lamports value of the LP balance of the token account receiving an add_liquidity, 0 = no cap.
+    // Per token account: a wallet spreading its LP over several accounts holds more.
+    // Wallets with an LpCapBypass are exempt
+    pub lp_account_cap: u64,
+
+    // boost of the LP tokens locked in LockedLpPosition accounts
+    pub locked_lp: LockedLp,
+
+    pub lp_fee_accrual: LpFeeAccrual,
+
+    // sol leg lent by flash_loan_sol, cleared by flash_repay_sol
+    pub flash_loan: FlashLoan,
+
+    // rebalance_liq_pool unstakes the mSOL leg above this share of the liq-pool value, 0 = off
+    pub liq_pool_max_msol_share: Fee,
+
+    // liq-pool legs held by the emergency escrow between emergency_drain_liq_pool and restore_liq_pool
+    pub liq_pool_drain: LiqPoolDrain,
+
+    // instant_withdraw limit, lamports per epoch, 0 = disabled
+    pub instant_withdraw_cap_per_epoch: u64,
+    pub last_instant_withdraw_epoch: u64,
+    pub instant_withdrawn: u64, // during the epoch #last_instant_withdraw_epoch
+
+    // share of a ticket paid to the crank_claim caller, at most the max_claim_tip of the ticket
+    pub claim_tip: Fee,
+
+    // share of the total stake target the cranks stake as directed by deposit_directed, 0 = disabled
+    pub directed_stake_max_share: Fee,
+    pub total_directed_stake: u64, // sum of all DirectedStakeRecord.directed_lamports
+
+    // deposit_stake_account limit on top of stake_system.min_stake
+    pub min_deposit_stake_account: u64,
+
+    // vote account criteria of add_validator_permissionless
+    pub validator_criteria: ValidatorCriteria,
+
+    // cap of any validator stake target, share of the total stake target. 0 = no cap
+    pub validator_max_stake_share: Fee,
+
+    // slots without a vote after which anyone can unstake from a validator. 0 = disabled
+    pub delinquent_unstake_slots: u64,
+
+    // validators charging more lose their score on update_active. 0 = not enforced
+    pub commission_ceiling: Fee,
+
+    // share of the undirected stake target spread by gauge weights. 0 = disabled
+    pub gauge_stake_share: Fee,
+    // epochs mSOL stays locked in a gauge
+    pub gauge_lock_epochs: u64,
+    // sum of the tallied Gauge::weight
+    pub total_gauge_weight: u64,
+
+    // stake targets are capped at ValidatorBond::bonded_lamports times this. 0 = bonds not required
+    pub bond_stake_multiplier: u32,
+
+    // redelegate moves stake only off validators deviating from target by more than this share of it
+    pub rebalance_threshold: Fee,
+
+    // share of the lamports moved by a crank paid from the reserve to its caller, at most max_crank_tip
+    pub crank_tip: Fee,
+    pub max_crank_tip: u64,
+
+    // admin nominated by change_authority, takes over on accept_authority. Pubkey::default() = none
+    pub pending_admin_authority: Pubkey,
+
+    // delay between propose_config and execute_config_proposal. 0 = config_marinade and config_lp apply directly
+    pub config_timelock_seconds: u64,
+
+    // PauseFlags bits, groups of instructions paused by the pause authority
+    pub pause_flags: u8,
+    // part of pause_flags set by guardian_pause, cleared by an executed ConfigChange::ResumeGuardianPause only
+    pub guardian_pause_flags: u8,
+
+    // bounds reward_fee, lp_min_fee, lp_max_fee and treasury_cut moves per epoch
+    pub fee_rate_limiter: FeeRateLimiter,
+
+    // update cranks mint the reward fees to the TreasurySplit destinations instead of treasury_msol_account
+    pub treasury_split_enabled: bool,
+
+    // changes treasury_msol_account and the TreasurySplit, assigned by the admin
+    pub treasury_authority: Pubkey,
+
+    // the privileged instructions append to the AdminLog, set by init_admin_log for good
+    pub admin_log_enabled: bool,
+
+    // update cranks mint the reward fees to the TreasuryVesting escrow, set by init_treasury_vesting
+    pub treasury_vesting_enabled: bool,
+
+    // ValidatorStakeCap accounts with a max_stake, the cranks require the cap of the validator while any
+    pub validator_stake_cap_count: u32,
+    // BlacklistedValidator accounts, the instructions staking into a validator require its PDA while any
+    pub blacklisted_validator_count: u32,
+    // ProtectedValidator accounts, the instructions moving stake off a validator require its PDA while any
+    pub protected_validator_count: u32,
 }
 
 impl State {
@@ -104,6 +265,9 @@ impl State {
     pub const VALIDATOR_LIST_SEED: &'static str = "validator_list";
 
     pub const MAX_REWARD_FEE: Fee = Fee::from_basis_points(1_000); // 10% max reward fee
+    pub const MAX_CLAIM_TIP: Fee = Fee::from_basis_points(10); // 0.1% max crank_claim tip
+    pub const MAX_CRANK_TIP: Fee = Fee::from_basis_points(10); // 0.1% max stake crank tip
+    pub const MAX_CONFIG_TIMELOCK_SECONDS: u64 = 30 * 24 * 3600; // 30 days
     pub const MAX_WITHDRAW_ATOM: u64 = LAMPORTS_PER_SOL / 10;
 
     // Note as of July 2023, observable staking reward per epoch is 0.045%
@@ -114,6 +278,8 @@ impl State {
 
     // min_stake minimum value is MIN_STAKE_MULTIPLIER * rent_exempt_for_token_acc
     pub const MIN_STAKE_LOWER_LIMIT: u64 = LAMPORTS_PER_SOL / 100;
+    // leaves room for stake program minimum delegation raises without making stake deltas impossible
+    pub const MIN_STAKE_UPPER_LIMIT: u64 = 100 * LAMPORTS_PER_SOL;
 
     pub fn serialized_len() -> usize {
         unsafe { MaybeUninit::<Self>::zeroed().assume_init() }
@@ -217,6 +383,47 @@ impl State {
             self.msol_supply,
         )
     }
+    /// liq-pool instructions must not run on legs lent by an open flash removal or flash loan,
+    /// nor on legs drained to the emergency escrow
+    pub fn check_liq_pool_not_lent(&self) -> Result<()> {
+        self.flash_remove.check_not_in_progress()?;
+        self.flash_loan.check_not_in_progress()?;
+        self.liq_pool_drain.check_not_drained()
+    }
+
+    /// records the part of a liquid unstake fee kept by the LPs, returns its lamports value
+    pub fn accrue_lp_fee(&mut self, lp_msol_fee: u64, epoch: u64) -> Result<u64> {
+        let fee_lamports = self.msol_to_sol(lp_msol_fee)?;
+        let lp_supply = self.liq_pool.lp_supply;
+        self.lp_fee_accrual.accrue(fee_lamports, lp_supply, epoch);
+        Ok(fee_lamports)
+    }
+
+    pub fn check_lp_account_cap(&self, account_lp_value: u64) -> Result<()> {
+        if self.lp_account_cap > 0 {
+            require_lte!(
+                account_lp_value,
+                self.lp_account_cap,
+                MarinadeError::LpAccountCapExceeded
+            );
+        }
+        Ok(())
+    }
+
+    /// liquid_unstake fee for taking `remove_lamports` out of `sol_leg_available`,
+    /// from unstake_fee_curve when set, else the liq_pool linear fee
+    pub fn liquid_unstake_fee(&self, sol_leg_available: u64, remove_lamports: u64) -> Fee {
+        if self.unstake_fee_curve.is_set() {
+            self.unstake_fee_curve.fee(
+                sol_leg_available.saturating_sub(remove_lamports),
+                self.liq_pool.lp_liquidity_target,
+            )
+        } else {
+            self.liq_pool
+                .liquid_unstake_fee(sol_leg_available, remove_lamports)
+        }
+    }
+
     /// calculate lamports value from some msol_amount
     /// result_lamports = msol_amount * msol_price
     pub fn msol_to_sol(&self, msol_amount: u64) -> Result<u64> {
@@ -227,6 +434,30 @@ impl State {
         )
     }
 
+    /// full value backing the liq-pool lp_supply at the current mSOL price (liq-pool TVL)
+    pub fn liq_pool_total_claimable_lamports(
+        &self,
+        sol_leg_balance: u64,
+        msol_leg_balance: u64,
+    ) -> Result<u64> {
+        LiqPool::total_claimable_lamports(
+            sol_leg_balance.saturating_sub(self.rent_exempt_for_token_acc),
+            self.msol_to_sol(msol_leg_balance)?,
+        )
+    }
+
+    /// SOL share of the liq-pool value in basis points, mSOL leg valued at the current mSOL price
+    pub fn liq_pool_leg_ratio_bps(
+        &self,
+        sol_leg_balance: u64,
+        msol_leg_balance: u64,
+    ) -> Result<u16> {
+        Ok(LiqPool::leg_ratio_bps(
+            sol_leg_balance.saturating_sub(self.rent_exempt_for_token_acc),
+            self.msol_to_sol(msol_leg_balance)?,
+        ))
+    }
+
     // **i128**: when do staking/unstaking use real reserve balance instead of virtual field
     pub fn stake_delta(&self, reserve_balance: u64) -> i128 {
         // Never try to stake lamports from emergency_cooling_down
@@ -266,6 +497,26 @@ impl State {
         self.msol_supply -= amount
     }
 
+    /// fails when the program or the instruction group (PauseFlags bit) is paused
+    pub fn check_not_paused(&self, group: u8) -> Result<()> {
+        require!(!self.paused, MarinadeError::ProgramIsPaused);
+        require!(self.pause_flags & group == 0, MarinadeError::GroupIsPaused);
+        Ok(())
+    }
+
+    /// tip for a crank moving `lamports`. Never touches the lamports owed to tickets
+    pub fn crank_tip_for(&self, lamports: u64) -> u64 {
+        self.crank_tip.apply(lamports).min(self.max_crank_tip).min(
+            self.available_reserve_balance
+                .saturating_sub(self.circulating_ticket_balance),
+        )
+    }
+
+    /// true when active_balance is more than rebalance_threshold away from target
+    pub fn is_off_target(&self, active_balance: u64, target: u64) -> bool {
+        active_balance.abs_diff(target) > self.rebalance_threshold.apply(target)
+    }
+
     pub fn on_stake_moved(&mut self, amount: u64, clock: &Clock) -> Result<()> {
         if clock.epoch != self.last_stake_move_epoch {
             self.last_stake_move_epoch = clock.epoch;
@@ -280,4 +531,172 @@ impl State {
         );
         Ok(())
     }
+
+    /// lamports instant_withdraw can still pay this epoch: idle reserve within the epoch cap
+    pub fn instant_withdraw_available(&self, reserve_balance: u64, epoch: u64) -> u64 {
+        let withdrawn = if epoch == self.last_instant_withdraw_epoch {
+            self.instant_withdrawn
+        } else {
+            0
+        };
+        let idle_reserve = self.stake_delta(reserve_balance).max(0) as u64;
+        idle_reserve.min(
+            self.instant_withdraw_cap_per_epoch
+                .saturating_sub(withdrawn),
+        )
+    }
+
+    /// stake target of a validator, at most its ValidatorStakeCap, validator_max_stake_share
+    /// and what its ValidatorBond backs
+    pub fn validator_stake_target(
+        &self,
+        validator: &ValidatorRecord,
+        directed_stake: Option<&DirectedStakeRecord>,
+        gauge: Option<&Gauge>,
+        stake_cap: Option<&ValidatorStakeCap>,
+        bond: Option<&ValidatorBond>,
+        total_stake_target: u64,
+    ) -> Result<u64> {
+        let mut target = self.uncapped_validator_stake_target(
+            validator,
+            directed_stake,
+            gauge,
+            total_stake_target,
+        )?;
+        if let Some(stake_cap) = stake_cap {
+            require_keys_eq!(
+                stake_cap.validator_account,
+                validator.validator_account,
+                MarinadeError::WrongValidatorStakeCap
+            );
+        }
+        target = ValidatorStakeCap::apply(stake_cap, target);
+        if self.is_bond_required() {
+            if let Some(bond) = bond {
+                require_keys_eq!(
+                    bond.validator_account,
+                    validator.validator_account,
+                    MarinadeError::WrongValidatorBond
+                );
+            }
+            target = target.min(ValidatorBond::max_stake(bond, self.bond_stake_multiplier));
+        }
+        if self.validator_max_stake_share.basis_points > 0 {
+            target = target.min(self.validator_max_stake_share.apply(total_stake_target));
+        }
+        Ok(target)
+    }
+
+    /// the stake cranks require the DirectedStakeRecord of the validator
+    pub fn is_directed_stake_enabled(&self) -> bool {
+        self.directed_stake_max_share.basis_points > 0
+    }
+
+    /// the stake cranks require the ValidatorStakeCap of the validator
+    pub fn has_validator_stake_caps(&self) -> bool {
+        self.validator_stake_cap_count > 0
+    }
+
+    /// the instructions staking into a validator require its BlacklistedValidator PDA
+    pub fn has_blacklisted_validators(&self) -> bool {
+        self.blacklisted_validator_count > 0
+    }
+
+    /// the stake cranks require the Gauge of the validator
+    pub fn is_gauge_stake_enabled(&self) -> bool {
+        self.gauge_stake_share.basis_points > 0 && self.total_gauge_weight > 0
+    }
+
+    /// the stake cranks require the ValidatorBond of the validator
+    pub fn is_bond_required(&self) -> bool {
+        self.bond_stake_multiplier > 0
+    }
+
+    /// the instructions moving stake off a validator require its ProtectedValidator PDA
+    pub fn has_protected_validators(&self) -> bool {
+        self.protected_validator_count > 0
+    }
+
+    /// With a directed stake record the lamports directed to the validator are honored first,
+    /// with a gauge the validator gets its gauge weight share of gauge_stake_share of the rest
+    /// and what is left is spread by score
+    fn uncapped_validator_stake_target(
+        &self,
+        validator: &ValidatorRecord,
+        directed_stake: Option<&DirectedStakeRecord>,
+        gauge: Option<&Gauge>,
+        total_stake_target: u64,
+    ) -> Result<u64> {
+        let (directed, undirected_stake_target) =
+            self.directed_stake_target(validator, directed_stake, total_stake_target)?;
+        let (gauged, score_stake_target) =
+            self.gauge_stake_target(validator, gauge, undirected_stake_target)?;
+        Ok(directed
+            + gauged
+            + self
+                .validator_system
+                .validator_stake_target(validator, score_stake_target)?)
+    }
+
+    /// returns (honored directed lamports of the validator, stake target left for the others)
+    fn directed_stake_target(
+        &self,
+        validator: &ValidatorRecord,
+        directed_stake: Option<&DirectedStakeRecord>,
+        total_stake_target: u64,
+    ) -> Result<(u64, u64)> {
+        let directed_stake = match directed_stake {
+            Some(directed_stake) if self.is_directed_stake_enabled() => directed_stake,
+            _ => return Ok((0, total_stake_target)),
+        };
+        require_keys_eq!(
+            directed_stake.validator_account,
+            validator.validator_account,
+            MarinadeError::WrongDirectedStakeRecord
+        );
+        let max_directed = self.directed_stake_max_share.apply(total_stake_target);
+        let honored_total = self.total_directed_stake.min(max_directed);
+        let honored = directed_stake.honored_lamports(self.total_directed_stake, max_directed)?;
+        Ok((honored, total_stake_target - honored_total))
+    }
+
+    /// returns (gauge lamports of the validator, stake target left to spread by score).
+    /// A validator without gauge gets no gauge lamports
+    fn gauge_stake_target(
+        &self,
+        validator: &ValidatorRecord,
+        gauge: Option<&Gauge>,
+        stake_target: u64,
+    ) -> Result<(u64, u64)> {
+        if !self.is_gauge_stake_enabled() {
+            return Ok((0, stake_target));
+        }
+        let gauge_total = self.gauge_stake_share.apply(stake_target);
+        let gauged = match gauge {
+            Some(gauge) => {
+                require_keys_eq!(
+                    gauge.validator_account,
+                    validator.validator_account,
+                    MarinadeError::WrongGauge
+                );
+                gauge.gauge_lamports(gauge_total, self.total_gauge_weight)?
+            }
+            None => 0,
+        };
+        Ok((gauged, stake_target - gauge_total))
+    }
+
+    pub fn on_instant_withdraw(&mut self, amount: u64, clock: &Clock) -> Result<()> {
+        if clock.epoch != self.last_instant_withdraw_epoch {
+            self.last_instant_withdraw_epoch = clock.epoch;
+            self.instant_withdrawn = 0;
+        }
+        self.instant_withdrawn += amount;
+        require_lte!(
+            self.instant_withdrawn,
+            self.instant_withdraw_cap_per_epoch,
+            MarinadeError::InstantWithdrawIsCapped
+        );
+        Ok(())
+    }
 }
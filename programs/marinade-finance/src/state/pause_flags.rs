@@ -0,0 +1,20 @@
+/// Bits of State::pause_flags, each pausing one group of instructions
+/// on top of the global State::paused
+pub struct PauseFlags;
+
+impl PauseFlags {
+    pub const DEPOSITS: u8 = 1 << 0; // deposit, deposit_stake_account, referral rewards claims
+    pub const LIQUID_UNSTAKE: u8 = 1 << 1; // liquid_unstake, instant and stake account withdrawals
+    pub const DELAYED_UNSTAKE: u8 = 1 << 2; // ticket instructions
+    pub const LIQUIDITY: u8 = 1 << 3; // liquidity pool instructions
+    pub const CRANKS: u8 = 1 << 4; // stake and validator maintenance, validator manager instructions
+    pub const VALIDATOR_BONDS: u8 = 1 << 5; // deposit and withdraw of validator bonds
+    pub const GAUGES: u8 = 1 << 6; // lock and unlock of gauge mSOL
+    pub const ALL: u8 = Self::DEPOSITS
+        | Self::LIQUID_UNSTAKE
+        | Self::DELAYED_UNSTAKE
+        | Self::LIQUIDITY
+        | Self::CRANKS
+        | Self::VALIDATOR_BONDS
+        | Self::GAUGES;
+}
@@ -0,0 +1,92 @@
+use crate::{error::MarinadeError, ID};
+use anchor_lang::prelude::*;
+
+pub const MAX_PAUSE_GUARDIANS: usize = 8;
+
+/// Keys allowed to set pause flags when `threshold` of them sign guardian_pause.
+/// PDA of [state, SEED]. Groups they pause resume only through an admin ConfigProposal
+#[account]
+#[derive(Debug)]
+pub struct PauseGuardians {
+    pub state_address: Pubkey,
+    pub threshold: u8,
+    pub count: u8,
+    pub guardians: [Pubkey; MAX_PAUSE_GUARDIANS], // first `count` are used
+}
+
+impl PauseGuardians {
+    pub const SEED: &'static [u8] = b"pause_guardians";
+
+    pub fn find_address(state: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes(), Self::SEED], &ID)
+    }
+
+    pub fn set(&mut self, guardians: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            threshold > 0
+                && threshold as usize <= guardians.len()
+                && guardians.len() <= MAX_PAUSE_GUARDIANS,
+            MarinadeError::InvalidPauseGuardians
+        );
+        for (i, guardian) in guardians.iter().enumerate() {
+            require!(
+                !guardians[..i].contains(guardian),
+                MarinadeError::InvalidPauseGuardians
+            );
+        }
+        self.threshold = threshold;
+        self.count = guardians.len() as u8;
+        self.guardians = [Pubkey::default(); MAX_PAUSE_GUARDIANS];
+        self.guardians[..guardians.len()].copy_from_slice(guardians);
+        Ok(())
+    }
+
+    pub fn guardians(&self) -> &[Pubkey] {
+        &self.guardians[..self.count as usize]
+    }
+
+    /// guardians among the signers, fails unless they reach the threshold
+    pub fn check_quorum(&self, signers: &[AccountInfo]) -> Result<Vec<Pubkey>> {
+        let mut signed: Vec<Pubkey> = vec![];
+        for signer in signers.iter().filter(|info| info.is_signer) {
+            if self.guardians().contains(signer.key) && !signed.contains(signer.key) {
+                signed.push(*signer.key);
+            }
+        }
+        require_gte!(
+            signed.len(),
+            self.threshold as usize,
+            MarinadeError::GuardianQuorumNotReached
+        );
+        Ok(signed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::{PauseGuardians, MAX_PAUSE_GUARDIANS};
+
+    #[test]
+    fn test_set() {
+        let mut guardians = PauseGuardians {
+            state_address: Pubkey::default(),
+            threshold: 0,
+            count: 0,
+            guardians: [Pubkey::default(); MAX_PAUSE_GUARDIANS],
+        };
+        let keys = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        assert!(guardians.set(&keys, 0).is_err());
+        assert!(guardians.set(&keys, 4).is_err());
+        assert!(guardians.set(&[keys[0], keys[0]], 1).is_err());
+        guardians.set(&keys, 2).unwrap();
+        assert_eq!(guardians.guardians(), &keys);
+        guardians.set(&keys[..1], 1).unwrap();
+        assert_eq!(guardians.guardians(), &keys[..1]);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{error::MarinadeError, state::Fee};
+use anchor_lang::prelude::*;
+
+/// Blocks liquidity removals once the mSOL price moved more than max_price_move
+/// against the reference price taken at most window_slots ago.
+/// Checked on every mSOL price update, stays tripped until the admin resets it
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct PriceCircuitBreaker {
+    pub max_price_move: Fee, // 0 disables the breaker
+    pub window_slots: u64,
+    pub reference_price: u64, // msol_price at reference_slot, 0 = not taken yet
+    pub reference_slot: u64,
+    pub tripped: bool,
+}
+
+impl PriceCircuitBreaker {
+    pub fn check_not_tripped(&self) -> Result<()> {
+        require!(!self.tripped, MarinadeError::CircuitBreakerTripped);
+        Ok(())
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.max_price_move.basis_points > 0
+    }
+
+    /// Returns true if this price update trips the breaker
+    pub fn on_price_update(&mut self, price: u64, slot: u64) -> bool {
+        if !self.is_enabled() || self.tripped {
+            return false;
+        }
+        if self.reference_price == 0 {
+            self.reset(price, slot);
+            return false;
+        }
+        let moved = price.abs_diff(self.reference_price);
+        if moved as u128 * Fee::MAX_BASIS_POINTS as u128
+            > self.max_price_move.basis_points as u128 * self.reference_price as u128
+        {
+            self.tripped = true;
+            return true;
+        }
+        // the new reference is taken only from a price that passed the check
+        if slot >= self.reference_slot.saturating_add(self.window_slots) {
+            self.reset(price, slot);
+        }
+        false
+    }
+
+    pub fn reset(&mut self, price: u64, slot: u64) {
+        self.reference_price = price;
+        self.reference_slot = slot;
+        self.tripped = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PriceCircuitBreaker;
+    use crate::state::{Fee, State};
+
+    const PRICE: u64 = State::PRICE_DENOMINATOR;
+
+    fn breaker() -> PriceCircuitBreaker {
+        PriceCircuitBreaker {
+            max_price_move: Fee::from_basis_points(100), // 1%
+            window_slots: 1_000,
+            reference_price: PRICE,
+            reference_slot: 10_000,
+            tripped: false,
+        }
+    }
+
+    #[test]
+    fn test_price_spike_trips() {
+        let mut breaker = breaker();
+        // normal rewards growth
+        assert!(!breaker.on_price_update(PRICE + PRICE / 1_000, 10_100));
+        assert!(breaker.check_not_tripped().is_ok());
+        // spike
+        assert!(breaker.on_price_update(PRICE + PRICE / 50, 10_200));
+        assert!(breaker.tripped);
+        assert!(breaker.check_not_tripped().is_err());
+        // stays tripped, even when the price comes back
+        assert!(!breaker.on_price_update(PRICE, 20_000));
+        assert!(breaker.check_not_tripped().is_err());
+
+        breaker.reset(PRICE + PRICE / 50, 20_000);
+        assert!(breaker.check_not_tripped().is_ok());
+        assert_eq!(breaker.reference_price, PRICE + PRICE / 50);
+    }
+
+    #[test]
+    fn test_price_drop_trips() {
+        let mut breaker = breaker();
+        assert!(breaker.on_price_update(PRICE - PRICE / 50, 10_001));
+    }
+
+    #[test]
+    fn test_window_moves_reference() {
+        let mut breaker = breaker();
+        let step = PRICE / 125; // 0.8%
+        assert!(!breaker.on_price_update(PRICE + step, 11_000));
+        assert_eq!(breaker.reference_price, PRICE + step);
+        assert_eq!(breaker.reference_slot, 11_000);
+        // within the window the reference stays
+        assert!(!breaker.on_price_update(PRICE + step, 11_500));
+        assert_eq!(breaker.reference_slot, 11_000);
+        assert!(!breaker.on_price_update(PRICE + 2 * step, 12_000));
+        assert!(breaker.check_not_tripped().is_ok());
+    }
+
+    #[test]
+    fn test_disabled() {
+        let mut breaker = PriceCircuitBreaker::default();
+        assert!(!breaker.on_price_update(PRICE, 1));
+        assert!(!breaker.on_price_update(PRICE * 2, 2));
+        assert!(breaker.check_not_tripped().is_ok());
+        assert_eq!(breaker.reference_price, 0);
+    }
+}
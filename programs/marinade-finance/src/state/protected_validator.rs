@@ -0,0 +1,35 @@
+use crate::{error::MarinadeError, ID};
+use anchor_lang::prelude::*;
+
+/// Validator whose existing stake rebalancing must not reduce, e.g. a long-term partnership.
+/// PDA of [state, SEED, validator_vote] set by the validator manager, the protection holds
+/// while the account exists: deactivate_stake and redelegate fail instead of moving its stake
+#[account]
+#[derive(Debug)]
+pub struct ProtectedValidator {
+    pub state_address: Pubkey,
+    pub validator_account: Pubkey,
+}
+
+impl ProtectedValidator {
+    pub const SEED: &'static [u8] = b"protected_validator";
+
+    pub fn find_address(state: &Pubkey, validator_account: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[&state.to_bytes(), Self::SEED, &validator_account.to_bytes()],
+            &ID,
+        )
+    }
+
+    /// `entry` is the PDA of the validator when passed, checked by the caller.
+    /// The caller requires it while State::protected_validator_count is not 0
+    pub fn check_not_protected(entry: Option<&AccountInfo>) -> Result<()> {
+        if let Some(entry) = entry {
+            require!(
+                entry.owner != &ID || entry.data_is_empty(),
+                MarinadeError::ValidatorIsProtected
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{calc::proportional, state::Fee, State, ID};
+use anchor_lang::prelude::*;
+
+/// Wallet integrator credited with `fee` of the staking rewards earned by the mSOL minted
+/// by the deposits it refers, at most the reward fee, as the mSOL price grows.
+/// Nothing is credited on the deposit itself, so depositing with a referral and unstaking
+/// right away earns the partner nothing.
+/// PDA of [state, SEED, partner]. The rewards are paid out of the protocol fees the treasury
+/// moves into a vault: any mSOL account owned by the [state, VAULT_AUTHORITY_SEED] PDA
+#[account]
+#[derive(Debug)]
+pub struct ReferralState {
+    pub state_address: Pubkey,
+    pub partner: Pubkey,
+    pub fee: Fee,
+    pub referred_msol: u64, // mSOL minted by the referred deposits
+    pub accrued_msol: u64,  // credited and not claimed yet
+    pub claimed_msol: u64,
+    pub settled_msol_price: u64, // highest mSOL price the rewards are credited up to
+}
+
+impl ReferralState {
+    pub const SEED: &'static [u8] = b"referral";
+    pub const VAULT_AUTHORITY_SEED: &'static [u8] = b"referral_vault";
+    pub const MAX_FEE: Fee = Fee::from_basis_points(50); // 0.5% of the rewards of the referred mSOL
+
+    pub fn find_address(state: &Pubkey, partner: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes(), Self::SEED, &partner.to_bytes()], &ID)
+    }
+
+    pub fn find_vault_authority(state: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes(), Self::VAULT_AUTHORITY_SEED], &ID)
+    }
+
+    /// Credits the rewards earned by referred_msol since the last settlement.
+    /// A price below settled_msol_price (slashing) credits nothing until it is recovered.
+    /// referred_msol counts past deposits even once unstaked, so it is capped at msol_supply
+    /// and fee at reward_fee: the credit never exceeds the reward fee of the whole pool.
+    /// Returns the mSOL credited
+    pub fn settle(&mut self, msol_price: u64, msol_supply: u64, reward_fee: Fee) -> Result<u64> {
+        if msol_price <= self.settled_msol_price {
+            return Ok(0);
+        }
+        let rewards = proportional(
+            self.referred_msol.min(msol_supply),
+            msol_price - self.settled_msol_price,
+            State::PRICE_DENOMINATOR,
+        )?;
+        let credited = proportional(
+            self.fee.min(reward_fee).apply(rewards),
+            State::PRICE_DENOMINATOR,
+            msol_price,
+        )?;
+        self.accrued_msol += credited;
+        self.settled_msol_price = msol_price;
+        Ok(credited)
+    }
+
+    /// settles the rewards of the earlier deposits first, the new mSOL earns from msol_price on.
+    /// Returns the mSOL credited
+    pub fn on_referred_deposit(&mut self, msol_minted: u64, state: &State) -> Result<u64> {
+        let credited = self.settle(state.msol_price, state.msol_supply, state.reward_fee)?;
+        self.referred_msol += msol_minted;
+        Ok(credited)
+    }
+
+    pub fn on_claim(&mut self, msol_amount: u64) {
+        self.accrued_msol -= msol_amount;
+        self.claimed_msol += msol_amount;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::ReferralState;
+    use crate::{state::Fee, State};
+
+    const PRICE: u64 = State::PRICE_DENOMINATOR;
+
+    #[test]
+    fn test_referral_accrual() -> Result<()> {
+        let reward_fee = Fee::from_basis_points(600);
+        let mut referral = ReferralState {
+            state_address: Pubkey::default(),
+            partner: Pubkey::default(),
+            fee: Fee::from_basis_points(25),
+            referred_msol: 0,
+            accrued_msol: 0,
+            claimed_msol: 0,
+            settled_msol_price: PRICE,
+        };
+        // nothing is credited before the referred mSOL earns rewards
+        referral.referred_msol += 1_000_000_000;
+        assert_eq!(referral.settle(PRICE, u64::MAX, reward_fee)?, 0);
+
+        // mSOL at 1.25 SOL: 1_000_000_000 mSOL earned 250_000_000 lamports,
+        // 0.25% of them in mSOL
+        assert_eq!(
+            referral.settle(PRICE * 5 / 4, u64::MAX, reward_fee)?,
+            500_000
+        );
+        assert_eq!(referral.accrued_msol, 500_000);
+        // a slashed price credits nothing, neither does its recovery
+        assert_eq!(referral.settle(PRICE, u64::MAX, reward_fee)?, 0);
+        assert_eq!(referral.settle(PRICE * 5 / 4, u64::MAX, reward_fee)?, 0);
+
+        referral.on_claim(200_000);
+        assert_eq!(referral.accrued_msol, 300_000);
+        assert_eq!(referral.claimed_msol, 200_000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_referral_credit_is_capped() -> Result<()> {
+        let mut referral = ReferralState {
+            state_address: Pubkey::default(),
+            partner: Pubkey::default(),
+            fee: Fee::from_basis_points(50),
+            referred_msol: 0,
+            accrued_msol: 0,
+            claimed_msol: 0,
+            settled_msol_price: PRICE,
+        };
+        // deposits referred in a loop and unstaked right away leave referred_msol above
+        // the whole supply, the credit stays within the rewards of msol_supply
+        referral.referred_msol = 1_000_000_000_000;
+        let msol_supply = 1_000_000_000;
+        assert_eq!(
+            referral.settle(PRICE * 2, msol_supply, Fee::from_basis_points(600))?,
+            2_500_000 // 0.5% of 1 SOL of rewards per mSOL, at 2 SOL per mSOL
+        );
+        // and within the reward fee
+        referral.settled_msol_price = PRICE;
+        assert_eq!(
+            referral.settle(PRICE * 2, msol_supply, Fee::from_basis_points(10))?,
+            500_000
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{error::MarinadeError, ID};
+use anchor_lang::prelude::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct OracleScore {
+    pub validator_account: Pubkey,
+    pub score: u32,
+    pub epoch: u64, // of the scorer update
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct ScoreUpdate {
+    pub validator_account: Pubkey,
+    pub score: u32,
+}
+
+/// Validator scores published each epoch by the scorer key. PDA of [state, SEED].
+/// Entries are aligned with the validator list indexes. apply_oracle_scores copies the fresh
+/// ones into the validator records, the stake targets keep using the last known score
+/// of a validator whose entry is stale or does not match
+#[account]
+#[derive(Debug)]
+pub struct ScoreOracle {
+    pub state_address: Pubkey,
+    pub scorer: Pubkey,
+    pub max_age_epochs: u64, // entries older than that are not applied
+    pub scores: Vec<OracleScore>,
+}
+
+impl ScoreOracle {
+    pub const SEED: &'static [u8] = b"score_oracle";
+    pub const MAX_SCORES: usize = 200;
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 4 + (32 + 4 + 8) * Self::MAX_SCORES;
+
+    pub fn find_address(state: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes(), Self::SEED], &ID)
+    }
+
+    /// writes the scores from start_index on, extending the entries by at most the scores given
+    pub fn update(&mut self, start_index: u32, scores: &[ScoreUpdate], epoch: u64) -> Result<()> {
+        let start = start_index as usize;
+        require!(
+            start <= self.scores.len() && start + scores.len() <= Self::MAX_SCORES,
+            MarinadeError::InvalidOracleScores
+        );
+        for (i, update) in scores.iter().enumerate() {
+            let entry = OracleScore {
+                validator_account: update.validator_account,
+                score: update.score,
+                epoch,
+            };
+            if start + i < self.scores.len() {
+                self.scores[start + i] = entry;
+            } else {
+                self.scores.push(entry);
+            }
+        }
+        Ok(())
+    }
+
+    /// score to apply to the validator at index, None keeps its last known score
+    pub fn fresh_score(&self, index: u32, validator_account: &Pubkey, epoch: u64) -> Option<u32> {
+        self.scores
+            .get(index as usize)
+            .filter(|entry| {
+                entry.validator_account == *validator_account
+                    && epoch.saturating_sub(entry.epoch) <= self.max_age_epochs
+            })
+            .map(|entry| entry.score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::{ScoreOracle, ScoreUpdate};
+
+    fn score(validator_account: Pubkey, score: u32) -> ScoreUpdate {
+        ScoreUpdate {
+            validator_account,
+            score,
+        }
+    }
+
+    #[test]
+    fn test_update_and_staleness() -> Result<()> {
+        let mut oracle = ScoreOracle {
+            state_address: Pubkey::new_unique(),
+            scorer: Pubkey::new_unique(),
+            max_age_epochs: 2,
+            scores: vec![],
+        };
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        // must be contiguous
+        assert!(oracle.update(1, &[score(a, 1)], 10).is_err());
+        oracle.update(0, &[score(a, 100), score(b, 200)], 10)?;
+        oracle.update(1, &[score(b, 300)], 11)?;
+        assert_eq!(oracle.scores.len(), 2);
+
+        assert_eq!(oracle.fresh_score(0, &a, 12), Some(100));
+        assert_eq!(oracle.fresh_score(1, &b, 12), Some(300));
+        // stale
+        assert_eq!(oracle.fresh_score(0, &a, 13), None);
+        // the list changed since the update
+        assert_eq!(oracle.fresh_score(0, &b, 10), None);
+        assert_eq!(oracle.fresh_score(2, &b, 10), None);
+        Ok(())
+    }
+}
@@ -5,15 +5,51 @@ use anchor_lang::{prelude::*, Discriminator};
 
 use super::list::List;
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, AnchorSerialize, AnchorDeserialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct StakeRecord {
     pub stake_account: Pubkey,
     pub last_update_delegated_lamports: u64,
     pub last_update_epoch: u64,
     pub is_emergency_unstaking: u8, // 1 for cooling down after emergency unstake, 0 otherwise
+    pub flagged: u8, // 1 once the stake lost lamports outside of the program control, see below
+}
+
+// flagged is stored after the original record fields, only in the lists with room for it:
+// lists created with additional_stake_record_space = 0 before it was added have
+// item_size == BASE_LEN. There it reads as 0 and can not be set
+impl AnchorSerialize for StakeRecord {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.stake_account.serialize(writer)?;
+        self.last_update_delegated_lamports.serialize(writer)?;
+        self.last_update_epoch.serialize(writer)?;
+        self.is_emergency_unstaking.serialize(writer)?;
+        if self.flagged != 0 {
+            self.flagged.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl AnchorDeserialize for StakeRecord {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        Ok(Self {
+            stake_account: AnchorDeserialize::deserialize(buf)?,
+            last_update_delegated_lamports: AnchorDeserialize::deserialize(buf)?,
+            last_update_epoch: AnchorDeserialize::deserialize(buf)?,
+            is_emergency_unstaking: AnchorDeserialize::deserialize(buf)?,
+            flagged: if buf.is_empty() {
+                0
+            } else {
+                AnchorDeserialize::deserialize(buf)?
+            },
+        })
+    }
 }
 
 impl StakeRecord {
+    pub const BASE_LEN: u32 = 32 + 8 + 8 + 1;
+    pub const LEN: u32 = Self::BASE_LEN + 1; // with flagged
+
     pub fn new(
         stake_account: &Pubkey,
         delegated_lamports: u64,
@@ -25,6 +61,7 @@ impl StakeRecord {
             last_update_delegated_lamports: delegated_lamports,
             last_update_epoch: clock.epoch,
             is_emergency_unstaking,
+            flagged: 0,
         }
     }
 }
@@ -91,12 +128,10 @@ impl StakeSystem {
     pub const STAKE_WITHDRAW_SEED: &'static [u8] = b"withdraw";
     pub const STAKE_DEPOSIT_SEED: &'static [u8] = b"deposit";
     pub const MIN_UPDATE_WINDOW: u64 = 3_000; // min value is 3_000 => 21 minutes approx
+    pub const MAX_UPDATE_WINDOW: u64 = 216_000; // half of a 432_000 slots epoch
 
     pub fn bytes_for_list(count: u32, additional_record_space: u32) -> u32 {
-        List::bytes_for(
-            StakeRecord::default().try_to_vec().unwrap().len() as u32 + additional_record_space,
-            count,
-        )
+        List::bytes_for(StakeRecord::LEN + additional_record_space, count)
     }
 
     pub fn find_stake_withdraw_authority(state: &Pubkey) -> (Pubkey, u8) {
@@ -118,7 +153,7 @@ impl StakeSystem {
     ) -> Result<Self> {
         let stake_list = List::new(
             &StakeList::DISCRIMINATOR,
-            StakeRecord::default().try_to_vec().unwrap().len() as u32 + additional_record_space,
+            StakeRecord::LEN + additional_record_space,
             stake_list_account,
             stake_list_data,
         )
@@ -152,6 +187,11 @@ impl StakeSystem {
         self.stake_list.item_size()
     }
 
+    /// false for the lists created with no room for StakeRecord::flagged
+    pub fn can_flag_stakes(&self) -> bool {
+        self.stake_record_size() >= StakeRecord::LEN
+    }
+
     pub fn add(
         &mut self,
         stake_list_data: &mut [u8],
@@ -207,3 +247,56 @@ impl StakeSystem {
             .map_err(|e| e.with_account_name("stake_list"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::{StakeList, StakeRecord};
+    use crate::state::list::List;
+    use anchor_lang::Discriminator;
+
+    fn record(flagged: u8) -> StakeRecord {
+        StakeRecord {
+            stake_account: Pubkey::new_unique(),
+            last_update_delegated_lamports: 1_000_000,
+            last_update_epoch: 400,
+            is_emergency_unstaking: 0,
+            flagged,
+        }
+    }
+
+    #[test]
+    fn test_flagged_needs_room() -> Result<()> {
+        // list created before the flagged byte, no additional record space
+        let mut data = vec![0u8; List::bytes_for(StakeRecord::BASE_LEN, 2) as usize];
+        let mut list = List::new(
+            &StakeList::DISCRIMINATOR,
+            StakeRecord::BASE_LEN,
+            Pubkey::new_unique(),
+            &mut data,
+        )?;
+        let unflagged = record(0);
+        list.push(&mut data, unflagged)?;
+        assert_eq!(list.get::<StakeRecord>(&data, 0)?, unflagged);
+        assert!(list.set(&mut data, 0, record(1)).is_err());
+
+        // current lists
+        let mut data = vec![0u8; List::bytes_for(StakeRecord::LEN, 2) as usize];
+        let mut list = List::new(
+            &StakeList::DISCRIMINATOR,
+            StakeRecord::LEN,
+            Pubkey::new_unique(),
+            &mut data,
+        )?;
+        let flagged = record(1);
+        list.push(&mut data, unflagged)?;
+        list.push(&mut data, flagged)?;
+        assert_eq!(list.get::<StakeRecord>(&data, 1)?, flagged);
+        // the removed record stays in the freed slot, the next push must not inherit its flag
+        list.remove(&mut data, 1)?;
+        list.push(&mut data, unflagged)?;
+        assert_eq!(list.get::<StakeRecord>(&data, 1)?, unflagged);
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::ID;
+use anchor_lang::prelude::*;
+
+/// Wallet exempted by the admin from staking_sol_cap, e.g. a strategic partner.
+/// PDA of [state, SEED, wallet], the exemption holds while the account exists
+#[account]
+#[derive(Debug)]
+pub struct StakingCapBypass {
+    pub state_address: Pubkey, // instance of marinade state this exemption belongs to
+    pub wallet: Pubkey,        // owner of the mSOL token account receiving the deposit
+}
+
+impl StakingCapBypass {
+    pub const SEED: &'static [u8] = b"staking_cap_bypass";
+
+    pub fn find_address(state: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes(), Self::SEED, &wallet.to_bytes()], &ID)
+    }
+}
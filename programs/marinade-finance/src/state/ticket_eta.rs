@@ -0,0 +1,24 @@
+use crate::ID;
+use anchor_lang::prelude::*;
+
+/// Queue position and estimated claimable epoch of a delayed-unstake ticket, taken at order time.
+/// PDA of [ticket, SEED], optional companion of TicketAccountData whose layout is shared by all
+/// the existing tickets. Closed by the permissionless close_ticket_eta once the ticket is closed,
+/// the rent goes back to rent_payer
+#[account]
+#[derive(Debug)]
+pub struct TicketEta {
+    pub ticket: Pubkey,
+    pub queue_position: u64, // lamports of the circulating tickets booked before this one
+    pub covered_by_reserve: bool, // the reserve already held the ticket lamports, no unstake needed
+    pub eta_epoch: u64,      // first epoch the ticket can be claimed
+    pub rent_payer: Pubkey,
+}
+
+impl TicketEta {
+    pub const SEED: &'static [u8] = b"ticket_eta";
+
+    pub fn find_address(ticket: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&ticket.to_bytes(), Self::SEED], &ID)
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{error::MarinadeError, state::delayed_unstake_ticket::TicketAccountData, ID};
+use anchor_lang::prelude::*;
+
+/// Index of the pending delayed-unstake tickets of one beneficiary, so wallets can list them
+/// without scanning the program accounts. PDA of [state, SEED, beneficiary].
+/// The registries are optional accounts of order_unstake, split_ticket and transfer_ticket (add),
+/// claim, crank_claim, cancel_unstake_ticket, gc_ticket and transfer_ticket (remove).
+/// Entries left by calls without the registry are removed by the permissionless prune_ticket_registry
+#[account]
+#[derive(Debug)]
+pub struct TicketRegistry {
+    pub state_address: Pubkey,
+    pub beneficiary: Pubkey,
+    pub tickets: Vec<Pubkey>,
+}
+
+impl TicketRegistry {
+    pub const SEED: &'static [u8] = b"ticket_registry";
+    pub const MAX_TICKETS: usize = 32;
+    pub const SPACE: usize = 8 + 32 + 32 + 4 + 32 * Self::MAX_TICKETS;
+
+    pub fn find_address(state: &Pubkey, beneficiary: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[&state.to_bytes(), Self::SEED, &beneficiary.to_bytes()],
+            &ID,
+        )
+    }
+
+    pub fn add(&mut self, ticket: Pubkey) -> Result<()> {
+        require_gt!(
+            Self::MAX_TICKETS,
+            self.tickets.len(),
+            MarinadeError::TicketRegistryFull
+        );
+        self.tickets.push(ticket);
+        Ok(())
+    }
+
+    /// returns false when the ticket was not listed
+    pub fn remove(&mut self, ticket: &Pubkey) -> bool {
+        if let Some(index) = self.tickets.iter().position(|t| t == ticket) {
+            self.tickets.swap_remove(index);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// removes the listed tickets that are closed, emptied or of another beneficiary.
+    /// Returns how many were removed
+    pub fn prune(&mut self, tickets: &[AccountInfo]) -> u32 {
+        let mut pruned = 0;
+        for ticket in tickets {
+            if !self.is_pending(ticket) && self.remove(ticket.key) {
+                pruned += 1;
+            }
+        }
+        pruned
+    }
+
+    fn is_pending(&self, ticket: &AccountInfo) -> bool {
+        if ticket.owner != &ID || ticket.data_is_empty() {
+            return false; // closed
+        }
+        match Account::<TicketAccountData>::try_from(ticket) {
+            Ok(ticket) => {
+                ticket.state_address == self.state_address
+                    && ticket.beneficiary == self.beneficiary
+                    && ticket.lamports_amount > 0
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::TicketRegistry;
+    use crate::{
+        state::delayed_unstake_ticket::{MaybeTicketExtension, TicketAccountData},
+        ID,
+    };
+
+    #[test]
+    fn test_add_remove() -> Result<()> {
+        let mut registry = TicketRegistry {
+            state_address: Pubkey::new_unique(),
+            beneficiary: Pubkey::new_unique(),
+            tickets: vec![],
+        };
+        let tickets: Vec<Pubkey> = (0..TicketRegistry::MAX_TICKETS)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        for ticket in &tickets {
+            registry.add(*ticket)?;
+        }
+        assert!(registry.add(Pubkey::new_unique()).is_err());
+        // the serialized registry fits the account
+        assert_eq!(registry.try_to_vec()?.len() + 8, TicketRegistry::SPACE);
+
+        assert!(registry.remove(&tickets[3]));
+        assert!(!registry.remove(&tickets[3]));
+        assert_eq!(registry.tickets.len(), TicketRegistry::MAX_TICKETS - 1);
+        registry.add(tickets[3])?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_prune() -> Result<()> {
+        let state_address = Pubkey::new_unique();
+        let beneficiary = Pubkey::new_unique();
+        let ticket_data = |beneficiary: Pubkey, lamports_amount: u64| {
+            let mut data = vec![];
+            TicketAccountData {
+                state_address,
+                beneficiary,
+                lamports_amount,
+                created_epoch: 10,
+                extension: MaybeTicketExtension(None),
+            }
+            .try_serialize(&mut data)
+            .unwrap();
+            data
+        };
+        let (pending, claimed, transferred, emptied, unlisted) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut tickets = [
+            (pending, ID, ticket_data(beneficiary, 1_000)),
+            (claimed, System::id(), vec![]),
+            (transferred, ID, ticket_data(Pubkey::new_unique(), 1_000)),
+            (emptied, ID, ticket_data(beneficiary, 0)), // for gc_ticket
+            (unlisted, System::id(), vec![]),
+        ];
+        let mut registry = TicketRegistry {
+            state_address,
+            beneficiary,
+            tickets: vec![pending, claimed, transferred, emptied],
+        };
+
+        let mut lamports = vec![1_000_000u64; tickets.len()];
+        let infos: Vec<AccountInfo> = tickets
+            .iter_mut()
+            .zip(lamports.iter_mut())
+            .map(|((key, owner, data), lamports)| {
+                AccountInfo::new(key, false, false, lamports, data, owner, false, 0)
+            })
+            .collect();
+        assert_eq!(registry.prune(&infos), 3);
+        assert_eq!(registry.tickets, vec![pending]);
+        assert_eq!(registry.prune(&infos), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{calc::proportional, error::MarinadeError, State, ID};
+use anchor_lang::prelude::*;
+
+pub const MAX_TREASURY_SPLIT_ENTRIES: usize = 4;
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct TreasurySplitEntry {
+    pub destination: Pubkey, // mSOL token account
+    pub weight: u32,
+}
+
+/// Destinations of the reward fees minted by the update cranks, used instead of
+/// State.treasury_msol_account while State.treasury_split_enabled.
+/// PDA of [state, SEED]
+#[account]
+#[derive(Debug)]
+pub struct TreasurySplit {
+    pub state_address: Pubkey,
+    pub count: u8,
+    pub entries: [TreasurySplitEntry; MAX_TREASURY_SPLIT_ENTRIES], // first `count` are used
+}
+
+impl TreasurySplit {
+    pub const SEED: &'static [u8] = b"treasury_split";
+
+    pub fn find_address(state: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes(), Self::SEED], &ID)
+    }
+
+    pub fn set(&mut self, entries: &[TreasurySplitEntry]) -> Result<()> {
+        require_gte!(
+            MAX_TREASURY_SPLIT_ENTRIES,
+            entries.len(),
+            MarinadeError::InvalidTreasurySplit
+        );
+        for (i, entry) in entries.iter().enumerate() {
+            require!(
+                entry.weight > 0
+                    && !entries[..i]
+                        .iter()
+                        .any(|other| other.destination == entry.destination),
+                MarinadeError::InvalidTreasurySplit
+            );
+        }
+        self.count = entries.len() as u8;
+        self.entries = [TreasurySplitEntry::default(); MAX_TREASURY_SPLIT_ENTRIES];
+        self.entries[..entries.len()].copy_from_slice(entries);
+        Ok(())
+    }
+
+    pub fn entries(&self) -> &[TreasurySplitEntry] {
+        &self.entries[..self.count as usize]
+    }
+
+    /// true when all the destinations, passed in the entries order, can receive mSOL.
+    /// Same as for a single treasury_msol_account, an invalid one is not an error
+    pub fn check_destinations(&self, state: &State, destinations: &[AccountInfo]) -> Result<bool> {
+        require_eq!(
+            destinations.len(),
+            self.entries().len(),
+            MarinadeError::WrongTreasurySplitDestination
+        );
+        let mut ready = true;
+        for (destination, entry) in destinations.iter().zip(self.entries()) {
+            require!(
+                destination.key == &entry.destination && destination.is_writable,
+                MarinadeError::WrongTreasurySplitDestination
+            );
+            ready &= state.get_treasury_msol_balance(destination).is_some();
+        }
+        Ok(ready)
+    }
+
+    /// shares of msol_amount proportional to the entry weights,
+    /// the rounding dust goes to the first entry
+    pub fn split(&self, msol_amount: u64) -> Result<Vec<u64>> {
+        let total_weight: u64 = self.entries().iter().map(|entry| entry.weight as u64).sum();
+        let mut shares = self
+            .entries()
+            .iter()
+            .map(|entry| proportional(msol_amount, entry.weight as u64, total_weight))
+            .collect::<Result<Vec<u64>>>()?;
+        let dust = msol_amount - shares.iter().sum::<u64>();
+        if let Some(first) = shares.first_mut() {
+            *first += dust;
+        }
+        Ok(shares)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::{TreasurySplit, TreasurySplitEntry, MAX_TREASURY_SPLIT_ENTRIES};
+
+    #[test]
+    fn test_split() {
+        let mut split = TreasurySplit {
+            state_address: Pubkey::default(),
+            count: 0,
+            entries: [TreasurySplitEntry::default(); MAX_TREASURY_SPLIT_ENTRIES],
+        };
+        let entry = |weight| TreasurySplitEntry {
+            destination: Pubkey::new_unique(),
+            weight,
+        };
+        let (ops, insurance, grants) = (entry(50), entry(30), entry(20));
+        split.set(&[ops, insurance, grants]).unwrap();
+        assert_eq!(split.split(1_000).unwrap(), vec![500, 300, 200]);
+        assert_eq!(split.split(7).unwrap(), vec![4, 2, 1]);
+        assert_eq!(split.split(0).unwrap(), vec![0, 0, 0]);
+
+        assert!(split.set(&[ops, entry(0)]).is_err());
+        assert!(split.set(&[ops, ops]).is_err());
+        assert!(split
+            .set(&[entry(1); MAX_TREASURY_SPLIT_ENTRIES + 1])
+            .is_err());
+        split.set(&[]).unwrap();
+        assert!(split.split(7).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{calc::proportional, error::MarinadeError, ID};
+use anchor_lang::prelude::*;
+
+pub const MAX_TREASURY_VESTING_EPOCHS: usize = 32;
+
+/// Escrow of the reward fees while State.treasury_vesting_enabled.
+/// The fees minted during an epoch unlock linearly over the next `vesting_epochs` epochs,
+/// claim_vested_fees delivers them to the treasury.
+/// PDA of [state, SEED], owner of msol_escrow
+#[account]
+#[derive(Debug)]
+pub struct TreasuryVesting {
+    pub state_address: Pubkey,
+    pub msol_escrow: Pubkey,
+    pub bump: u8,
+    pub vesting_epochs: u64, // fixed at init, buckets are indexed by epoch % vesting_epochs
+    // fees minted during bucket_epochs[i], the first `vesting_epochs` are used
+    pub bucket_epochs: [u64; MAX_TREASURY_VESTING_EPOCHS],
+    pub bucket_amounts: [u64; MAX_TREASURY_VESTING_EPOCHS],
+    pub released: u64, // fully vested amount of the recycled buckets
+    pub claimed: u64,
+}
+
+impl TreasuryVesting {
+    pub const SEED: &'static [u8] = b"treasury_vesting";
+
+    pub fn find_address(state: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes(), Self::SEED], &ID)
+    }
+
+    pub fn check_vesting_epochs(vesting_epochs: u64) -> Result<()> {
+        require!(
+            vesting_epochs > 0 && vesting_epochs <= MAX_TREASURY_VESTING_EPOCHS as u64,
+            MarinadeError::InvalidTreasuryVestingEpochs
+        );
+        Ok(())
+    }
+
+    pub fn deposit(&mut self, epoch: u64, msol_amount: u64) {
+        let index = (epoch % self.vesting_epochs) as usize;
+        if self.bucket_epochs[index] != epoch {
+            // the bucket is at least vesting_epochs old
+            self.released += self.bucket_amounts[index];
+            self.bucket_epochs[index] = epoch;
+            self.bucket_amounts[index] = 0;
+        }
+        self.bucket_amounts[index] += msol_amount;
+    }
+
+    pub fn vested(&self, epoch: u64) -> Result<u64> {
+        let mut vested = self.released;
+        for index in 0..self.vesting_epochs as usize {
+            vested += proportional(
+                self.bucket_amounts[index],
+                epoch
+                    .saturating_sub(self.bucket_epochs[index])
+                    .min(self.vesting_epochs),
+                self.vesting_epochs,
+            )?;
+        }
+        Ok(vested)
+    }
+
+    pub fn claimable(&self, epoch: u64) -> Result<u64> {
+        Ok(self.vested(epoch)? - self.claimed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::{TreasuryVesting, MAX_TREASURY_VESTING_EPOCHS};
+
+    #[test]
+    fn test_vesting() -> Result<()> {
+        let mut vesting = TreasuryVesting {
+            state_address: Pubkey::default(),
+            msol_escrow: Pubkey::default(),
+            bump: 255,
+            vesting_epochs: 4,
+            bucket_epochs: [0; MAX_TREASURY_VESTING_EPOCHS],
+            bucket_amounts: [0; MAX_TREASURY_VESTING_EPOCHS],
+            released: 0,
+            claimed: 0,
+        };
+        vesting.deposit(100, 400);
+        vesting.deposit(100, 400);
+        assert_eq!(vesting.claimable(100)?, 0);
+        assert_eq!(vesting.claimable(101)?, 200);
+        vesting.deposit(102, 4_000);
+        assert_eq!(vesting.claimable(103)?, 600 + 1_000);
+        vesting.claimed = 1_600;
+        // the bucket of epoch 100 is recycled
+        vesting.deposit(104, 40);
+        assert_eq!(vesting.released, 800);
+        assert_eq!(vesting.claimable(104)?, 800 + 2_000 - 1_600);
+        assert_eq!(vesting.claimable(u64::MAX)?, 800 + 4_000 + 40 - 1_600);
+
+        assert!(TreasuryVesting::check_vesting_epochs(0).is_err());
+        assert!(TreasuryVesting::check_vesting_epochs(33).is_err());
+        Ok(())
+    }
+}
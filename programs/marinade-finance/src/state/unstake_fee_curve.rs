@@ -0,0 +1,146 @@
+use crate::{
+    error::MarinadeError,
+    require_lte,
+    state::{liq_pool::LiqPool, Fee},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct FeeCurvePoint {
+    pub liquidity_bps: u32, // liquidity left after the unstake, basis points of lp_liquidity_target
+    pub fee: Fee,
+}
+
+/// Piecewise linear liquid_unstake fee, replacing the LiqPool min/max linear fee while set.
+/// Between two points the fee is interpolated, outside of them it is the fee of the nearest point
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct UnstakeFeeCurve {
+    pub len: u8, // 0 = not set
+    pub points: [FeeCurvePoint; UnstakeFeeCurve::MAX_POINTS],
+}
+
+impl UnstakeFeeCurve {
+    pub const MAX_POINTS: usize = 8;
+
+    pub fn new(points: &[FeeCurvePoint]) -> Result<Self> {
+        Self::check_points(points)?;
+        let mut curve = Self {
+            len: points.len() as u8,
+            ..Default::default()
+        };
+        curve.points[..points.len()].copy_from_slice(points);
+        Ok(curve)
+    }
+
+    /// liquidity strictly increasing, fee never increasing with the liquidity
+    pub fn check_points(points: &[FeeCurvePoint]) -> Result<()> {
+        require_lte!(
+            points.len(),
+            Self::MAX_POINTS,
+            MarinadeError::InvalidUnstakeFeeCurve
+        );
+        for point in points {
+            require_lte!(
+                point.liquidity_bps,
+                Fee::MAX_BASIS_POINTS,
+                MarinadeError::InvalidUnstakeFeeCurve
+            );
+            require_lte!(
+                point.fee,
+                LiqPool::MAX_FEE,
+                MarinadeError::LpMaxFeeIsTooHigh
+            );
+        }
+        for pair in points.windows(2) {
+            require_gt!(
+                pair[1].liquidity_bps,
+                pair[0].liquidity_bps,
+                MarinadeError::InvalidUnstakeFeeCurve
+            );
+            require_lte!(
+                pair[1].fee,
+                pair[0].fee,
+                MarinadeError::InvalidUnstakeFeeCurve
+            );
+        }
+        Ok(())
+    }
+
+    pub fn points(&self) -> &[FeeCurvePoint] {
+        &self.points[..self.len as usize]
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.len > 0
+    }
+
+    /// liquid_unstake fee when `lamports` are left in the SOL leg, see LiqPool::linear_fee
+    pub fn fee(&self, lamports: u64, liquidity_target: u64) -> Fee {
+        let liquidity_bps = if liquidity_target == 0 {
+            Fee::MAX_BASIS_POINTS
+        } else {
+            (lamports as u128 * Fee::MAX_BASIS_POINTS as u128 / liquidity_target as u128)
+                .min(Fee::MAX_BASIS_POINTS as u128) as u32
+        };
+        let points = self.points();
+        match points.iter().position(|p| p.liquidity_bps >= liquidity_bps) {
+            None => points[points.len() - 1].fee,
+            Some(0) => points[0].fee,
+            Some(i) => {
+                let (low, high) = (points[i - 1], points[i]);
+                // LMT no error possible, the fee goes down from low.fee to high.fee
+                let fee_drop = (low.fee.basis_points - high.fee.basis_points) as u64
+                    * (liquidity_bps - low.liquidity_bps) as u64
+                    / (high.liquidity_bps - low.liquidity_bps) as u64;
+                Fee::from_basis_points(low.fee.basis_points - fee_drop as u32)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::{FeeCurvePoint, UnstakeFeeCurve};
+    use crate::state::Fee;
+
+    const TARGET: u64 = 10_000;
+
+    fn point(liquidity_bps: u32, fee: u32) -> FeeCurvePoint {
+        FeeCurvePoint {
+            liquidity_bps,
+            fee: Fee::from_basis_points(fee),
+        }
+    }
+
+    #[test]
+    fn test_fee() -> Result<()> {
+        let curve =
+            UnstakeFeeCurve::new(&[point(1_000, 300), point(5_000, 100), point(8_000, 10)])?;
+        // flat before the first and after the last point
+        assert_eq!(curve.fee(0, TARGET), Fee::from_basis_points(300));
+        assert_eq!(curve.fee(1_000, TARGET), Fee::from_basis_points(300));
+        assert_eq!(curve.fee(9_000, TARGET), Fee::from_basis_points(10));
+        assert_eq!(curve.fee(u64::MAX, TARGET), Fee::from_basis_points(10));
+        // interpolated
+        assert_eq!(curve.fee(3_000, TARGET), Fee::from_basis_points(200));
+        assert_eq!(curve.fee(5_000, TARGET), Fee::from_basis_points(100));
+        assert_eq!(curve.fee(6_000, TARGET), Fee::from_basis_points(70));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_points() {
+        // liquidity not increasing
+        assert!(UnstakeFeeCurve::new(&[point(5_000, 300), point(5_000, 100)]).is_err());
+        // fee increasing with the liquidity
+        assert!(UnstakeFeeCurve::new(&[point(1_000, 100), point(5_000, 300)]).is_err());
+        // above LiqPool::MAX_FEE
+        assert!(UnstakeFeeCurve::new(&[point(1_000, 1_001)]).is_err());
+        assert!(UnstakeFeeCurve::new(&[point(10_001, 100)]).is_err());
+        assert!(UnstakeFeeCurve::new(&[point(1, 1); 9]).is_err());
+        // a flat fee is fine
+        assert!(UnstakeFeeCurve::new(&[point(1_000, 100), point(5_000, 100)]).is_ok());
+    }
+}
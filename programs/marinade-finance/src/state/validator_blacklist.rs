@@ -0,0 +1,36 @@
+use crate::{error::MarinadeError, ID};
+use anchor_lang::prelude::*;
+
+/// Vote account blacklisted by the admin, e.g. after a slashing incident.
+/// PDA of [state, SEED, validator_vote], the validator is blacklisted while the account exists:
+/// it can not be added, stake delegated to it is not deposited, the cranks do not stake into it
+/// and deactivate_stake unwinds its stake first
+#[account]
+#[derive(Debug)]
+pub struct BlacklistedValidator {
+    pub state_address: Pubkey,
+    pub validator_account: Pubkey,
+}
+
+impl BlacklistedValidator {
+    pub const SEED: &'static [u8] = b"blacklisted_validator";
+
+    pub fn find_address(state: &Pubkey, validator_account: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[&state.to_bytes(), Self::SEED, &validator_account.to_bytes()],
+            &ID,
+        )
+    }
+
+    /// `entry` is the PDA of the validator when passed, checked by the caller.
+    /// The caller requires it while State::blacklisted_validator_count is not 0
+    pub fn check_not_blacklisted(entry: Option<&AccountInfo>) -> Result<()> {
+        if let Some(entry) = entry {
+            require!(
+                entry.owner != &ID || entry.data_is_empty(),
+                MarinadeError::ValidatorBlacklisted
+            );
+        }
+        Ok(())
+    }
+}
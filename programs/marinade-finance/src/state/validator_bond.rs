@@ -0,0 +1,75 @@
+use crate::{error::MarinadeError, ID};
+use anchor_lang::prelude::*;
+
+/// SOL escrowed by a validator to back the stake the pool delegates to it.
+/// PDA of [state, SEED, validator_vote] holding bonded_lamports on top of its rent.
+/// While State::bond_stake_multiplier is set the validator stake target is at most
+/// bonded_lamports * bond_stake_multiplier, and the admin can slash the bond into the reserve
+#[account]
+#[derive(Debug)]
+pub struct ValidatorBond {
+    pub state_address: Pubkey,
+    pub validator_account: Pubkey,
+    pub authority: Pubkey, // authorized withdrawer of the vote account at init
+    pub bonded_lamports: u64,
+}
+
+impl ValidatorBond {
+    pub const SEED: &'static [u8] = b"validator_bond";
+
+    pub fn find_address(state: &Pubkey, validator_account: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[&state.to_bytes(), Self::SEED, &validator_account.to_bytes()],
+            &ID,
+        )
+    }
+
+    /// `info` is the PDA of the validator when passed, checked by the caller.
+    /// None when the validator has no bond
+    pub fn load(info: Option<&AccountInfo>) -> Result<Option<Self>> {
+        match info {
+            Some(info) if info.owner == &ID && !info.data_is_empty() => {
+                Ok(Some(Self::try_deserialize(&mut &info.data.borrow()[..])?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// stake the bond backs, 0 without a bond
+    pub fn max_stake(bond: Option<&Self>, multiplier: u32) -> u64 {
+        bond.map_or(0, |bond| {
+            bond.bonded_lamports.saturating_mul(multiplier as u64)
+        })
+    }
+
+    pub fn check_backs(&self, multiplier: u32, active_balance: u64) -> Result<()> {
+        require!(
+            multiplier == 0 || Self::max_stake(Some(self), multiplier) >= active_balance,
+            MarinadeError::BondIsTooLow
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::ValidatorBond;
+
+    #[test]
+    fn test_bond_backs_stake() {
+        let bond = ValidatorBond {
+            state_address: Pubkey::default(),
+            validator_account: Pubkey::default(),
+            authority: Pubkey::default(),
+            bonded_lamports: 1_000,
+        };
+        assert_eq!(ValidatorBond::max_stake(Some(&bond), 20), 20_000);
+        assert_eq!(ValidatorBond::max_stake(None, 20), 0);
+        assert!(bond.check_backs(20, 20_000).is_ok());
+        assert!(bond.check_backs(20, 20_001).is_err());
+        // bonds not required
+        assert!(bond.check_backs(0, u64::MAX).is_ok());
+    }
+}
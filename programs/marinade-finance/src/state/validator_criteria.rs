@@ -0,0 +1,261 @@
+use crate::{error::MarinadeError, require_lte, state::Fee};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote;
+
+/// On-chain criteria a vote account must meet for add_validator_permissionless.
+/// The activated stake of a vote account is not readable by programs, so it is not a criterion
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct ValidatorCriteria {
+    pub permissionless_score: u32, // score of the validators added by anyone, 0 = disabled
+    pub max_commission: Fee,
+    pub max_vote_lag_slots: u64, // slots since the last vote, 0 = not checked
+}
+
+impl ValidatorCriteria {
+    pub fn check_commission(&self, vote: &VoteAccountSummary) -> Result<()> {
+        require_lte!(
+            vote.commission_fee(),
+            self.max_commission,
+            MarinadeError::ValidatorCommissionTooHigh
+        );
+        Ok(())
+    }
+
+    pub fn is_delinquent(&self, vote: &VoteAccountSummary, slot: u64) -> bool {
+        self.max_vote_lag_slots > 0 && vote.is_silent_for(self.max_vote_lag_slots, slot)
+    }
+
+    pub fn check(&self, vote: &VoteAccountSummary, slot: u64) -> Result<()> {
+        self.check_commission(vote)?;
+        require!(
+            !self.is_delinquent(vote, slot),
+            MarinadeError::ValidatorDelinquent
+        );
+        Ok(())
+    }
+}
+
+/// The vote account fields the criteria need, read at fixed offsets
+/// instead of deserializing the whole VoteState
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VoteAccountSummary {
+    pub commission: u8, // percents
+    pub last_vote_slot: Option<u64>,
+}
+
+impl VoteAccountSummary {
+    // version tag, node_pubkey, authorized_withdrawer
+    const COMMISSION_OFFSET: usize = 4 + 32 + 32;
+    const VOTES_OFFSET: usize = Self::COMMISSION_OFFSET + 1;
+
+    pub fn load(vote_account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(
+            *vote_account.owner,
+            vote::program::ID,
+            MarinadeError::InvalidVoteAccount
+        );
+        Self::parse(&vote_account.data.borrow())
+    }
+
+    // CircBuf<(Pubkey, Epoch, Epoch)> of 32 items, idx and is_empty
+    const PRIOR_VOTERS_SIZE: usize = 32 * (32 + 8 + 8) + 8 + 1;
+
+    fn read_u64(data: &[u8], offset: usize) -> Result<u64> {
+        data.get(offset..offset + 8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+            .ok_or_else(|| error!(MarinadeError::InvalidVoteAccount))
+    }
+
+    /// (vote size, slot offset in a vote)
+    fn vote_layout(data: &[u8]) -> Result<(usize, usize)> {
+        let version = data
+            .get(0..4)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+            .ok_or_else(|| error!(MarinadeError::InvalidVoteAccount))?;
+        // votes are Lockout {slot, confirmation_count} in 1.14.11
+        // and LandedVote {latency, lockout} in the current version. 0.23.5 is not supported
+        match version {
+            1 => Ok((12, 0)),
+            2 => Ok((13, 1)),
+            _ => err!(MarinadeError::InvalidVoteAccount),
+        }
+    }
+
+    fn read_pubkey(vote_account: &AccountInfo, offset: usize) -> Result<Pubkey> {
+        require_keys_eq!(
+            *vote_account.owner,
+            vote::program::ID,
+            MarinadeError::InvalidVoteAccount
+        );
+        let data = vote_account.data.borrow();
+        Self::vote_layout(&data)?;
+        data.get(offset..offset + 32)
+            .map(|bytes| Pubkey::new_from_array(bytes.try_into().unwrap()))
+            .ok_or_else(|| error!(MarinadeError::InvalidVoteAccount))
+    }
+
+    /// node_pubkey of a vote account, the validator identity
+    pub fn node_pubkey(vote_account: &AccountInfo) -> Result<Pubkey> {
+        Self::read_pubkey(vote_account, 4)
+    }
+
+    /// authorized_withdrawer of a vote account, allowed to act for the validator
+    pub fn authorized_withdrawer(vote_account: &AccountInfo) -> Result<Pubkey> {
+        Self::read_pubkey(vote_account, 4 + 32)
+    }
+
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let read_u64 = |offset: usize| Self::read_u64(data, offset);
+        let (vote_size, slot_offset) = Self::vote_layout(data)?;
+        let commission = *data
+            .get(Self::COMMISSION_OFFSET)
+            .ok_or_else(|| error!(MarinadeError::InvalidVoteAccount))?;
+        let votes_len = read_u64(Self::VOTES_OFFSET)? as usize;
+        let last_vote_slot = if votes_len == 0 {
+            None
+        } else {
+            Some(read_u64(
+                Self::VOTES_OFFSET + 8 + (votes_len - 1) * vote_size + slot_offset,
+            )?)
+        };
+        Ok(Self {
+            commission,
+            last_vote_slot,
+        })
+    }
+
+    /// credits earned by the vote account in `epoch`, 0 when not in its epoch_credits
+    pub fn epoch_credits(data: &[u8], epoch: u64) -> Result<u64> {
+        let read_u64 = |offset: usize| Self::read_u64(data, offset);
+        let (vote_size, _) = Self::vote_layout(data)?;
+        let votes_len = read_u64(Self::VOTES_OFFSET)? as usize;
+        let mut offset = Self::VOTES_OFFSET + 8 + votes_len * vote_size;
+        // root_slot: Option<Slot>
+        offset += match data.get(offset) {
+            Some(0) => 1,
+            Some(1) => 1 + 8,
+            _ => return err!(MarinadeError::InvalidVoteAccount),
+        };
+        // authorized_voters: BTreeMap<Epoch, Pubkey>
+        offset += 8 + read_u64(offset)? as usize * (8 + 32);
+        offset += Self::PRIOR_VOTERS_SIZE;
+        // epoch_credits: Vec<(Epoch, credits, prev_credits)>, the latest last
+        let epoch_credits_len = read_u64(offset)? as usize;
+        offset += 8;
+        for i in (0..epoch_credits_len).rev() {
+            let item = offset + i * 24;
+            let item_epoch = read_u64(item)?;
+            if item_epoch < epoch {
+                break;
+            }
+            if item_epoch == epoch {
+                return Ok(read_u64(item + 8)?.saturating_sub(read_u64(item + 16)?));
+            }
+        }
+        Ok(0)
+    }
+
+    /// true when the vote account did not vote in the last `slots` slots or never voted
+    pub fn is_silent_for(&self, slots: u64, slot: u64) -> bool {
+        match self.last_vote_slot {
+            Some(last) => slot.saturating_sub(last) > slots,
+            None => true,
+        }
+    }
+
+    pub fn commission_fee(&self) -> Fee {
+        Fee::from_basis_points(self.commission as u32 * 100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::{ValidatorCriteria, VoteAccountSummary};
+    use crate::state::Fee;
+
+    fn vote_data(version: u32, commission: u8, votes: &[u64]) -> Vec<u8> {
+        let mut data = version.to_le_bytes().to_vec();
+        data.extend_from_slice(&[0; 64]);
+        data.push(commission);
+        data.extend_from_slice(&(votes.len() as u64).to_le_bytes());
+        for slot in votes {
+            if version == 2 {
+                data.push(1); // latency
+            }
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&1u32.to_le_bytes());
+        }
+        data.extend_from_slice(&[0; 32]); // root slot and the rest
+        data
+    }
+
+    fn vote_data_with_credits(version: u32, epoch_credits: &[(u64, u64, u64)]) -> Vec<u8> {
+        let mut data = vote_data(version, 0, &[100]);
+        data.truncate(data.len() - 32);
+        data.extend_from_slice(&[1, 90, 0, 0, 0, 0, 0, 0, 0]); // root slot
+        data.extend_from_slice(&1u64.to_le_bytes()); // authorized voters
+        data.extend_from_slice(&[0; 8 + 32]);
+        data.extend_from_slice(&[0; VoteAccountSummary::PRIOR_VOTERS_SIZE]);
+        data.extend_from_slice(&(epoch_credits.len() as u64).to_le_bytes());
+        for (epoch, credits, prev_credits) in epoch_credits {
+            data.extend_from_slice(&epoch.to_le_bytes());
+            data.extend_from_slice(&credits.to_le_bytes());
+            data.extend_from_slice(&prev_credits.to_le_bytes());
+        }
+        data.extend_from_slice(&[0; 16]); // last timestamp
+        data
+    }
+
+    #[test]
+    fn test_epoch_credits() -> Result<()> {
+        for version in [1, 2] {
+            let data = vote_data_with_credits(version, &[(7, 1_000, 600), (9, 1_500, 1_000)]);
+            assert_eq!(VoteAccountSummary::epoch_credits(&data, 9)?, 500);
+            assert_eq!(VoteAccountSummary::epoch_credits(&data, 7)?, 400);
+            assert_eq!(VoteAccountSummary::epoch_credits(&data, 8)?, 0);
+            assert_eq!(VoteAccountSummary::epoch_credits(&data, 10)?, 0);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        for version in [1, 2] {
+            let vote = VoteAccountSummary::parse(&vote_data(version, 7, &[100, 101, 105]))?;
+            assert_eq!(vote.commission, 7);
+            assert_eq!(vote.last_vote_slot, Some(105));
+        }
+        let vote = VoteAccountSummary::parse(&vote_data(2, 0, &[]))?;
+        assert_eq!(vote.last_vote_slot, None);
+        assert!(VoteAccountSummary::parse(&vote_data(0, 0, &[])).is_err());
+        assert!(VoteAccountSummary::parse(&[1, 0, 0, 0]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_criteria() {
+        let criteria = ValidatorCriteria {
+            permissionless_score: 1_000,
+            max_commission: Fee::from_basis_points(500),
+            max_vote_lag_slots: 150,
+        };
+        let vote = VoteAccountSummary {
+            commission: 5,
+            last_vote_slot: Some(1_000),
+        };
+        assert!(criteria.check(&vote, 1_150).is_ok());
+        assert!(criteria.check(&vote, 1_151).is_err());
+        let greedy = VoteAccountSummary {
+            commission: 6,
+            ..vote
+        };
+        assert!(criteria.check(&greedy, 1_000).is_err());
+        let silent = VoteAccountSummary {
+            commission: 0,
+            last_vote_slot: None,
+        };
+        assert!(criteria.is_delinquent(&silent, 0));
+    }
+}
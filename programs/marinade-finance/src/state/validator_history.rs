@@ -0,0 +1,125 @@
+use crate::{calc::proportional, state::Fee, ID};
+use anchor_lang::prelude::*;
+
+/// Results of a validator in one epoch, as seen by update_active in the next epoch
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct ValidatorEpochRecord {
+    pub epoch: u64,
+    pub credits: u64,         // vote credits earned in the epoch
+    pub effective_stake: u64, // pool stake delegated to the validator during the epoch
+    pub rewards: u64,         // rewards paid on effective_stake for the epoch
+}
+
+impl ValidatorEpochRecord {
+    /// rewards / effective_stake over a year of epochs, not compounded
+    pub fn apy(&self, epochs_per_year: u64) -> Result<Fee> {
+        if self.effective_stake == 0 {
+            return Ok(Fee::from_basis_points(0));
+        }
+        let basis_points = proportional(
+            self.rewards,
+            epochs_per_year * Fee::MAX_BASIS_POINTS as u64,
+            self.effective_stake,
+        )?;
+        Ok(Fee::from_basis_points(
+            basis_points.min(u32::MAX as u64) as u32
+        ))
+    }
+}
+
+/// Ring buffer of the last ValidatorHistory::EPOCHS epochs of a validator of the list,
+/// filled by update_active when passed. PDA of [state, SEED, validator_vote]
+#[account]
+#[derive(Debug)]
+pub struct ValidatorHistory {
+    pub state_address: Pubkey,
+    pub validator_account: Pubkey,
+    pub next_index: u32,
+    pub records: [ValidatorEpochRecord; VALIDATOR_HISTORY_EPOCHS],
+}
+
+pub const VALIDATOR_HISTORY_EPOCHS: usize = 16;
+
+impl ValidatorHistory {
+    pub const SEED: &'static [u8] = b"validator_history";
+    pub const SPACE: usize = 8 + 32 + 32 + 4 + VALIDATOR_HISTORY_EPOCHS * 32;
+
+    pub fn find_address(state: &Pubkey, validator_account: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[&state.to_bytes(), Self::SEED, &validator_account.to_bytes()],
+            &ID,
+        )
+    }
+
+    fn latest_index(&self) -> usize {
+        (self.next_index as usize + VALIDATOR_HISTORY_EPOCHS - 1) % VALIDATOR_HISTORY_EPOCHS
+    }
+
+    pub fn latest(&self) -> &ValidatorEpochRecord {
+        &self.records[self.latest_index()]
+    }
+
+    /// adds the results of one stake account of the validator in `epoch`.
+    /// credits are per validator, the latest value is kept
+    pub fn record(&mut self, epoch: u64, credits: Option<u64>, effective_stake: u64, rewards: u64) {
+        if self.latest().epoch != epoch {
+            let index = self.next_index as usize;
+            self.records[index] = ValidatorEpochRecord {
+                epoch,
+                ..Default::default()
+            };
+            self.next_index = ((index + 1) % VALIDATOR_HISTORY_EPOCHS) as u32;
+        }
+        let index = self.latest_index();
+        let record = &mut self.records[index];
+        if let Some(credits) = credits {
+            record.credits = credits;
+        }
+        record.effective_stake += effective_stake;
+        record.rewards += rewards;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::{ValidatorEpochRecord, ValidatorHistory, VALIDATOR_HISTORY_EPOCHS};
+
+    #[test]
+    fn test_record() -> Result<()> {
+        let mut history = ValidatorHistory {
+            state_address: Pubkey::default(),
+            validator_account: Pubkey::default(),
+            next_index: 0,
+            records: [ValidatorEpochRecord::default(); VALIDATOR_HISTORY_EPOCHS],
+        };
+        // two stake accounts of the validator in epoch 100
+        history.record(100, Some(6_000), 1_000_000, 200);
+        history.record(100, None, 3_000_000, 600);
+        assert_eq!(history.next_index, 1);
+        assert_eq!(
+            *history.latest(),
+            ValidatorEpochRecord {
+                epoch: 100,
+                credits: 6_000,
+                effective_stake: 4_000_000,
+                rewards: 800,
+            }
+        );
+        // 800 / 4_000_000 per epoch for 182 epochs = 3.64%
+        assert_eq!(history.latest().apy(182)?.basis_points, 364);
+
+        for epoch in 101..101 + VALIDATOR_HISTORY_EPOCHS as u64 {
+            history.record(epoch, None, 1, 0);
+        }
+        // the ring wrapped over epoch 100
+        assert_eq!(history.next_index, 1);
+        assert_eq!(
+            history.records[0].epoch,
+            100 + VALIDATOR_HISTORY_EPOCHS as u64
+        );
+        assert!(history.records.iter().all(|record| record.epoch != 100));
+        Ok(())
+    }
+}
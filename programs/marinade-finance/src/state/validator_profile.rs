@@ -0,0 +1,32 @@
+use crate::{state::Fee, ID};
+use anchor_lang::prelude::*;
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct ValidatorProfileData {
+    pub contact_info_hash: [u8; 32], // hash of the off-chain contact info document
+    pub max_commission: Fee,         // commission the validator commits not to exceed
+    pub mev_sharing: bool,           // MEV rewards are shared with the stakers
+}
+
+/// Data a validator publishes about itself, signed with its identity key.
+/// PDA of [state, SEED, validator_vote]
+#[account]
+#[derive(Debug)]
+pub struct ValidatorProfile {
+    pub state_address: Pubkey,
+    pub validator_account: Pubkey,
+    pub identity: Pubkey, // node_pubkey of the vote account when last signed
+    pub data: ValidatorProfileData,
+    pub updated_epoch: u64,
+}
+
+impl ValidatorProfile {
+    pub const SEED: &'static [u8] = b"validator_profile";
+
+    pub fn find_address(state: &Pubkey, validator_account: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[&state.to_bytes(), Self::SEED, &validator_account.to_bytes()],
+            &ID,
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use crate::ID;
+use anchor_lang::prelude::*;
+
+/// Admin cap of the stake the pool delegates to a validator.
+/// PDA of [state, SEED, validator_vote], kept outside of the ValidatorRecord
+/// whose size is fixed by the existing validator lists
+#[account]
+#[derive(Debug)]
+pub struct ValidatorStakeCap {
+    pub state_address: Pubkey,
+    pub validator_account: Pubkey,
+    pub max_stake: u64, // lamports, 0 = no cap
+}
+
+impl ValidatorStakeCap {
+    pub const SEED: &'static [u8] = b"validator_stake_cap";
+    pub const SPACE: usize = 8 + 32 + 32 + 8;
+
+    pub fn find_address(state: &Pubkey, validator_account: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[&state.to_bytes(), Self::SEED, &validator_account.to_bytes()],
+            &ID,
+        )
+    }
+
+    /// `info` is the PDA of the validator when passed, checked by the caller.
+    /// None when the validator has no cap
+    pub fn load(info: Option<&AccountInfo>) -> Result<Option<Self>> {
+        match info {
+            Some(info) if info.owner == &ID && !info.data_is_empty() => {
+                Ok(Some(Self::try_deserialize(&mut &info.data.borrow()[..])?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// target at most max_stake of the cap, if any
+    pub fn apply(cap: Option<&Self>, target: u64) -> u64 {
+        match cap {
+            Some(cap) if cap.max_stake > 0 => target.min(cap.max_stake),
+            _ => target,
+        }
+    }
+}
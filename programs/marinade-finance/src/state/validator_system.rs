@@ -1,7 +1,11 @@
 //use std::convert::TryInto;
 
 use crate::{calc::proportional, error::MarinadeError, ID};
-use anchor_lang::{prelude::*, Discriminator};
+use anchor_lang::{
+    prelude::*,
+    solana_program::hash::{hashv, Hash},
+    Discriminator,
+};
 
 use super::list::List;
 
@@ -247,6 +251,15 @@ impl ValidatorSystem {
         Ok(validator_record)
     }
 
+    /// hash of the vote accounts of the list in order, binds indexes to validators
+    pub fn validator_list_hash(&self, validator_list_data: &[u8]) -> Result<Hash> {
+        let validator_accounts = (0..self.validator_count())
+            .map(|index| Ok(self.get(validator_list_data, index)?.validator_account))
+            .collect::<Result<Vec<Pubkey>>>()?;
+        let bytes: Vec<&[u8]> = validator_accounts.iter().map(|key| key.as_ref()).collect();
+        Ok(hashv(&bytes))
+    }
+
     // Do not forget to update totals
     pub fn set(
         &self,
@@ -0,0 +1,129 @@
+use crate::{calc::proportional, error::MarinadeError, ID};
+use anchor_lang::prelude::*;
+
+/// Linear vesting of liquidity removed from the liq-pool.
+/// SOL is held by this account itself, mSOL by the msol_escrow token account
+#[account]
+#[derive(Debug)]
+pub struct VestingSchedule {
+    pub state_address: Pubkey, // instance of marinade state this schedule belongs to
+    pub beneficiary: Pubkey,   // main account where to send SOL and mSOL owner when claimed
+    pub msol_escrow: Pubkey,   // token account owned by the vesting authority PDA
+    pub escrow_authority_bump_seed: u8,
+    pub start_slot: u64,
+    pub cliff_slot: u64, // nothing is claimable before this slot
+    pub end_slot: u64,   // everything is claimable from this slot
+    pub total_lamports: u64,
+    pub total_msol: u64,
+    pub claimed_lamports: u64,
+    pub claimed_msol: u64,
+}
+
+impl VestingSchedule {
+    pub const ESCROW_AUTHORITY_SEED: &'static [u8] = b"vesting_authority";
+
+    pub fn find_escrow_authority(state: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&state.to_bytes()[..32], Self::ESCROW_AUTHORITY_SEED], &ID)
+    }
+
+    pub fn check_slots(start_slot: u64, cliff_slot: u64, end_slot: u64) -> Result<()> {
+        require_gte!(
+            cliff_slot,
+            start_slot,
+            MarinadeError::InvalidVestingSchedule
+        );
+        require_gte!(end_slot, cliff_slot, MarinadeError::InvalidVestingSchedule);
+        Ok(())
+    }
+
+    /// part of `total` vested at `slot`
+    pub fn vested(&self, total: u64, slot: u64) -> Result<u64> {
+        if slot < self.cliff_slot {
+            Ok(0)
+        } else if slot >= self.end_slot {
+            Ok(total)
+        } else {
+            proportional(
+                total,
+                slot - self.start_slot,
+                self.end_slot - self.start_slot,
+            )
+        }
+    }
+
+    pub fn claimable_lamports(&self, slot: u64) -> Result<u64> {
+        Ok(self.vested(self.total_lamports, slot)? - self.claimed_lamports)
+    }
+
+    pub fn claimable_msol(&self, slot: u64) -> Result<u64> {
+        Ok(self.vested(self.total_msol, slot)? - self.claimed_msol)
+    }
+
+    pub fn is_fully_claimed(&self) -> bool {
+        self.claimed_lamports == self.total_lamports && self.claimed_msol == self.total_msol
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::VestingSchedule;
+
+    fn schedule() -> VestingSchedule {
+        VestingSchedule {
+            state_address: Pubkey::new_unique(),
+            beneficiary: Pubkey::new_unique(),
+            msol_escrow: Pubkey::new_unique(),
+            escrow_authority_bump_seed: 255,
+            start_slot: 1_000,
+            cliff_slot: 1_500,
+            end_slot: 3_000,
+            total_lamports: 2_000_000,
+            total_msol: 1_000_000,
+            claimed_lamports: 0,
+            claimed_msol: 0,
+        }
+    }
+
+    #[test]
+    fn test_pre_cliff() -> Result<()> {
+        let schedule = schedule();
+        assert_eq!(schedule.claimable_lamports(0)?, 0);
+        assert_eq!(schedule.claimable_lamports(1_000)?, 0);
+        assert_eq!(schedule.claimable_msol(1_499)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mid_schedule() -> Result<()> {
+        let mut schedule = schedule();
+        // the cliff releases everything accrued since start
+        assert_eq!(schedule.claimable_lamports(1_500)?, 500_000);
+        assert_eq!(schedule.claimable_msol(2_000)?, 500_000);
+        schedule.claimed_lamports = 500_000;
+        assert_eq!(schedule.claimable_lamports(2_000)?, 500_000);
+        assert!(!schedule.is_fully_claimed());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fully_vested() -> Result<()> {
+        let mut schedule = schedule();
+        assert_eq!(schedule.claimable_lamports(3_000)?, 2_000_000);
+        assert_eq!(schedule.claimable_msol(u64::MAX)?, 1_000_000);
+        schedule.claimed_lamports = schedule.total_lamports;
+        schedule.claimed_msol = schedule.total_msol;
+        assert_eq!(schedule.claimable_lamports(3_000)?, 0);
+        assert!(schedule.is_fully_claimed());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_slots() {
+        assert!(VestingSchedule::check_slots(1, 1, 1).is_ok());
+        assert!(VestingSchedule::check_slots(1, 2, 3).is_ok());
+        assert!(VestingSchedule::check_slots(2, 1, 3).is_err());
+        assert!(VestingSchedule::check_slots(1, 3, 2).is_err());
+    }
+}